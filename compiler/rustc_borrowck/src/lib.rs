@@ -36,6 +36,7 @@
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::{self, CapturedPlace, ParamEnv, RegionVid, TyCtxt};
 use rustc_session::lint::builtin::{MUTABLE_BORROW_RESERVATION_CONFLICT, UNUSED_MUT};
+use rustc_session::ItemPhase;
 use rustc_span::{Span, Symbol, DUMMY_SP};
 
 use either::Either;
@@ -125,6 +126,7 @@ fn mir_borrowck<'tcx>(
 ) -> &'tcx BorrowCheckResult<'tcx> {
     let (input_body, promoted) = tcx.mir_promoted(def);
     debug!("run query mir_borrowck: {}", tcx.def_path_str(def.did.to_def_id()));
+    let _timer = tcx.sess.item_timer(ItemPhase::Borrowck, def.did.to_def_id());
     let hir_owner = tcx.hir().local_def_id_to_hir_id(def.did).owner;
 
     let opt_closure_req = tcx.infer_ctxt().with_opaque_type_inference(hir_owner).enter(|infcx| {
@@ -10,7 +10,7 @@
 use rustc_errors::{pluralize, Applicability, MultiSpan, PResult};
 use rustc_expand::base::{self, *};
 use rustc_parse_format as parse;
-use rustc_span::symbol::{sym, Ident, Symbol};
+use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::{InnerSpan, Span};
 use smallvec::SmallVec;
 
@@ -20,6 +20,9 @@
 #[derive(PartialEq)]
 enum ArgumentType {
     Placeholder(&'static str),
+    /// A `{:%...}` placeholder, formatted with `CustomFormat` and the given spec,
+    /// along with the span of the spec within the format string.
+    Custom(Symbol, Span),
     Count,
 }
 
@@ -279,6 +282,14 @@ fn verify_piece(&mut self, p: &parse::Piece<'_>) {
                     parse::ArgumentNamed(s, span) => Named(s, span),
                 };
 
+                if arg.format.ty.starts_with('%') {
+                    let fmtsp = self.fmtsp;
+                    let sp = arg.format.ty_span.map_or(fmtsp, |sp| fmtsp.from_inner(sp));
+                    self.verify_arg_type(pos, Custom(Symbol::intern(arg.format.ty), sp));
+                    self.curpiece += 1;
+                    return;
+                }
+
                 let ty = Placeholder(match arg.format.ty {
                     "" => "Display",
                     "?" => "Debug",
@@ -306,7 +317,8 @@ fn verify_piece(&mut self, p: &parse::Piece<'_>) {
                                 - `p`, which uses the `Pointer` trait\n\
                                 - `b`, which uses the `Binary` trait\n\
                                 - `x`, which uses the `LowerHex` trait\n\
-                                - `X`, which uses the `UpperHex` trait",
+                                - `X`, which uses the `UpperHex` trait\n\
+                                - `%...`, which uses the `CustomFormat` trait",
                         );
                         if let Some(sp) = sp {
                             for (fmt, name) in &[
@@ -515,7 +527,7 @@ fn verify_arg_type(&mut self, arg: Position, ty: ArgumentType) {
         match arg {
             Exact(arg) | Capture(arg) => {
                 match ty {
-                    Placeholder(_) => {
+                    Placeholder(_) | Custom(..) => {
                         // record every (position, type) combination only once
                         let seen_ty = &mut self.arg_unique_types[arg];
                         let i = seen_ty.iter().position(|x| *x == ty).unwrap_or_else(|| {
@@ -902,6 +914,11 @@ fn format_arg(
         let trait_ = match *ty {
             Placeholder(trait_) if trait_ == "<invalid>" => return DummyResult::raw_expr(sp, true),
             Placeholder(trait_) => trait_,
+            Custom(spec, spec_sp) => {
+                let spec = Context::custom_spec(ecx, ecx.with_def_site_ctxt(spec_sp), spec);
+                let path = ecx.std_path(&[sym::fmt, sym::ArgumentV1, sym::new_custom]);
+                return ecx.expr_call_global(sp, path, vec![arg, spec]);
+            }
             Count => {
                 let path = ecx.std_path(&[sym::fmt, sym::ArgumentV1, sym::from_usize]);
                 return ecx.expr_call_global(macsp, path, vec![arg]);
@@ -923,6 +940,72 @@ fn format_arg(
         let path = ecx.std_path(&[sym::fmt, sym::ArgumentV1, Symbol::intern(new_fn_name)]);
         ecx.expr_call_global(sp, path, vec![arg])
     }
+
+    /// Builds a value of a fresh type that carries the spec of a `{:%...}`
+    /// placeholder, so that `CustomFormat` can check it at compile time.
+    /// The value is spanned on the spec, where rustc reports a rejected one:
+    ///
+    /// ```text
+    /// {
+    ///     struct Spec;
+    ///     impl ::core::fmt::CustomSpec for Spec {
+    ///         const SPEC: &'static str = "%...";
+    ///     }
+    ///     Spec
+    /// }
+    /// ```
+    fn custom_spec(ecx: &ExtCtxt<'_>, sp: Span, spec: Symbol) -> P<ast::Expr> {
+        let ident = Ident::new(sym::Spec, sp);
+        let unit = ast::VariantData::Unit(ast::DUMMY_NODE_ID);
+        let item_struct =
+            ecx.item(sp, ident, Vec::new(), ast::ItemKind::Struct(unit, Default::default()));
+
+        let str_ty = ecx.ty_ident(sp, Ident::new(sym::str, sp));
+        let static_lt = ecx.lifetime(sp, Ident::new(kw::StaticLifetime, sp));
+        let const_ty = ecx.ty_rptr(sp, str_ty, Some(static_lt), ast::Mutability::Not);
+        let spec_const = P(ast::AssocItem {
+            id: ast::DUMMY_NODE_ID,
+            span: sp,
+            ident: Ident::new(sym::SPEC, sp),
+            vis: ast::Visibility {
+                span: sp.shrink_to_lo(),
+                kind: ast::VisibilityKind::Inherited,
+                tokens: None,
+            },
+            attrs: Vec::new(),
+            kind: ast::AssocItemKind::Const(
+                ast::Defaultness::Final,
+                const_ty,
+                Some(ecx.expr_str(sp, spec)),
+            ),
+            tokens: None,
+        });
+        let trait_path = ecx.path_global(sp, ecx.std_path(&[sym::fmt, sym::CustomSpec]));
+        let item_impl = ecx.item(
+            sp,
+            Ident::empty(),
+            Vec::new(),
+            ast::ItemKind::Impl(Box::new(ast::Impl {
+                unsafety: ast::Unsafe::No,
+                polarity: ast::ImplPolarity::Positive,
+                defaultness: ast::Defaultness::Final,
+                constness: ast::Const::No,
+                generics: Default::default(),
+                of_trait: Some(ecx.trait_ref(trait_path)),
+                self_ty: ecx.ty_ident(sp, ident),
+                items: vec![spec_const],
+            })),
+        );
+
+        ecx.expr_block(ecx.block(
+            sp,
+            vec![
+                ecx.stmt_item(sp, item_struct),
+                ecx.stmt_item(sp, item_impl),
+                ecx.stmt_expr(ecx.expr_ident(sp, ident)),
+            ],
+        ))
+    }
 }
 
 fn expand_format_args_impl<'cx>(
@@ -360,9 +360,22 @@ fn join_codegen(
             .join(sess);
 
         sess.time("llvm_dump_timing_file", || {
-            if sess.opts.debugging_opts.llvm_time_trace {
-                let file_name = outputs.with_extension("llvm_timings.json");
+            if sess.llvm_time_trace() {
+                // Without `-Z llvm-time-trace`, the time trace is only needed
+                // to attribute LLVM time to items for `-Z time-items`.
+                let keep_file = sess.opts.debugging_opts.llvm_time_trace;
+                let file_name = if keep_file {
+                    outputs.with_extension("llvm_timings.json")
+                } else {
+                    outputs.temp_path_ext("llvm_timings.json", None)
+                };
                 llvm_util::time_trace_profiler_finish(&file_name);
+                if sess.opts.debugging_opts.time_items.is_some() {
+                    llvm_util::record_item_timings(sess, &file_name);
+                }
+                if !keep_file {
+                    let _ = std::fs::remove_file(&file_name);
+                }
             }
         });
 
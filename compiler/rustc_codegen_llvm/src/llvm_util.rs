@@ -9,20 +9,25 @@
 use rustc_data_structures::small_c_str::SmallCStr;
 use rustc_fs_util::path_to_c_string;
 use rustc_middle::bug;
+use rustc_serialize::json::Json;
 use rustc_session::config::PrintRequest;
-use rustc_session::Session;
+use rustc_session::{ItemPhase, Session};
+use rustc_span::def_id::DefId;
 use rustc_span::symbol::Symbol;
 use rustc_target::spec::{MergeFunctions, PanicStrategy};
 use smallvec::{smallvec, SmallVec};
 use std::ffi::{CStr, CString};
 use tracing::debug;
 
+use std::cmp::Reverse;
+use std::fs;
 use std::mem;
 use std::path::Path;
 use std::ptr;
 use std::slice;
 use std::str;
 use std::sync::Once;
+use std::time::Duration;
 
 static INIT: Once = Once::new();
 
@@ -125,7 +130,7 @@ fn llvm_arg_to_arg_name(full_arg: &str) -> &str {
         }
     }
 
-    if sess.opts.debugging_opts.llvm_time_trace {
+    if sess.llvm_time_trace() {
         llvm::LLVMTimeTraceProfilerInitialize();
     }
 
@@ -159,6 +164,65 @@ pub fn time_trace_profiler_finish(file_name: &Path) {
     }
 }
 
+/// Attributes the time LLVM spent on every function in the time trace
+/// `file_name` to the item the function is an instantiation of, for
+/// `-Z time-items`.
+pub fn record_item_timings(sess: &Session, file_name: &Path) {
+    let trace = match fs::read_to_string(file_name) {
+        Ok(trace) => trace,
+        Err(err) => {
+            sess.warn(&format!(
+                "failed to read LLVM time trace `{}`: {}",
+                file_name.display(),
+                err
+            ));
+            return;
+        }
+    };
+    let Ok(trace) = Json::from_str(&trace) else {
+        sess.warn(&format!("failed to parse LLVM time trace `{}`", file_name.display()));
+        return;
+    };
+
+    // The events that belong to a codegened function carry its symbol name as
+    // their detail.
+    let mut events: Vec<_> = trace
+        .find("traceEvents")
+        .and_then(Json::as_array)
+        .map_or(&[][..], |events| &events[..])
+        .iter()
+        .filter_map(|event| {
+            if event.find("ph")?.as_string()? != "X" {
+                return None;
+            }
+            let symbol_name = event.find("args")?.find("detail")?.as_string()?;
+            let def_id = sess.item_timings.symbol_item(symbol_name)?;
+            let thread_id = event.find("tid")?.as_u64()?;
+            let start = event.find("ts")?.as_u64()?;
+            let end = start + event.find("dur")?.as_u64()?;
+            Some((thread_id, start, end, def_id))
+        })
+        .collect();
+
+    // The events of a function are nested, e.g. the passes run on it within
+    // the pass manager running on it, so only count the outermost ones.
+    events.sort_by_key(|&(thread_id, start, end, _)| (thread_id, start, Reverse(end)));
+    let mut enclosing: Vec<(u64, u64, DefId)> = Vec::new();
+    for (thread_id, start, end, def_id) in events {
+        while let Some(&(enclosing_thread_id, enclosing_end, _)) = enclosing.last() {
+            if enclosing_thread_id == thread_id && enclosing_end > start {
+                break;
+            }
+            enclosing.pop();
+        }
+        if !enclosing.iter().any(|&(.., enclosing_def_id)| enclosing_def_id == def_id) {
+            let duration = Duration::from_micros(end - start);
+            sess.item_timings.record(ItemPhase::Llvm, def_id, duration);
+        }
+        enclosing.push((thread_id, end, def_id));
+    }
+}
+
 // WARNING: the features after applying `to_llvm_features` must be known
 // to LLVM or the feature detection code will walk past the end of the feature
 // array, leading to crashes.
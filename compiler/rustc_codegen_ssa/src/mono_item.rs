@@ -2,11 +2,13 @@
 use crate::common;
 use crate::traits::*;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_middle::mir::mono::MonoItem;
 use rustc_middle::mir::mono::{Linkage, Visibility};
 use rustc_middle::ty;
 use rustc_middle::ty::layout::{HasTyCtxt, LayoutOf};
-use rustc_middle::ty::Instance;
+use rustc_middle::ty::{Instance, TyCtxt};
+use rustc_session::{ItemPhase, ItemTimer};
 
 pub trait MonoItemExt<'a, 'tcx> {
     fn define<Bx: BuilderMethods<'a, 'tcx>>(&self, cx: &'a Bx::CodegenCx);
@@ -30,6 +32,7 @@ fn define<Bx: BuilderMethods<'a, 'tcx>>(&self, cx: &'a Bx::CodegenCx) {
 
         match *self {
             MonoItem::Static(def_id) => {
+                let _timer = item_timer(cx.tcx(), self, def_id);
                 cx.codegen_static(def_id, cx.tcx().is_mutable_static(def_id));
             }
             MonoItem::GlobalAsm(item_id) => {
@@ -88,6 +91,7 @@ fn define<Bx: BuilderMethods<'a, 'tcx>>(&self, cx: &'a Bx::CodegenCx) {
                 }
             }
             MonoItem::Fn(instance) => {
+                let _timer = item_timer(cx.tcx(), self, instance.def_id());
                 base::codegen_instance::<Bx>(&cx, instance);
             }
         }
@@ -145,3 +149,11 @@ fn to_raw_string(&self) -> String {
         }
     }
 }
+
+/// Attributes the time spent defining `item` to `def_id`, for `-Z time-items`.
+fn item_timer<'tcx>(tcx: TyCtxt<'tcx>, item: &MonoItem<'tcx>, def_id: DefId) -> ItemTimer<'tcx> {
+    if tcx.sess.opts.debugging_opts.time_items.is_some() {
+        tcx.sess.item_timings.record_symbol(def_id, item.symbol_name(tcx).name);
+    }
+    tcx.sess.item_timer(ItemPhase::Codegen, def_id)
+}
@@ -79,23 +79,35 @@
 //! invocation) and allocate the corresponding strings together with a mapping
 //! for `DepNodeIndex as StringId`.
 //!
+//! ## Chrome Trace Output
+//!
+//! With `-Z self-profile-chrome-trace`, the recorded events are additionally
+//! written in the Chrome Trace Event format, which can be opened in a browser
+//! without any extra tools. See the `chrome_trace` module for details.
+//!
 //! [mm]: https://github.com/rust-lang/measureme/
 
+mod chrome_trace;
+
 use crate::cold_path;
 use crate::fx::FxHashMap;
 
+use chrome_trace::{ChromeTrace, ChromeTraceString, EventName};
+
 use std::borrow::Borrow;
 use std::collections::hash_map::Entry;
 use std::convert::Into;
 use std::error::Error;
 use std::fs;
+use std::io;
 use std::path::Path;
 use std::process;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 pub use measureme::EventId;
-use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
+use measureme::event_id::SEPARATOR_BYTE;
+use measureme::{Profiler, SerializableString, StringComponent, StringId};
 use parking_lot::RwLock;
 use smallvec::SmallVec;
 
@@ -277,7 +289,7 @@ pub fn generic_activity_with_arg<A>(
         A: Borrow<str> + Into<String>,
     {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_arg = profiler.get_or_alloc_cached_string(event_arg);
@@ -320,7 +332,7 @@ pub fn generic_activity_with_arg_recorder<F>(
     {
         // Ensure this event will only be recorded when self-profiling is turned on.
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
 
             // Ensure the closure to create event arguments will only be called when argument
@@ -359,7 +371,7 @@ pub fn artifact_size<A>(&self, artifact_kind: &str, artifact_name: A, size: u64)
         A: Borrow<str> + Into<String>,
     {
         drop(self.exec(EventFilter::ARTIFACT_SIZES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(artifact_kind);
             let event_arg = profiler.get_or_alloc_cached_string(artifact_name);
             let event_id = builder.from_label_and_arg(event_label, event_arg);
@@ -371,6 +383,14 @@ pub fn artifact_size<A>(&self, artifact_kind: &str, artifact_name: A, size: u64)
                 thread_id,
                 size,
             );
+            if let Some(chrome_trace) = &profiler.chrome_trace {
+                chrome_trace.record_integer(
+                    profiler.artifact_size_event_kind,
+                    EventName::Id(event_id.to_string_id()),
+                    thread_id,
+                    size,
+                );
+            }
 
             TimingGuard::none()
         }))
@@ -383,7 +403,7 @@ pub fn generic_activity_with_args(
         event_args: &[String],
     ) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_args: Vec<_> = event_args
@@ -470,6 +490,13 @@ fn instant_query_event(
                 EventId::from_virtual(event_id),
                 thread_id,
             );
+            if let Some(chrome_trace) = &profiler.chrome_trace {
+                chrome_trace.record_instant(
+                    event_kind(profiler),
+                    EventName::QueryInvocation(query_invocation_id.0),
+                    thread_id,
+                );
+            }
 
             TimingGuard::none()
         }));
@@ -502,6 +529,15 @@ pub fn llvm_recording_enabled(&self) -> bool {
     pub fn get_self_profiler(&self) -> Option<Arc<SelfProfiler>> {
         self.profiler.clone()
     }
+
+    /// Writes the Chrome trace of `-Z self-profile-chrome-trace`, see
+    /// `SelfProfiler::write_chrome_trace`.
+    pub fn write_chrome_trace(&self) -> io::Result<()> {
+        match &self.profiler {
+            Some(profiler) => profiler.write_chrome_trace(),
+            None => Ok(()),
+        }
+    }
 }
 
 /// A helper for recording costly arguments to self-profiling events. Used with
@@ -544,6 +580,10 @@ pub struct SelfProfiler {
     query_blocked_event_kind: StringId,
     query_cache_hit_event_kind: StringId,
     artifact_size_event_kind: StringId,
+
+    // This field is `Some` if the events are also written in the Chrome Trace
+    // Event format.
+    chrome_trace: Option<ChromeTrace>,
 }
 
 impl SelfProfiler {
@@ -551,6 +591,7 @@ pub fn new(
         output_directory: &Path,
         crate_name: Option<&str>,
         event_filters: &Option<Vec<String>>,
+        chrome_trace: bool,
     ) -> Result<SelfProfiler, Box<dyn Error + Send + Sync>> {
         fs::create_dir_all(output_directory)?;
 
@@ -559,14 +600,27 @@ pub fn new(
         let path = output_directory.join(&filename);
         let profiler = Profiler::new(&path)?;
 
-        let query_event_kind = profiler.alloc_string("Query");
-        let generic_activity_event_kind = profiler.alloc_string("GenericActivity");
-        let incremental_load_result_event_kind = profiler.alloc_string("IncrementalLoadResult");
-        let incremental_result_hashing_event_kind =
-            profiler.alloc_string("IncrementalResultHashing");
-        let query_blocked_event_kind = profiler.alloc_string("QueryBlocked");
-        let query_cache_hit_event_kind = profiler.alloc_string("QueryCacheHit");
-        let artifact_size_event_kind = profiler.alloc_string("ArtifactSize");
+        let chrome_trace = chrome_trace
+            .then(|| {
+                let filename = format!("{}-{}.trace.json", crate_name, process::id());
+                ChromeTrace::new(output_directory.join(&filename))
+            })
+            .transpose()?;
+
+        let alloc_string = |s: &str| {
+            let string_id = profiler.alloc_string(s);
+            if let Some(chrome_trace) = &chrome_trace {
+                chrome_trace.record_string(string_id, s);
+            }
+            string_id
+        };
+        let query_event_kind = alloc_string("Query");
+        let generic_activity_event_kind = alloc_string("GenericActivity");
+        let incremental_load_result_event_kind = alloc_string("IncrementalLoadResult");
+        let incremental_result_hashing_event_kind = alloc_string("IncrementalResultHashing");
+        let query_blocked_event_kind = alloc_string("QueryBlocked");
+        let query_cache_hit_event_kind = alloc_string("QueryCacheHit");
+        let artifact_size_event_kind = alloc_string("ArtifactSize");
 
         let mut event_filter_mask = EventFilter::empty();
 
@@ -612,13 +666,21 @@ pub fn new(
             query_blocked_event_kind,
             query_cache_hit_event_kind,
             artifact_size_event_kind,
+            chrome_trace,
         })
     }
 
     /// Allocates a new string in the profiling data. Does not do any caching
     /// or deduplication.
-    pub fn alloc_string<STR: SerializableString + ?Sized>(&self, s: &STR) -> StringId {
-        self.profiler.alloc_string(s)
+    pub fn alloc_string<STR>(&self, s: &STR) -> StringId
+    where
+        STR: SerializableString + ChromeTraceString + ?Sized,
+    {
+        let string_id = self.profiler.alloc_string(s);
+        if let Some(chrome_trace) = &self.chrome_trace {
+            chrome_trace.record_string(string_id, s);
+        }
+        string_id
     }
 
     /// Gets a `StringId` for the given string. This method makes sure that
@@ -644,13 +706,16 @@ pub fn get_or_alloc_cached_string<A>(&self, s: A) -> StringId
         match string_cache.entry(s.into()) {
             Entry::Occupied(e) => *e.get(),
             Entry::Vacant(e) => {
-                let string_id = self.profiler.alloc_string(&e.key()[..]);
+                let string_id = self.alloc_string(&e.key()[..]);
                 *e.insert(string_id)
             }
         }
     }
 
     pub fn map_query_invocation_id_to_string(&self, from: QueryInvocationId, to: StringId) {
+        if let Some(chrome_trace) = &self.chrome_trace {
+            chrome_trace.map_query_invocation_id(from.0, to);
+        }
         let from = StringId::new_virtual(from.0);
         self.profiler.map_virtual_to_concrete_string(from, to);
     }
@@ -659,6 +724,13 @@ pub fn bulk_map_query_invocation_id_to_single_string<I>(&self, from: I, to: Stri
     where
         I: Iterator<Item = QueryInvocationId> + ExactSizeIterator,
     {
+        if let Some(chrome_trace) = &self.chrome_trace {
+            let from: Vec<u32> = from.map(|qid| qid.0).collect();
+            chrome_trace.bulk_map_query_invocation_ids(from.iter().copied(), to);
+            let from = from.into_iter().map(StringId::new_virtual);
+            self.profiler.bulk_map_virtual_to_single_concrete_string(from, to);
+            return;
+        }
         let from = from.map(|qid| StringId::new_virtual(qid.0));
         self.profiler.bulk_map_virtual_to_single_concrete_string(from, to);
     }
@@ -667,13 +739,56 @@ pub fn query_key_recording_enabled(&self) -> bool {
         self.event_filter_mask.contains(EventFilter::QUERY_KEYS)
     }
 
+    /// Writes the Chrome trace from the events recorded so far, if enabled.
+    /// Events recorded afterwards are not included in the trace.
+    pub fn write_chrome_trace(&self) -> io::Result<()> {
+        match &self.chrome_trace {
+            Some(chrome_trace) => chrome_trace.write(),
+            None => Ok(()),
+        }
+    }
+
     pub fn event_id_builder(&self) -> EventIdBuilder<'_> {
-        EventIdBuilder::new(&self.profiler)
+        EventIdBuilder { profiler: self }
+    }
+}
+
+/// Builds `EventId`s like `measureme::EventIdBuilder`, but allocates the
+/// strings through the `SelfProfiler`, so that they end up in the Chrome trace
+/// as well.
+pub struct EventIdBuilder<'p> {
+    profiler: &'p SelfProfiler,
+}
+
+impl EventIdBuilder<'_> {
+    #[inline]
+    pub fn from_label(&self, label: StringId) -> EventId {
+        EventId::from_label(label)
+    }
+
+    pub fn from_label_and_arg(&self, label: StringId, arg: StringId) -> EventId {
+        self.from_label_and_args(label, &[arg])
+    }
+
+    pub fn from_label_and_args(&self, label: StringId, args: &[StringId]) -> EventId {
+        // The label is followed by a separator and the argument for every
+        // argument, like in `measureme::EventIdBuilder`.
+        let mut components =
+            SmallVec::<[StringComponent<'_>; 3]>::with_capacity(1 + 2 * args.len());
+        components.push(StringComponent::Ref(label));
+        for &arg in args {
+            components.push(StringComponent::Value(SEPARATOR_BYTE));
+            components.push(StringComponent::Ref(arg));
+        }
+        EventId::from_label(self.profiler.alloc_string(&components[..]))
     }
 }
 
 #[must_use]
-pub struct TimingGuard<'a>(Option<measureme::TimingGuard<'a>>);
+pub struct TimingGuard<'a>(
+    Option<measureme::TimingGuard<'a>>,
+    Option<chrome_trace::IntervalGuard<'a>>,
+);
 
 impl<'a> TimingGuard<'a> {
     #[inline]
@@ -684,25 +799,36 @@ pub fn start(
     ) -> TimingGuard<'a> {
         let thread_id = get_thread_id();
         let raw_profiler = &profiler.profiler;
+        let chrome_trace_guard = profiler.chrome_trace.as_ref().map(|chrome_trace| {
+            chrome_trace.start_interval(
+                event_kind,
+                EventName::Id(event_id.to_string_id()),
+                thread_id,
+            )
+        });
         let timing_guard =
             raw_profiler.start_recording_interval_event(event_kind, event_id, thread_id);
-        TimingGuard(Some(timing_guard))
+        TimingGuard(Some(timing_guard), chrome_trace_guard)
     }
 
     #[inline]
     pub fn finish_with_query_invocation_id(self, query_invocation_id: QueryInvocationId) {
-        if let Some(guard) = self.0 {
+        let TimingGuard(guard, chrome_trace_guard) = self;
+        if let Some(guard) = guard {
             cold_path(|| {
                 let event_id = StringId::new_virtual(query_invocation_id.0);
                 let event_id = EventId::from_virtual(event_id);
                 guard.finish_with_override_event_id(event_id);
+                if let Some(chrome_trace_guard) = chrome_trace_guard {
+                    chrome_trace_guard.finish_with_query_invocation_id(query_invocation_id.0);
+                }
             });
         }
     }
 
     #[inline]
     pub fn none() -> TimingGuard<'a> {
-        TimingGuard(None)
+        TimingGuard(None, None)
     }
 
     #[inline(always)]
@@ -0,0 +1,531 @@
+//! Writes the events recorded by the `SelfProfiler` in the [Chrome Trace Event
+//! format][format] in addition to the `measureme` format, enabled with
+//! `-Z self-profile-chrome-trace`. The resulting `.trace.json` file can be
+//! opened directly in `chrome://tracing` or in the [Perfetto UI][perfetto],
+//! without having to install the `measureme` tools first.
+//!
+//! `measureme` only writes the strings making up event ids to disk, so they
+//! cannot be read back when the trace is written. Instead, every string
+//! allocated through the `SelfProfiler` is mirrored here, together with the
+//! mapping from query invocation ids to the strings allocated for them at the
+//! end of the compilation session.
+//!
+//! Query invocations are only mapped to strings at the end of the session, so
+//! events can't be converted to JSON as they arrive. Every thread instead
+//! encodes its events into a buffer of its own, without synchronizing with
+//! other threads, and appends them to a temporary spool file next to the trace
+//! whenever the buffer fills up. Events refer to strings by an index into a
+//! table of the `StringId`s used by their thread, so that they are encoded as
+//! plain integers. When the session finishes, the spool file is read back and
+//! converted to JSON, and any error is reported as a warning of the session.
+//!
+//! The events are converted as follows:
+//!
+//! - Interval events (queries, generic activities, LLVM passes, incremental
+//!   cache loads, ...) become complete (`X`) events.
+//! - Instant events (query cache hits) become instant (`i`) events.
+//! - Integer events (artifact sizes) become instant events with the size in
+//!   their arguments.
+//!
+//! The event kind becomes the category of an event. The label of an event id
+//! becomes the name of the event, and its arguments (query keys, function
+//! arguments) become the arguments `arg0`, `arg1`, etc. of the event, like in
+//! the `crox` tool of `measureme`.
+//!
+//! [format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+//! [perfetto]: https://ui.perfetto.dev
+
+use crate::fx::FxHashMap;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::mem;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Instant;
+
+use measureme::event_id::SEPARATOR_BYTE;
+use measureme::{StringComponent, StringId};
+use parking_lot::Mutex;
+use rustc_serialize::json::{Json, ToJson};
+
+/// A part of a string allocated through the `SelfProfiler`.
+pub enum Component {
+    Value(String),
+    Ref(StringId),
+}
+
+/// The strings that can be allocated through the `SelfProfiler`, which have
+/// to be mirrored for the Chrome trace.
+pub trait ChromeTraceString {
+    fn components(&self) -> Vec<Component>;
+}
+
+impl ChromeTraceString for str {
+    fn components(&self) -> Vec<Component> {
+        vec![Component::Value(self.to_owned())]
+    }
+}
+
+impl ChromeTraceString for [StringComponent<'_>] {
+    fn components(&self) -> Vec<Component> {
+        self.iter()
+            .map(|component| match *component {
+                StringComponent::Value(s) => Component::Value(s.to_owned()),
+                StringComponent::Ref(id) => Component::Ref(id),
+            })
+            .collect()
+    }
+}
+
+impl<const N: usize> ChromeTraceString for [StringComponent<'_>; N] {
+    fn components(&self) -> Vec<Component> {
+        self[..].components()
+    }
+}
+
+/// The event id of a recorded event.
+#[derive(Clone, Copy)]
+pub enum EventName {
+    /// A string allocated through the `SelfProfiler`.
+    Id(StringId),
+    /// A query invocation, mapped to a string at the end of the session.
+    QueryInvocation(u32),
+}
+
+#[derive(Clone, Copy)]
+enum Timestamp {
+    Interval { start: u64, end: u64 },
+    Instant(u64),
+    Integer { timestamp: u64, value: u64 },
+}
+
+/// An event as encoded in the spool file, where strings are referred to by
+/// their index in the table of the thread buffer that recorded the event.
+struct Record {
+    buffer: u32,
+    kind: u32,
+    name: RecordName,
+    timestamp: Timestamp,
+}
+
+enum RecordName {
+    String(u32),
+    QueryInvocation(u32),
+}
+
+/// The length of an encoded `Record`.
+const RECORD_LEN: usize = 30;
+
+/// The number of bytes a thread buffers before appending them to the spool
+/// file.
+const BATCH_LEN: usize = RECORD_LEN * 2048;
+
+impl Record {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let (name_tag, name) = match self.name {
+            RecordName::String(index) => (0, index),
+            RecordName::QueryInvocation(id) => (1, id),
+        };
+        let (timestamp_tag, a, b) = match self.timestamp {
+            Timestamp::Interval { start, end } => (0, start, end),
+            Timestamp::Instant(timestamp) => (1, timestamp, 0),
+            Timestamp::Integer { timestamp, value } => (2, timestamp, value),
+        };
+        out.extend_from_slice(&self.buffer.to_le_bytes());
+        out.extend_from_slice(&self.kind.to_le_bytes());
+        out.push(name_tag);
+        out.extend_from_slice(&name.to_le_bytes());
+        out.push(timestamp_tag);
+        out.extend_from_slice(&a.to_le_bytes());
+        out.extend_from_slice(&b.to_le_bytes());
+    }
+
+    fn decode(bytes: &[u8; RECORD_LEN]) -> io::Result<Record> {
+        let u32_at = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+        let u64_at = |i: usize| u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap());
+        let name = match bytes[8] {
+            0 => RecordName::String(u32_at(9)),
+            1 => RecordName::QueryInvocation(u32_at(9)),
+            _ => return Err(corrupted_spool()),
+        };
+        let (a, b) = (u64_at(14), u64_at(22));
+        let timestamp = match bytes[13] {
+            0 => Timestamp::Interval { start: a, end: b },
+            1 => Timestamp::Instant(a),
+            2 => Timestamp::Integer { timestamp: a, value: b },
+            _ => return Err(corrupted_spool()),
+        };
+        Ok(Record { buffer: u32_at(0), kind: u32_at(4), name, timestamp })
+    }
+}
+
+fn corrupted_spool() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "corrupted event spool file")
+}
+
+#[derive(Default)]
+struct Strings {
+    strings: FxHashMap<StringId, Vec<Component>>,
+    query_invocations: FxHashMap<u32, StringId>,
+}
+
+/// The events recorded by one thread for one trace.
+struct ThreadBuffer {
+    trace_id: usize,
+    /// The index of this buffer in `ChromeTrace::threads`.
+    index: u32,
+    /// Only locked by other threads when the trace is written.
+    events: Mutex<ThreadEvents>,
+}
+
+#[derive(Default)]
+struct ThreadEvents {
+    /// The `StringId`s the events of this thread refer to by index.
+    string_ids: Vec<StringId>,
+    string_indices: FxHashMap<StringId, u32>,
+    /// Encoded events that haven't been appended to the spool file yet.
+    pending: Vec<u8>,
+}
+
+impl ThreadEvents {
+    fn string_index(&mut self, id: StringId) -> u32 {
+        let string_ids = &mut self.string_ids;
+        *self.string_indices.entry(id).or_insert_with(|| {
+            string_ids.push(id);
+            (string_ids.len() - 1) as u32
+        })
+    }
+}
+
+struct Thread {
+    thread_id: u32,
+    name: String,
+    buffer: Arc<ThreadBuffer>,
+}
+
+struct Spool {
+    file: BufWriter<File>,
+    /// The first error that occurred while appending to the spool file, which
+    /// is reported when the trace is written.
+    error: Option<io::Error>,
+}
+
+static NEXT_TRACE_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The buffer of the current thread for the trace it last recorded an
+    /// event for.
+    static THREAD_BUFFER: RefCell<Option<Arc<ThreadBuffer>>> = RefCell::new(None);
+}
+
+pub struct ChromeTrace {
+    id: usize,
+    path: PathBuf,
+    spool_path: PathBuf,
+    start: Instant,
+    strings: Mutex<Strings>,
+    threads: Mutex<Vec<Thread>>,
+    /// This field is `None` once the trace has been written.
+    spool: Mutex<Option<Spool>>,
+}
+
+impl ChromeTrace {
+    pub fn new(path: PathBuf) -> io::Result<ChromeTrace> {
+        let mut spool_path = path.clone().into_os_string();
+        spool_path.push(".events");
+        let spool_path = PathBuf::from(spool_path);
+        let file = BufWriter::new(File::create(&spool_path)?);
+        Ok(ChromeTrace {
+            id: NEXT_TRACE_ID.fetch_add(1, Ordering::Relaxed),
+            path,
+            spool_path,
+            start: Instant::now(),
+            strings: Default::default(),
+            threads: Default::default(),
+            spool: Mutex::new(Some(Spool { file, error: None })),
+        })
+    }
+
+    pub fn record_string<STR: ChromeTraceString + ?Sized>(&self, id: StringId, s: &STR) {
+        self.strings.lock().strings.insert(id, s.components());
+    }
+
+    pub fn map_query_invocation_id(&self, from: u32, to: StringId) {
+        self.strings.lock().query_invocations.insert(from, to);
+    }
+
+    pub fn bulk_map_query_invocation_ids(&self, from: impl Iterator<Item = u32>, to: StringId) {
+        let mut strings = self.strings.lock();
+        for from in from {
+            strings.query_invocations.insert(from, to);
+        }
+    }
+
+    fn nanos_since_start(&self) -> u64 {
+        self.start.elapsed().as_nanos() as u64
+    }
+
+    fn register_thread(&self, thread_id: u32) -> Arc<ThreadBuffer> {
+        let mut threads = self.threads.lock();
+        let buffer = Arc::new(ThreadBuffer {
+            trace_id: self.id,
+            index: threads.len() as u32,
+            events: Default::default(),
+        });
+        let name =
+            thread::current().name().map_or_else(|| format!("thread {}", thread_id), String::from);
+        threads.push(Thread { thread_id, name, buffer: buffer.clone() });
+        buffer
+    }
+
+    fn record(&self, kind: StringId, name: EventName, thread_id: u32, timestamp: Timestamp) {
+        THREAD_BUFFER.with(|buffer| {
+            let mut buffer = buffer.borrow_mut();
+            if !matches!(&*buffer, Some(buffer) if buffer.trace_id == self.id) {
+                *buffer = Some(self.register_thread(thread_id));
+            }
+            let buffer = buffer.as_ref().unwrap();
+
+            let mut events = buffer.events.lock();
+            let kind = events.string_index(kind);
+            let name = match name {
+                EventName::Id(id) => RecordName::String(events.string_index(id)),
+                EventName::QueryInvocation(id) => RecordName::QueryInvocation(id),
+            };
+            Record { buffer: buffer.index, kind, name, timestamp }.encode(&mut events.pending);
+            if events.pending.len() >= BATCH_LEN {
+                self.append_to_spool(&mut events.pending);
+            }
+        });
+    }
+
+    /// Appends the given encoded events to the spool file, or drops them if
+    /// the trace has already been written.
+    fn append_to_spool(&self, pending: &mut Vec<u8>) {
+        if let Some(spool) = &mut *self.spool.lock() {
+            if spool.error.is_none() {
+                if let Err(err) = spool.file.write_all(pending) {
+                    spool.error = Some(err);
+                }
+            }
+        }
+        pending.clear();
+    }
+
+    pub fn start_interval(
+        &self,
+        kind: StringId,
+        name: EventName,
+        thread_id: u32,
+    ) -> IntervalGuard<'_> {
+        IntervalGuard { trace: self, kind, name, thread_id, start: self.nanos_since_start() }
+    }
+
+    pub fn record_instant(&self, kind: StringId, name: EventName, thread_id: u32) {
+        let timestamp = Timestamp::Instant(self.nanos_since_start());
+        self.record(kind, name, thread_id, timestamp);
+    }
+
+    pub fn record_integer(&self, kind: StringId, name: EventName, thread_id: u32, value: u64) {
+        let timestamp = Timestamp::Integer { timestamp: self.nanos_since_start(), value };
+        self.record(kind, name, thread_id, timestamp);
+    }
+
+    /// Writes the trace from the events recorded so far. Events recorded
+    /// afterwards are dropped, and writing the trace again does nothing.
+    pub fn write(&self) -> io::Result<()> {
+        self.write_inner().map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("failed to write Chrome trace to `{}`: {}", self.path.display(), err),
+            )
+        })
+    }
+
+    fn write_inner(&self) -> io::Result<()> {
+        // Append the events still buffered by every thread, and take the
+        // tables of the `StringId`s they refer to.
+        let mut thread_names = BTreeMap::new();
+        let mut string_ids = Vec::new();
+        for thread in self.threads.lock().iter() {
+            let mut events = thread.buffer.events.lock();
+            self.append_to_spool(&mut events.pending);
+            thread_names.entry(thread.thread_id).or_insert_with(|| thread.name.clone());
+            string_ids.push((thread.thread_id, mem::take(&mut events.string_ids)));
+        }
+
+        let Some(mut spool) = self.spool.lock().take() else { return Ok(()) };
+        if let Some(err) = spool.error.take() {
+            return Err(err);
+        }
+        spool.file.flush()?;
+        drop(spool);
+
+        let result = self.convert_spool(&thread_names, &string_ids);
+        let _ = fs::remove_file(&self.spool_path);
+        result
+    }
+
+    fn convert_spool(
+        &self,
+        thread_names: &BTreeMap<u32, String>,
+        string_ids: &[(u32, Vec<StringId>)],
+    ) -> io::Result<()> {
+        let strings = self.strings.lock();
+        let mut resolved: FxHashMap<StringId, String> = FxHashMap::default();
+        let mut resolve_cached = |id: StringId| {
+            resolved
+                .entry(id)
+                .or_insert_with(|| {
+                    let mut s = String::new();
+                    resolve(&strings.strings, id, &mut s);
+                    s
+                })
+                .clone()
+        };
+
+        let pid = process::id();
+        let mut file = BufWriter::new(File::create(&self.path)?);
+        write!(file, "{{\"traceEvents\":[")?;
+
+        let mut first = true;
+        for (&thread_id, name) in thread_names {
+            let mut args = BTreeMap::new();
+            args.insert("name".to_string(), name.to_json());
+            let mut object = BTreeMap::new();
+            object.insert("ph".to_string(), "M".to_json());
+            object.insert("name".to_string(), "thread_name".to_json());
+            object.insert("pid".to_string(), pid.to_json());
+            object.insert("tid".to_string(), thread_id.to_json());
+            object.insert("args".to_string(), Json::Object(args));
+            write_event(&mut file, &mut first, Json::Object(object))?;
+        }
+
+        let mut spool = BufReader::new(File::open(&self.spool_path)?);
+        let mut bytes = [0; RECORD_LEN];
+        loop {
+            match spool.read_exact(&mut bytes) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+            let record = Record::decode(&bytes)?;
+            let (thread_id, string_ids) =
+                string_ids.get(record.buffer as usize).ok_or_else(corrupted_spool)?;
+            let string_id =
+                |index: u32| string_ids.get(index as usize).copied().ok_or_else(corrupted_spool);
+
+            let id = match record.name {
+                RecordName::String(index) => Some(string_id(index)?),
+                RecordName::QueryInvocation(id) => strings.query_invocations.get(&id).copied(),
+            };
+            let name = id.map_or_else(|| "<unknown>".to_string(), &mut resolve_cached);
+            let kind = resolve_cached(string_id(record.kind)?);
+
+            // The label and the arguments of an event id are separated by
+            // `SEPARATOR_BYTE`, see `EventIdBuilder`.
+            let mut parts = name.split(SEPARATOR_BYTE);
+            let label = parts.next().unwrap();
+            let mut args: BTreeMap<String, Json> =
+                parts.enumerate().map(|(i, arg)| (format!("arg{}", i), arg.to_json())).collect();
+
+            let mut object = BTreeMap::new();
+            object.insert("name".to_string(), label.to_json());
+            object.insert("cat".to_string(), kind.to_json());
+            object.insert("pid".to_string(), pid.to_json());
+            object.insert("tid".to_string(), thread_id.to_json());
+            match record.timestamp {
+                Timestamp::Interval { start, end } => {
+                    object.insert("ph".to_string(), "X".to_json());
+                    object.insert("ts".to_string(), micros(start));
+                    object.insert("dur".to_string(), micros(end - start));
+                }
+                Timestamp::Instant(timestamp) => {
+                    object.insert("ph".to_string(), "i".to_json());
+                    object.insert("s".to_string(), "t".to_json());
+                    object.insert("ts".to_string(), micros(timestamp));
+                }
+                Timestamp::Integer { timestamp, value } => {
+                    object.insert("ph".to_string(), "i".to_json());
+                    object.insert("s".to_string(), "t".to_json());
+                    object.insert("ts".to_string(), micros(timestamp));
+                    args.insert("value".to_string(), value.to_json());
+                }
+            }
+            if !args.is_empty() {
+                object.insert("args".to_string(), Json::Object(args));
+            }
+            write_event(&mut file, &mut first, Json::Object(object))?;
+        }
+
+        writeln!(file, "\n],\"displayTimeUnit\":\"ms\"}}")?;
+        file.flush()
+    }
+}
+
+impl Drop for ChromeTrace {
+    fn drop(&mut self) {
+        // The trace is written and the spool file removed by `write`, unless
+        // the session ended before. Nothing can be reported at this point.
+        if self.spool.get_mut().is_some() {
+            let _ = fs::remove_file(&self.spool_path);
+        }
+    }
+}
+
+/// Appends the string with the given id to `out`.
+fn resolve(strings: &FxHashMap<StringId, Vec<Component>>, id: StringId, out: &mut String) {
+    match strings.get(&id) {
+        Some(components) => {
+            for component in components {
+                match component {
+                    Component::Value(s) => out.push_str(s),
+                    Component::Ref(id) => resolve(strings, *id, out),
+                }
+            }
+        }
+        None => out.push_str("<unknown>"),
+    }
+}
+
+/// Chrome trace timestamps and durations are in microseconds.
+fn micros(nanos: u64) -> Json {
+    Json::F64(nanos as f64 / 1000.0)
+}
+
+fn write_event(file: &mut impl Write, first: &mut bool, event: Json) -> io::Result<()> {
+    if !*first {
+        write!(file, ",")?;
+    }
+    *first = false;
+    write!(file, "\n{}", event)
+}
+
+/// Records an interval event when dropped.
+pub struct IntervalGuard<'a> {
+    trace: &'a ChromeTrace,
+    kind: StringId,
+    name: EventName,
+    thread_id: u32,
+    start: u64,
+}
+
+impl IntervalGuard<'_> {
+    pub fn finish_with_query_invocation_id(mut self, query_invocation_id: u32) {
+        self.name = EventName::QueryInvocation(query_invocation_id);
+    }
+}
+
+impl Drop for IntervalGuard<'_> {
+    fn drop(&mut self) {
+        let end = self.trace.nanos_since_start();
+        let timestamp = Timestamp::Interval { start: self.start, end };
+        self.trace.record(self.kind, self.name, self.thread_id, timestamp);
+    }
+}
@@ -28,6 +28,7 @@
 use rustc_lint::LintStore;
 use rustc_log::stdout_isatty;
 use rustc_metadata::locator;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_save_analysis as save;
 use rustc_save_analysis::DumpHandler;
 use rustc_serialize::json::ToJson;
@@ -405,9 +406,23 @@ fn run_compiler(
 
             queries.ongoing_codegen()?;
 
+            if sess.opts.debugging_opts.time_items.is_some() {
+                // The items are described now, since the report is only
+                // printed after LLVM is done and the `TyCtxt` is gone.
+                queries.global_ctxt()?.peek_mut().enter(|tcx| {
+                    sess.item_timings.describe_items(|def_id| {
+                        let path = with_no_trimmed_paths!(tcx.def_path_str(def_id));
+                        let span = tcx.def_span(def_id);
+                        (path, sess.source_map().span_to_embeddable_string(span))
+                    })
+                });
+            }
             if sess.opts.debugging_opts.print_type_sizes {
                 sess.code_stats.print_type_sizes();
             }
+            if sess.opts.prints.contains(&PrintRequest::TypeSizes) {
+                sess.code_stats.print_type_sizes_json();
+            }
 
             let linker = queries.linker()?;
             Ok(Some(linker))
@@ -418,6 +433,10 @@ fn run_compiler(
             linker.link()?
         }
 
+        if let Some(count) = sess.opts.debugging_opts.time_items {
+            sess.item_timings.print_report(count);
+        }
+
         if sess.opts.debugging_opts.perf_stats {
             sess.print_perf_stats();
         }
@@ -637,9 +656,12 @@ fn print_crate_info(
     temps_dir: &Option<PathBuf>,
 ) -> Compilation {
     use rustc_session::config::PrintRequest::*;
-    // NativeStaticLibs and LinkArgs are special - printed during linking
+    // NativeStaticLibs and LinkArgs are special - printed during linking,
+    // as are MonomorphizationReport and TypeSizes, printed during codegen
     // (empty iterator returns true)
-    if sess.opts.prints.iter().all(|&p| p == NativeStaticLibs || p == LinkArgs) {
+    if sess.opts.prints.iter().all(|&p| {
+        p == NativeStaticLibs || p == LinkArgs || p == MonomorphizationReport || p == TypeSizes
+    }) {
         return Compilation::Continue;
     }
 
@@ -731,6 +753,8 @@ fn print_crate_info(
             // Any output here interferes with Cargo's parsing of other printed output
             NativeStaticLibs => {}
             LinkArgs => {}
+            MonomorphizationReport => {}
+            TypeSizes => {}
         }
     }
     Compilation::Stop
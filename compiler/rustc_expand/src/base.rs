@@ -931,6 +931,7 @@ fn pre_expansion_lint(
         sess: &Session,
         registered_tools: &FxHashSet<Ident>,
         node_id: NodeId,
+        inner_span: Span,
         attrs: &[Attribute],
         items: &[P<Item>],
         name: &str,
@@ -1104,6 +1104,7 @@ fn wrap_flat_map_node_noop_flat_map(
                         ecx.sess,
                         ecx.resolver.registered_tools(),
                         ecx.current_expansion.lint_node_id,
+                        spans.inner_span,
                         &attrs,
                         &items,
                         ident.name.as_str(),
@@ -297,6 +297,10 @@ pub struct BuiltinAttribute {
         must_not_suspend, Normal, template!(Word, NameValueStr: "reason"), WarnFollowing,
         must_not_suspend, experimental!(must_not_suspend)
     ),
+    gated!(
+        blocking, Normal, template!(Word, NameValueStr: "reason"), WarnFollowing,
+        blocking, experimental!(blocking)
+    ),
     ungated!(
         deprecated, Normal,
         template!(
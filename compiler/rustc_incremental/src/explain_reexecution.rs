@@ -0,0 +1,200 @@
+//! Explains why queries were re-executed in an incremental session, enabled
+//! with `-Zexplain-query-reexecution=<dir>`.
+//!
+//! While trying to mark a node of the previous dependency graph as green, the
+//! dependency graph records the dependency that prevented it (see
+//! `DepGraph::invalidation_chains`). Following these records from a
+//! re-executed query leads to the input that changed. For this report, the
+//! changed input is the deepest red node in such a chain that refers to a
+//! definition: typically a HIR owner of the local crate, which is attributed
+//! to the source file containing it, or a definition from an upstream crate,
+//! which means the metadata of that crate changed. Chains without such a
+//! node end in a global input, like the crate's HIR as a whole.
+//!
+//! Two files are written to the given directory:
+//!
+//! - `reexecuted-queries.json` lists every re-executed query, the chain of
+//!   nodes that caused its re-execution and the changed input.
+//! - `reexecuted-queries.txt` summarizes how many queries of which kind were
+//!   re-executed because of each changed input, grouped by source file.
+
+use rustc_hir::def_id::{DefPathHash, LocalDefId};
+use rustc_middle::dep_graph::{DepNode, FingerprintStyle};
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_serialize::json::{Json, ToJson};
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const JSON_FILENAME: &str = "reexecuted-queries.json";
+const SUMMARY_FILENAME: &str = "reexecuted-queries.txt";
+
+/// The changed input at the end of an invalidation chain.
+enum ChangedInput {
+    /// A definition of the local crate, and the source file containing it.
+    Definition { def_path: String, file: String },
+    /// Something from the metadata of an upstream crate.
+    CrateMetadata { krate: String },
+    /// A node not referring to a definition that still exists.
+    Other { node: String },
+}
+
+impl ChangedInput {
+    /// The key this input is grouped under in the summary.
+    fn summary_key(&self) -> String {
+        match self {
+            ChangedInput::Definition { file, .. } => file.clone(),
+            ChangedInput::CrateMetadata { krate } => format!("<metadata of crate `{}`>", krate),
+            ChangedInput::Other { node } => format!("<{}>", node),
+        }
+    }
+}
+
+impl ToJson for ChangedInput {
+    fn to_json(&self) -> Json {
+        let mut object = BTreeMap::new();
+        match self {
+            ChangedInput::Definition { def_path, file } => {
+                object.insert("kind".to_string(), "definition".to_json());
+                object.insert("def".to_string(), def_path.to_json());
+                object.insert("file".to_string(), file.to_json());
+            }
+            ChangedInput::CrateMetadata { krate } => {
+                object.insert("kind".to_string(), "crate_metadata".to_json());
+                object.insert("crate".to_string(), krate.to_json());
+            }
+            ChangedInput::Other { node } => {
+                object.insert("kind".to_string(), "other".to_json());
+                object.insert("node".to_string(), node.to_json());
+            }
+        }
+        Json::Object(object)
+    }
+}
+
+/// Looks up the definition a node of the previous session refers to, if it
+/// refers to a definition of the local crate that still exists.
+fn local_def_id(tcx: TyCtxt<'_>, node: &DepNode) -> Option<LocalDefId> {
+    if node.kind.fingerprint_style(tcx) != FingerprintStyle::DefPathHash {
+        return None;
+    }
+    let hash = DefPathHash(node.hash.into());
+    if hash.stable_crate_id() != tcx.sess.local_stable_crate_id() {
+        return None;
+    }
+    let local_def_index =
+        tcx.definitions_untracked().def_path_hash_to_def_index_map().get(&hash)?;
+    Some(LocalDefId { local_def_index })
+}
+
+/// Formats a node of the previous session. Unlike the `Debug` implementation
+/// of `DepNode`, this does not panic for nodes referring to definitions that
+/// do not exist anymore.
+fn node_to_string(tcx: TyCtxt<'_>, node: &DepNode) -> String {
+    if node.kind.fingerprint_style(tcx) != FingerprintStyle::DefPathHash {
+        return format!("{:?}", node);
+    }
+    match local_def_id(tcx, node) {
+        Some(def_id) => {
+            let def_path = with_no_trimmed_paths!(tcx.def_path_str(def_id.to_def_id()));
+            format!("{:?}({})", node.kind, def_path)
+        }
+        None => format!("{:?}({})", node.kind, node.hash),
+    }
+}
+
+fn changed_input(tcx: TyCtxt<'_>, chain: &[DepNode]) -> ChangedInput {
+    for node in chain.iter().rev() {
+        // A node that could not be marked green may still have turned out to
+        // be green after being re-executed, so it did not change itself.
+        if node.kind.fingerprint_style(tcx) != FingerprintStyle::DefPathHash
+            || !tcx.dep_graph.is_red(node)
+        {
+            continue;
+        }
+
+        if let Some(def_id) = local_def_id(tcx, node) {
+            let span = tcx.def_span(def_id);
+            let file = tcx.sess.source_map().lookup_source_file(span.lo());
+            return ChangedInput::Definition {
+                def_path: with_no_trimmed_paths!(tcx.def_path_str(def_id.to_def_id())),
+                file: file.name.prefer_remapped().to_string(),
+            };
+        }
+
+        let stable_crate_id = DefPathHash(node.hash.into()).stable_crate_id();
+        if let Some(&krate) =
+            tcx.crates(()).iter().find(|&&krate| tcx.stable_crate_id(krate) == stable_crate_id)
+        {
+            return ChangedInput::CrateMetadata { krate: tcx.crate_name(krate).to_string() };
+        }
+    }
+
+    ChangedInput::Other { node: node_to_string(tcx, chain.last().unwrap()) }
+}
+
+/// Writes the reports about the queries re-executed in this session to `dir`.
+pub fn write_reports(tcx: TyCtxt<'_>, dir: &Path) {
+    let mut reexecuted: Vec<_> = tcx
+        .dep_graph
+        .invalidation_chains()
+        .into_iter()
+        .map(|chain| {
+            let input = changed_input(tcx, &chain);
+            let chain: Vec<String> = chain.iter().map(|node| node_to_string(tcx, node)).collect();
+            (chain, input)
+        })
+        .collect();
+    reexecuted.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    // Count the re-executed queries by changed input, and by query kind.
+    let mut by_input: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+    for (chain, input) in &reexecuted {
+        let query = &chain[0];
+        let kind = query.split('(').next().unwrap().to_string();
+        *by_input.entry(input.summary_key()).or_default().entry(kind).or_default() += 1;
+    }
+
+    let json = Json::Array(
+        reexecuted
+            .into_iter()
+            .map(|(chain, input)| {
+                let mut object = BTreeMap::new();
+                object.insert("query".to_string(), chain[0].to_json());
+                object.insert("chain".to_string(), chain.to_json());
+                object.insert("input".to_string(), input.to_json());
+                Json::Object(object)
+            })
+            .collect(),
+    );
+
+    let mut by_input: Vec<_> = by_input
+        .into_iter()
+        .map(|(input, kinds)| (kinds.values().sum::<usize>(), input, kinds))
+        .collect();
+    by_input.sort_by(|(a_count, a, _), (b_count, b, _)| b_count.cmp(a_count).then(a.cmp(b)));
+
+    let mut summary = String::new();
+    for (count, input, kinds) in by_input {
+        writeln!(summary, "{}: {} re-executed queries", input, count).unwrap();
+        let mut kinds: Vec<_> = kinds.into_iter().collect();
+        kinds.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        for (kind, count) in kinds {
+            writeln!(summary, "    {:>6} {}", count, kind).unwrap();
+        }
+    }
+
+    let result = fs::create_dir_all(dir)
+        .and_then(|()| fs::write(dir.join(JSON_FILENAME), format!("{}\n", json.pretty())))
+        .and_then(|()| fs::write(dir.join(SUMMARY_FILENAME), summary));
+    if let Err(err) = result {
+        tcx.sess.err(&format!(
+            "failed to write query re-execution reports to `{}`: {}",
+            dir.display(),
+            err
+        ));
+    }
+}
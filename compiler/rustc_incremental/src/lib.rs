@@ -14,6 +14,7 @@
 
 mod assert_dep_graph;
 pub mod assert_module_sources;
+mod explain_reexecution;
 mod persist;
 
 use assert_dep_graph::assert_dep_graph;
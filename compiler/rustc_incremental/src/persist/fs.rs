@@ -118,6 +118,8 @@
 
 use rand::{thread_rng, RngCore};
 
+use super::shared_cache;
+
 #[cfg(test)]
 mod tests;
 
@@ -256,6 +258,45 @@ pub fn prepare_session_directory(
         let source_directory = find_source_directory(&crate_dir, &source_directories_already_tried);
 
         let Some(source_directory) = source_directory else {
+            // There's nowhere to copy from, but the shared cache might have
+            // been populated from another working directory.
+            if let Some(cache_dir) = &sess.opts.debugging_opts.incremental_shared_cache {
+                let index_file_name = shared_cache::index_file_name(sess, &crate_dir);
+                match shared_cache::import_session_directory(
+                    sess,
+                    cache_dir,
+                    &index_file_name,
+                    &session_dir,
+                ) {
+                    Ok(true) => {
+                        debug!("successfully imported data from shared cache");
+                        sess.init_incr_comp_session(session_dir, directory_lock, true);
+                        return Ok(());
+                    }
+                    Ok(false) => {}
+                    Err(err) => {
+                        sess.warn(&format!(
+                            "Failed to import incremental compilation session \
+                             from shared cache `{}`: {}",
+                            cache_dir.display(),
+                            err
+                        ));
+
+                        // Start over with an empty session directory, since
+                        // it may contain some of the imported files.
+                        if let Err(err) = safe_remove_dir_all(&session_dir) {
+                            sess.warn(&format!(
+                                "Failed to delete partly initialized \
+                                 session dir `{}`: {}",
+                                session_dir.display(),
+                                err
+                            ));
+                        }
+                        create_dir(sess, &session_dir, "session")?;
+                    }
+                }
+            }
+
             // There's nowhere to copy from, we're done
             debug!(
                 "no source directory found. Continuing with empty session \
@@ -375,6 +416,27 @@ pub fn finalize_session_directory(sess: &Session, svh: Svh) {
         Ok(_) => {
             debug!("finalize_session_directory() - directory renamed successfully");
 
+            // Publish the session directory to the shared cache while we
+            // still hold the lock, so that it cannot be garbage collected.
+            if let Some(cache_dir) = &sess.opts.debugging_opts.incremental_shared_cache {
+                let index_file_name =
+                    shared_cache::index_file_name(sess, new_path.parent().unwrap());
+                if let Err(err) = shared_cache::export_session_directory(
+                    sess,
+                    cache_dir,
+                    &index_file_name,
+                    &new_path,
+                ) {
+                    sess.warn(&format!(
+                        "Error exporting incremental compilation session \
+                         directory `{}` to shared cache `{}`: {}",
+                        new_path.display(),
+                        cache_dir.display(),
+                        err
+                    ));
+                }
+            }
+
             // This unlocks the directory
             sess.finalize_incr_comp_session(new_path);
         }
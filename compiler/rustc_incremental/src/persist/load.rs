@@ -13,6 +13,7 @@
 use super::data::*;
 use super::file_format;
 use super::fs::*;
+use super::shared_cache;
 use super::work_product;
 
 type WorkProductMap = FxHashMap<WorkProductId, WorkProduct>;
@@ -142,7 +143,7 @@ pub fn load_dep_graph(sess: &Session) -> DepGraphFuture {
     // Fortunately, we just checked that this isn't the case.
     let path = dep_graph_path(&sess);
     let report_incremental_info = sess.opts.debugging_opts.incremental_info;
-    let expected_hash = sess.opts.dep_tracking_hash(false);
+    let expected_hash = shared_cache::commandline_args_hash(sess);
 
     let mut prev_work_products = FxHashMap::default();
     let nightly_build = sess.is_nightly_build();
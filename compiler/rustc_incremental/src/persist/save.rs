@@ -11,6 +11,7 @@
 use super::dirty_clean;
 use super::file_format;
 use super::fs::*;
+use super::shared_cache;
 use super::work_product;
 
 /// Saves and writes the [`DepGraph`] to the file system.
@@ -43,6 +44,12 @@ pub fn save_dep_graph(tcx: TyCtxt<'_>) {
             tcx.dep_graph.print_incremental_info()
         }
 
+        if let Some(dir) = &sess.opts.debugging_opts.explain_query_reexecution {
+            sess.time("explain_query_reexecution", || {
+                crate::explain_reexecution::write_reports(tcx, dir)
+            });
+        }
+
         join(
             move || {
                 sess.time("incr_comp_persist_result_cache", || {
@@ -185,7 +192,7 @@ pub fn build_dep_graph(
     }
 
     // First encode the commandline arguments hash
-    if let Err(err) = sess.opts.dep_tracking_hash(false).encode(&mut encoder) {
+    if let Err(err) = shared_cache::commandline_args_hash(sess).encode(&mut encoder) {
         sess.err(&format!(
             "failed to write dependency graph hash `{}`: {}",
             path_buf.display(),
@@ -201,5 +208,6 @@ pub fn build_dep_graph(
         encoder,
         sess.opts.debugging_opts.query_dep_graph,
         sess.opts.debugging_opts.incremental_info,
+        sess.opts.debugging_opts.explain_query_reexecution.is_some(),
     ))
 }
@@ -0,0 +1,256 @@
+//! A content-addressed store for incremental compilation caches that can be
+//! shared between working directories and machines, enabled with
+//! `-Zincremental-shared-cache=<dir>`.
+//!
+//! The session directories managed in `fs` live below the directory passed to
+//! `-Cincremental` and rely on file locks and timestamps, which makes them
+//! unsuitable for sharing between several checkouts of the same sources or
+//! between CI workers. The shared cache complements them: whenever a session
+//! directory is finalized, all of its files (the dependency graph, the query
+//! result cache, the work product index and the work product object files)
+//! are also published into the shared cache. If a later session does not find
+//! a finalized session directory of its own, it initializes its session
+//! directory from the shared cache instead of starting from scratch.
+//!
+//! The shared cache has the following layout:
+//!
+//! ```text
+//! {shared-cache-dir}/objects/{hh}/{content-hash}
+//! {shared-cache-dir}/index/{crate-name}-{stable-crate-id}-{options-hash}
+//! ```
+//!
+//! Every file is stored under the stable hash of its contents, so identical
+//! files published from different sessions are stored only once, and files
+//! never change once they have been stored. An index file lists the name and
+//! content hash of every file of a session directory. Index files are named
+//! like the crate directories in `fs`, followed by a stable hash of all tracked
+//! command line options and the compiler version, so that only sessions that
+//! could have produced the same dependency graph share an index file.
+//!
+//! Nothing in the shared cache is trusted when importing it: the content hash
+//! of every index entry has to be a well-formed hash, and every object has to
+//! hash to its name. Otherwise, the object is removed from the shared cache so
+//! that it can be published again, and the session starts from scratch.
+//!
+//! Both objects and index files are written to a temporary file first and
+//! then renamed into place, so no locking is needed: concurrent readers see
+//! either the previous or the new version of an index file, and all the
+//! objects it refers to are already in place when it becomes visible.
+//!
+//! Absolute paths end up in the dependency graph, in the query result cache
+//! and in the debuginfo of the object files. In order for two working
+//! directories to share a cache, they thus have to be remapped to the same
+//! path with `--remap-path-prefix`. Remapped paths are hashed without their
+//! local part, so the tracked options then hash to the same index file name.
+
+use rustc_data_structures::base_n;
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::stable_hasher::StableHasher;
+use rustc_fs_util::link_or_copy;
+use rustc_session::Session;
+
+use std::fs as std_fs;
+use std::hash::{Hash, Hasher};
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use rand::{thread_rng, RngCore};
+
+use super::file_format;
+
+const OBJECTS_DIR: &str = "objects";
+const INDEX_DIR: &str = "index";
+
+/// Returns the hash of the command line arguments that is stored in the
+/// dependency graph, which has to match for the dependency graph to be reused.
+///
+/// When sharing the cache, the local side of `--remap-path-prefix` differs
+/// between working directories, so only the remapped prefixes are hashed.
+pub fn commandline_args_hash(sess: &Session) -> u64 {
+    if sess.opts.debugging_opts.incremental_shared_cache.is_none() {
+        return sess.opts.dep_tracking_hash(false);
+    }
+
+    let mut opts = sess.opts.clone();
+    for (from, _) in &mut opts.remap_path_prefix {
+        *from = PathBuf::new();
+    }
+    opts.dep_tracking_hash(false)
+}
+
+/// Returns the name of the index file within the shared cache for the session
+/// directories in `crate_dir`.
+pub fn index_file_name(sess: &Session, crate_dir: &Path) -> String {
+    let mut hasher = StableHasher::new();
+    commandline_args_hash(sess).hash(&mut hasher);
+    file_format::rustc_version(sess.is_nightly_build()).hash(&mut hasher);
+    let key: Fingerprint = hasher.finish();
+
+    // The crate directory is named `{crate-name}-{stable-crate-id}`.
+    let mut name = crate_dir.file_name().unwrap().to_string_lossy().into_owned();
+    name.push('-');
+    base_n::push_str(key.to_smaller_hash() as u128, base_n::CASE_INSENSITIVE, &mut name);
+    name
+}
+
+/// The length of the names of the objects in the shared cache.
+const CONTENT_HASH_LEN: usize = 32;
+
+/// Returns the stable hash of the given file contents, as used for the names
+/// of the objects in the shared cache.
+fn content_hash(contents: &[u8]) -> String {
+    let mut hasher = StableHasher::new();
+    hasher.write(contents);
+    let hash: Fingerprint = hasher.finish();
+    let (hi, lo) = hash.as_value();
+    format!("{:016x}{:016x}", hi, lo)
+}
+
+/// Returns whether `hash` could have been returned by `content_hash`, which
+/// guarantees that the object path derived from it stays within the cache.
+fn is_content_hash(hash: &str) -> bool {
+    hash.len() == CONTENT_HASH_LEN && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+fn malformed_index() -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, "malformed index file")
+}
+
+fn object_path(cache_dir: &Path, hash: &str) -> PathBuf {
+    cache_dir.join(OBJECTS_DIR).join(&hash[..2]).join(hash)
+}
+
+/// Moves `temp_path` to `path` with a single rename, so that other processes
+/// never observe a partially written file.
+fn publish(temp_path: &Path, path: &Path) -> io::Result<()> {
+    let result = std_fs::rename(temp_path, path);
+    if result.is_err() {
+        let _ = std_fs::remove_file(temp_path);
+    }
+    result
+}
+
+fn temp_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!(".{}.{:08x}.tmp", name, thread_rng().next_u32()))
+}
+
+/// Initializes the (empty) session directory from the index file
+/// `index_file_name` of the shared cache. Returns `false` if the shared cache
+/// does not contain such an index file.
+///
+/// An error is returned if the index file is malformed or an object does not
+/// match its content hash, in which case the session directory may have been
+/// partially initialized and has to be emptied.
+pub fn import_session_directory(
+    sess: &Session,
+    cache_dir: &Path,
+    index_file_name: &str,
+    session_dir: &Path,
+) -> io::Result<bool> {
+    let index = match std_fs::read_to_string(cache_dir.join(INDEX_DIR).join(index_file_name)) {
+        Ok(index) => index,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err),
+    };
+
+    let mut files = 0;
+    let mut corrupted = None;
+    for line in index.lines() {
+        let (hash, file_name) = line.split_once(' ').ok_or_else(malformed_index)?;
+        if !is_content_hash(hash)
+            || matches!(file_name, "" | "." | "..")
+            || file_name.contains(&['/', '\\'][..])
+        {
+            return Err(malformed_index());
+        }
+
+        debug!("importing `{}` from shared cache object {}", file_name, hash);
+        let object_path = object_path(cache_dir, hash);
+        let contents = std_fs::read(&object_path)?;
+        if content_hash(&contents) != hash {
+            // Remove the corrupted object, so that the next session exporting
+            // the same file stores it again. The remaining objects are still
+            // checked, so that all corrupted ones are removed at once.
+            let _ = std_fs::remove_file(&object_path);
+            corrupted = Some(object_path);
+            continue;
+        }
+        // The contents are written rather than linked, so that they can't
+        // change after they have been checked.
+        std_fs::write(session_dir.join(file_name), &contents)?;
+        files += 1;
+    }
+
+    if let Some(object_path) = corrupted {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("object `{}` does not match its content hash", object_path.display()),
+        ));
+    }
+
+    if sess.opts.debugging_opts.incremental_info {
+        eprintln!(
+            "[incremental] session directory: {} files imported from shared cache `{}`",
+            files,
+            cache_dir.display()
+        );
+    }
+
+    Ok(true)
+}
+
+/// Publishes all files of the finalized session directory into the shared
+/// cache, and makes the index file `index_file_name` refer to them.
+pub fn export_session_directory(
+    sess: &Session,
+    cache_dir: &Path,
+    index_file_name: &str,
+    session_dir: &Path,
+) -> io::Result<()> {
+    let _timer = sess.timer("incr_comp_export_to_shared_cache");
+
+    let index_dir = cache_dir.join(INDEX_DIR);
+    std_fs::create_dir_all(&index_dir)?;
+
+    let mut entries = Vec::new();
+    let mut files_stored = 0;
+    for entry in session_dir.read_dir()? {
+        let entry = entry?;
+        let file_name = entry.file_name().into_string().map_err(|_| {
+            io::Error::new(ErrorKind::InvalidData, "non-UTF-8 file name in session directory")
+        })?;
+        let path = entry.path();
+
+        let hash = content_hash(&std_fs::read(&path)?);
+        let object_path = object_path(cache_dir, &hash);
+        if !object_path.exists() {
+            let object_dir = object_path.parent().unwrap();
+            std_fs::create_dir_all(object_dir)?;
+            let temp_path = temp_path(object_dir, &hash);
+            link_or_copy(&path, &temp_path)?;
+            publish(&temp_path, &object_path)?;
+            files_stored += 1;
+        }
+
+        entries.push(format!("{} {}\n", hash, file_name));
+    }
+
+    // Sort the entries, so that publishing the same session directory twice
+    // results in the same index file.
+    entries.sort();
+
+    let temp_path = temp_path(&index_dir, index_file_name);
+    std_fs::write(&temp_path, entries.concat())?;
+    publish(&temp_path, &index_dir.join(index_file_name))?;
+
+    if sess.opts.debugging_opts.incremental_info {
+        eprintln!(
+            "[incremental] shared cache: {} of {} files stored in `{}`",
+            files_stored,
+            entries.len(),
+            cache_dir.display()
+        );
+    }
+
+    Ok(())
+}
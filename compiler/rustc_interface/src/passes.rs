@@ -34,7 +34,7 @@
 use rustc_session::search_paths::PathKind;
 use rustc_session::{Limit, Session};
 use rustc_span::symbol::{sym, Symbol};
-use rustc_span::FileName;
+use rustc_span::{FileName, Span};
 use rustc_trait_selection::traits;
 use rustc_typeck as typeck;
 use tempfile::Builder as TempFileBuilder;
@@ -220,6 +220,12 @@ pub fn register_plugins<'a>(
     );
     register_lints(sess, &mut lint_store);
 
+    sess.time("lint_plugin_loading", || {
+        for path in &sess.opts.debugging_opts.lint_plugin {
+            util::load_lint_plugin(sess, &mut lint_store, Path::new(path));
+        }
+    });
+
     let registrars =
         sess.time("plugin_loading", || plugin::load::load_plugins(sess, metadata_loader, &krate));
     sess.time("plugin_registration", || {
@@ -261,11 +267,13 @@ fn pre_expansion_lint(
         sess: &Session,
         registered_tools: &RegisteredTools,
         node_id: ast::NodeId,
+        inner_span: Span,
         attrs: &[ast::Attribute],
         items: &[rustc_ast::ptr::P<ast::Item>],
         name: &str,
     ) {
-        pre_expansion_lint(sess, self.0, registered_tools, (node_id, attrs, items), name);
+        let check_node = (node_id, inner_span, attrs, items);
+        pre_expansion_lint(sess, self.0, registered_tools, check_node, name);
     }
 }
 
@@ -281,6 +289,9 @@ pub fn configure_and_expand(
     resolver: &mut Resolver<'_>,
 ) -> Result<ast::Crate> {
     tracing::trace!("configure_and_expand");
+    // The lint names of the lint config are checked once here rather than
+    // wherever its tables are applied, which may be many times.
+    lint_store.check_lint_config(sess, resolver.registered_tools());
     pre_expansion_lint(sess, lint_store, resolver.registered_tools(), &krate, crate_name);
     rustc_builtin_macros::register_builtin_macros(resolver);
 
@@ -944,6 +955,7 @@ fn analysis(tcx: TyCtxt<'_>, (): ()) -> Result<()> {
                         // maybe move the check to a MIR pass?
                         tcx.ensure().check_mod_liveness(module);
                         tcx.ensure().check_mod_intrinsics(module);
+                        tcx.ensure().check_mod_fmt_custom_specs(module);
                     });
                 });
             }
@@ -657,15 +657,18 @@ macro_rules! untracked {
     untracked!(dump_mir_exclude_pass_number, true);
     untracked!(dump_mir_graphviz, true);
     untracked!(emit_stack_sizes, true);
+    untracked!(explain_query_reexecution, Some(PathBuf::from("reexecution")));
     untracked!(future_incompat_test, true);
     untracked!(hir_stats, true);
     untracked!(identify_regions, true);
     untracked!(incremental_ignore_spans, true);
     untracked!(incremental_info, true);
+    untracked!(incremental_shared_cache, Some(PathBuf::from("shared-cache")));
     untracked!(incremental_verify_ich, true);
     untracked!(input_stats, true);
     untracked!(keep_hygiene_data, true);
     untracked!(link_native_libraries, false);
+    untracked!(lint_config, Some(PathBuf::from("lints.toml")));
     untracked!(llvm_time_trace, true);
     untracked!(ls, true);
     untracked!(macro_backtrace, true);
@@ -687,6 +690,7 @@ macro_rules! untracked {
     untracked!(query_dep_graph, true);
     untracked!(save_analysis, true);
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
+    untracked!(self_profile_chrome_trace, true);
     untracked!(self_profile_events, Some(vec![String::new()]));
     untracked!(span_debug, true);
     untracked!(span_free_formats, true);
@@ -694,6 +698,7 @@ macro_rules! untracked {
     untracked!(terminal_width, Some(80));
     untracked!(threads, 99);
     untracked!(time, true);
+    untracked!(time_items, Some(10));
     untracked!(time_llvm_passes, true);
     untracked!(time_passes, true);
     untracked!(trace_macros, true);
@@ -807,6 +812,7 @@ macro_rules! tracked_no_crate_hash {
             assert_non_crate_hash_different(&reference, &opts);
         };
     }
+    tracked_no_crate_hash!(lint_plugin, vec![String::from("liblints.so")]);
     tracked_no_crate_hash!(no_codegen, true);
 }
 
@@ -6,6 +6,8 @@
 use rustc_data_structures::jobserver;
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::registry::Registry;
+use rustc_lint::plugin::{LintPluginDeclaration, LINT_PLUGIN_DECLARATION_SYMBOL};
+use rustc_lint::LintStore;
 #[cfg(parallel_compiler)]
 use rustc_middle::ty::tls;
 use rustc_parse::validate_attr;
@@ -235,6 +237,39 @@ fn load_backend_from_dylib(path: &Path) -> MakeBackendFn {
     *backend_sym
 }
 
+/// Loads the lint plugin at `path` and registers its lints in `lint_store`,
+/// see `rustc_lint::plugin`.
+pub fn load_lint_plugin(sess: &Session, lint_store: &mut LintStore, path: &Path) {
+    let lib = match unsafe { Library::new(path) } {
+        Ok(lib) => lib,
+        Err(err) => {
+            sess.err(&format!("couldn't load lint plugin {:?}: {}", path, err));
+            return;
+        }
+    };
+
+    let declaration =
+        match unsafe { lib.get::<*const LintPluginDeclaration>(LINT_PLUGIN_DECLARATION_SYMBOL) } {
+            Ok(declaration) => unsafe { *declaration.into_raw() },
+            Err(err) => {
+                sess.err(&format!(
+                    "couldn't load lint plugin {:?}: {}; lint plugins have to be declared \
+                     with `rustc_lint::declare_lint_plugin!`",
+                    path, err
+                ));
+                return;
+            }
+        };
+
+    // Intentionally leak the dynamic library, like codegen backends: the
+    // registered lint passes live as long as the lint store.
+    mem::forget(lib);
+
+    if let Err(err) = lint_store.register_lint_plugin(unsafe { &*declaration }) {
+        sess.err(&format!("couldn't load lint plugin {:?}: {}", path, err));
+    }
+}
+
 /// Get the codegen backend based on the name and specified sysroot.
 ///
 /// A name of `None` indicates that the default backend should be used.
@@ -0,0 +1,118 @@
+use crate::{LateContext, LateLintPass, LintContext};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, GeneratorKind};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::Instance;
+use rustc_span::symbol::sym;
+
+declare_lint! {
+    /// The `blocking_in_async` lint detects calls to functions marked with
+    /// `#[blocking]` from within `async` functions, blocks and closures.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,edition2018
+    /// #![warn(blocking_in_async)]
+    ///
+    /// use std::time::Duration;
+    ///
+    /// async fn wait() {
+    ///     std::thread::sleep(Duration::from_millis(10));
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Futures are polled by executors that usually run many of them on a
+    /// small number of threads. A function marked with `#[blocking]`, like
+    /// `std::thread::sleep`, `std::fs::read` or `Mutex::lock`, can block the
+    /// thread it is called on for a long time, during which no other future
+    /// polled by this thread makes progress. Blocking calls should be moved
+    /// to a thread that is allowed to block, or replaced by an asynchronous
+    /// alternative provided by the executor.
+    ///
+    /// Calls of trait methods are checked against the method of the impl they
+    /// resolve to, so that `Read::read` on a `TcpStream` is reported but not
+    /// on a `&[u8]`. Methods provided by the trait are only reported if the
+    /// impl overrides them. Closures within an `async` body are not checked,
+    /// since they are often passed to functions running them on such a thread.
+    ///
+    /// The standard library provides neither a way to run a blocking call on
+    /// another thread from within a future nor asynchronous alternatives, so
+    /// the suggested `spawn_blocking` stands for the function of the executor
+    /// in use that does so.
+    pub BLOCKING_IN_ASYNC,
+    Allow,
+    "calls to `#[blocking]` functions within `async` bodies"
+}
+
+declare_lint_pass!(BlockingInAsync => [BLOCKING_IN_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingInAsync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (def_id, substs) = match expr.kind {
+            ExprKind::Call(callee, _) => match callee.kind {
+                ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, callee.hir_id) {
+                    Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => {
+                        (def_id, cx.typeck_results().node_substs(callee.hir_id))
+                    }
+                    _ => return,
+                },
+                _ => return,
+            },
+            ExprKind::MethodCall(..) => {
+                match cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    Some(def_id) => (def_id, cx.typeck_results().node_substs(expr.hir_id)),
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        // Only the body of the generator itself is checked, not the bodies of
+        // closures nested within it.
+        let Some(body_id) = cx.enclosing_body else { return };
+        let async_kind = match cx.tcx.hir().body(body_id).generator_kind {
+            Some(GeneratorKind::Async(async_kind)) => async_kind,
+            _ => return,
+        };
+
+        // A call of a trait method is checked against the method of the impl
+        // it resolves to, if it can be resolved in this generic context.
+        let def_id = match Instance::resolve(cx.tcx, cx.param_env, def_id, substs) {
+            Ok(Some(instance)) => instance.def_id(),
+            _ => def_id,
+        };
+
+        let attrs = cx.tcx.get_attrs(def_id);
+        let Some(attr) = attrs.iter().find(|attr| attr.has_name(sym::blocking)) else { return };
+
+        cx.struct_span_lint(BLOCKING_IN_ASYNC, expr.span, |lint| {
+            let msg = format!(
+                "call to blocking function `{}` in an {}",
+                with_no_trimmed_paths!(cx.tcx.def_path_str(def_id)),
+                async_kind
+            );
+            let mut err = lint.build(&msg);
+            err.span_label(expr.span, "this call blocks the thread polling the future");
+            if let Some(note) = attr.value_str() {
+                err.note(note.as_str());
+            }
+            if !expr.span.from_expansion() {
+                err.multipart_suggestion(
+                    "consider running this call on a thread that is allowed to block, \
+                     using the `spawn_blocking` function of your executor",
+                    vec![
+                        (expr.span.shrink_to_lo(), "spawn_blocking(move || ".into()),
+                        (expr.span.shrink_to_hi(), ").await".into()),
+                    ],
+                    Applicability::HasPlaceholders,
+                );
+            }
+            err.emit();
+        });
+    }
+}
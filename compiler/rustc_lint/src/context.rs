@@ -22,7 +22,7 @@
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sync;
 use rustc_errors::{add_elided_lifetime_in_path_suggestion, struct_span_err};
-use rustc_errors::{Applicability, MultiSpan, SuggestionStyle};
+use rustc_errors::{Applicability, DiagnosticBuilder, MultiSpan, SuggestionStyle};
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::{CrateNum, DefId};
@@ -35,6 +35,7 @@
 use rustc_middle::ty::{self, print::Printer, subst::GenericArg, RegisteredTools, Ty, TyCtxt};
 use rustc_session::lint::BuiltinLintDiagnostics;
 use rustc_session::lint::{FutureIncompatibleInfo, Level, Lint, LintBuffer, LintId};
+use rustc_session::lint_config;
 use rustc_session::Session;
 use rustc_span::lev_distance::find_best_match_for_name;
 use rustc_span::symbol::{sym, Ident, Symbol};
@@ -335,7 +336,79 @@ pub fn check_lint_name_cmdline(
             .emit();
             return;
         }
-        let db = match self.check_lint_name(lint_name_only, tool_name, registered_tools) {
+
+        if let Some(mut db) = self.check_external_lint_name(sess, lint_name, registered_tools) {
+            let msg = format!(
+                "requested on the command line with `{} {}`",
+                match level {
+                    Level::Allow => "-A",
+                    Level::Warn => "-W",
+                    Level::ForceWarn => "--force-warn",
+                    Level::Deny => "-D",
+                    Level::Forbid => "-F",
+                    Level::Expect(_) => {
+                        unreachable!("lints with the level of `expect` should not run this code");
+                    }
+                },
+                lint_name
+            );
+            db.note(&msg);
+            db.emit();
+        }
+    }
+
+    /// Checks the validity of the lint names of every table of the lint config
+    /// passed with `-Z lint-config`, including overrides that don't apply to
+    /// any file.
+    pub fn check_lint_config(&self, sess: &Session, registered_tools: &RegisteredTools) {
+        let Some(lint_config) = &sess.opts.lint_config else { return };
+        for (lint_name, level) in &lint_config.lints {
+            self.check_lint_name_config(sess, lint_name, *level, None, registered_tools);
+        }
+        for lint_override in &lint_config.overrides {
+            for (lint_name, level) in &lint_override.lints {
+                self.check_lint_name_config(
+                    sess,
+                    lint_name,
+                    *level,
+                    Some(&lint_override.pattern),
+                    registered_tools,
+                );
+            }
+        }
+    }
+
+    /// Checks the validity of a lint name of the lint config, `pattern` being
+    /// the pattern of the override the name appears in.
+    fn check_lint_name_config(
+        &self,
+        sess: &Session,
+        lint_name: &str,
+        level: Level,
+        pattern: Option<&str>,
+        registered_tools: &RegisteredTools,
+    ) {
+        if let Some(mut db) = self.check_external_lint_name(sess, lint_name, registered_tools) {
+            db.note(&format!(
+                "requested by `{} = \"{}\"` in the `{}` table of the lint config",
+                lint_name,
+                level.as_str(),
+                lint_config::table_name(pattern)
+            ));
+            db.emit();
+        }
+    }
+
+    /// Checks the validity of a lint name that does not come from an
+    /// attribute, returning the diagnostic to emit for an invalid name.
+    fn check_external_lint_name<'s>(
+        &self,
+        sess: &'s Session,
+        lint_name: &str,
+        registered_tools: &RegisteredTools,
+    ) -> Option<DiagnosticBuilder<'s, ()>> {
+        let (tool_name, lint_name_only) = parse_lint_and_tool_name(lint_name);
+        match self.check_lint_name(lint_name_only, tool_name, registered_tools) {
             CheckLintNameResult::Ok(_) => None,
             CheckLintNameResult::Warning(ref msg, _) => Some(sess.struct_warn(msg)),
             CheckLintNameResult::NoLint(suggestion) => {
@@ -367,25 +440,6 @@ pub fn check_lint_name_cmdline(
                 )
                 .forget_guarantee(),
             ),
-        };
-
-        if let Some(mut db) = db {
-            let msg = format!(
-                "requested on the command line with `{} {}`",
-                match level {
-                    Level::Allow => "-A",
-                    Level::Warn => "-W",
-                    Level::ForceWarn => "--force-warn",
-                    Level::Deny => "-D",
-                    Level::Forbid => "-F",
-                    Level::Expect(_) => {
-                        unreachable!("lints with the level of `expect` should not run this code");
-                    }
-                },
-                lint_name
-            );
-            db.note(&msg);
-            db.emit();
         }
     }
 
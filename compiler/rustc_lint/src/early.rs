@@ -70,6 +70,23 @@ fn with_lint_attrs<F>(&mut self, id: ast::NodeId, attrs: &'a [ast::Attribute], f
         self.context.builder.pop(push);
     }
 
+    /// Like `with_lint_attrs`, for a module loaded from the file containing
+    /// `inner_span`, which is subject to the overrides of the lint config for
+    /// that file.
+    fn with_module_lint_attrs<F>(
+        &mut self,
+        id: ast::NodeId,
+        inner_span: Span,
+        attrs: &'a [ast::Attribute],
+        f: F,
+    ) where
+        F: FnOnce(&mut Self),
+    {
+        let config_push = self.context.builder.push_config_overrides(inner_span);
+        self.with_lint_attrs(id, attrs, f);
+        self.context.builder.pop(config_push);
+    }
+
     fn enter_attrs(&mut self, attrs: &'a [ast::Attribute]) {
         debug!("early context: enter_attrs({:?})", attrs);
         run_early_pass!(self, enter_lint_attrs, attrs);
@@ -90,11 +107,17 @@ fn visit_param(&mut self, param: &'a ast::Param) {
     }
 
     fn visit_item(&mut self, it: &'a ast::Item) {
-        self.with_lint_attrs(it.id, &it.attrs, |cx| {
+        let check_item = |cx: &mut Self| {
             run_early_pass!(cx, check_item, it);
             ast_visit::walk_item(cx, it);
             run_early_pass!(cx, check_item_post, it);
-        })
+        };
+        match it.kind {
+            ast::ItemKind::Mod(_, ast::ModKind::Loaded(_, ast::Inline::No, spans)) => {
+                self.with_module_lint_attrs(it.id, spans.inner_span, &it.attrs, check_item)
+            }
+            _ => self.with_lint_attrs(it.id, &it.attrs, check_item),
+        }
     }
 
     fn visit_foreign_item(&mut self, it: &'a ast::ForeignItem) {
@@ -339,6 +362,9 @@ impl EarlyLintPass for EarlyLintPassObjects<'_> {
 /// This trait generalizes over those nodes.
 pub trait EarlyCheckNode<'a>: Copy {
     fn id(self) -> ast::NodeId;
+    /// The span of the contents of the node, which determines the overrides
+    /// of the lint config applying to it.
+    fn inner_span(self) -> Span;
     fn attrs<'b>(self) -> &'b [ast::Attribute]
     where
         'a: 'b;
@@ -351,6 +377,9 @@ impl<'a> EarlyCheckNode<'a> for &'a ast::Crate {
     fn id(self) -> ast::NodeId {
         ast::CRATE_NODE_ID
     }
+    fn inner_span(self) -> Span {
+        self.spans.inner_span
+    }
     fn attrs<'b>(self) -> &'b [ast::Attribute]
     where
         'a: 'b,
@@ -367,22 +396,25 @@ fn check<'b>(self, cx: &mut EarlyContextAndPass<'b, impl EarlyLintPass>)
     }
 }
 
-impl<'a> EarlyCheckNode<'a> for (ast::NodeId, &'a [ast::Attribute], &'a [P<ast::Item>]) {
+impl<'a> EarlyCheckNode<'a> for (ast::NodeId, Span, &'a [ast::Attribute], &'a [P<ast::Item>]) {
     fn id(self) -> ast::NodeId {
         self.0
     }
+    fn inner_span(self) -> Span {
+        self.1
+    }
     fn attrs<'b>(self) -> &'b [ast::Attribute]
     where
         'a: 'b,
     {
-        self.1
+        self.2
     }
     fn check<'b>(self, cx: &mut EarlyContextAndPass<'b, impl EarlyLintPass>)
     where
         'a: 'b,
     {
-        walk_list!(cx, visit_attribute, self.1);
-        walk_list!(cx, visit_item, self.2);
+        walk_list!(cx, visit_attribute, self.2);
+        walk_list!(cx, visit_item, self.3);
     }
 }
 
@@ -406,7 +438,9 @@ fn early_lint_node<'a>(
         pass,
     };
 
-    cx.with_lint_attrs(check_node.id(), check_node.attrs(), |cx| check_node.check(cx));
+    cx.with_module_lint_attrs(check_node.id(), check_node.inner_span(), check_node.attrs(), |cx| {
+        check_node.check(cx)
+    });
     cx.context.buffered
 }
 
@@ -20,7 +20,7 @@
 use rustc_session::parse::{add_feature_diagnostics, feature_err};
 use rustc_session::Session;
 use rustc_span::symbol::{sym, Symbol};
-use rustc_span::{Span, DUMMY_SP};
+use rustc_span::{FileName, Span, DUMMY_SP};
 use tracing::debug;
 
 fn lint_levels(tcx: TyCtxt<'_>, (): ()) -> LintLevelMap {
@@ -32,12 +32,14 @@ fn lint_levels(tcx: TyCtxt<'_>, (): ()) -> LintLevelMap {
 
     builder.levels.id_to_set.reserve(krate.owners.len() + 1);
 
+    let config_push = builder.levels.push_config_overrides(tcx.hir().root_module().spans.inner_span);
     let push =
         builder.levels.push(tcx.hir().attrs(hir::CRATE_HIR_ID), true, Some(hir::CRATE_HIR_ID));
 
     builder.levels.register_id(hir::CRATE_HIR_ID);
     tcx.hir().walk_toplevel_module(&mut builder);
     builder.levels.pop(push);
+    builder.levels.pop(config_push);
 
     builder.levels.update_unstable_expectation_ids();
     builder.levels.build_map()
@@ -106,6 +108,13 @@ fn process_command_line(&mut self, sess: &Session, store: &LintStore) {
 
         self.cur =
             self.sets.list.push(LintSet { specs: FxHashMap::default(), parent: COMMAND_LINE });
+
+        // The `[lints]` table of the lint config is applied before the command
+        // line flags, so that they take precedence over it.
+        if let Some(lint_config) = &sess.opts.lint_config {
+            self.insert_config_levels(&lint_config.lints, None);
+        }
+
         for &(ref lint_name, level) in &sess.opts.lint_opts {
             store.check_lint_name_cmdline(sess, &lint_name, level, self.registered_tools);
             let orig_level = level;
@@ -129,6 +138,74 @@ fn process_command_line(&mut self, sess: &Session, store: &LintStore) {
         }
     }
 
+    /// Inserts the levels of a table of the lint config into the current
+    /// specs, `pattern` being the pattern of the override they belong to.
+    fn insert_config_levels(&mut self, lints: &[(String, Level)], pattern: Option<&str>) {
+        for &(ref lint_name, level) in lints {
+            let Ok(ids) = self.store.find_lints(lint_name) else {
+                // errors handled in `LintStore::check_lint_config`
+                continue
+            };
+            let src = LintLevelSource::Config(
+                Symbol::intern(lint_name),
+                level,
+                pattern.map(Symbol::intern),
+            );
+            for id in ids {
+                // Like command line flags, the lint config does not override
+                // ForceWarn and Forbid.
+                let (old_level, _) = self.sets.get_lint_level(
+                    id.lint,
+                    self.cur,
+                    Some(self.current_specs()),
+                    self.sess,
+                );
+                if let Level::ForceWarn | Level::Forbid = old_level {
+                    continue;
+                }
+
+                if self.check_gated_lint(id, DUMMY_SP) {
+                    self.current_specs_mut().insert(id, (level, src));
+                }
+            }
+        }
+    }
+
+    /// Pushes the levels of the overrides of the lint config that apply to a
+    /// module loaded from a file, `inner_span` being the span of the contents
+    /// of the module.
+    ///
+    /// This has to be called before `push`ing the attributes of the module,
+    /// and the returned `BuilderPush` passed to `pop` after popping them.
+    pub(crate) fn push_config_overrides(&mut self, inner_span: Span) -> BuilderPush {
+        let prev = self.cur;
+        let sess = self.sess;
+        let Some(lint_config) = &sess.opts.lint_config else {
+            return BuilderPush { prev, changed: false };
+        };
+
+        let file = sess.source_map().lookup_source_file(inner_span.lo());
+        let FileName::Real(ref name) = file.name else {
+            return BuilderPush { prev, changed: false };
+        };
+        let Some(path) = name.local_path() else {
+            return BuilderPush { prev, changed: false };
+        };
+        let path = sess.opts.working_dir.local_path_if_available().join(path);
+
+        self.cur = self.sets.list.push(LintSet { specs: FxHashMap::default(), parent: prev });
+        for lint_override in lint_config.overrides_for(&path) {
+            self.insert_config_levels(&lint_override.lints, Some(lint_override.pattern.as_str()));
+        }
+
+        if self.current_specs().is_empty() {
+            self.sets.list.pop();
+            self.cur = prev;
+        }
+
+        BuilderPush { prev, changed: prev != self.cur }
+    }
+
     /// Attempts to insert the `id` to `level_src` map entry. If unsuccessful
     /// (e.g. if a forbid was already inserted on the same scope), then emits a
     /// diagnostic with no change to `specs`.
@@ -154,6 +231,7 @@ fn insert_spec(&mut self, id: LintId, (level, src): LevelAndSource) {
                     LintLevelSource::Default => false,
                     LintLevelSource::Node(symbol, _, _) => self.store.is_lint_group(symbol),
                     LintLevelSource::CommandLine(symbol, _) => self.store.is_lint_group(symbol),
+                    LintLevelSource::Config(symbol, _, _) => self.store.is_lint_group(symbol),
                 };
                 debug!(
                     "fcw_warning={:?}, specs.get(&id) = {:?}, old_src={:?}, id_name={:?}",
@@ -181,6 +259,9 @@ fn insert_spec(&mut self, id: LintId, (level, src): LevelAndSource) {
                         LintLevelSource::CommandLine(_, _) => {
                             diag.note("`forbid` lint level was set on command line");
                         }
+                        LintLevelSource::Config(_, _, _) => {
+                            diag.note("`forbid` lint level was set in the lint config");
+                        }
                     }
                 };
                 if !fcw_warning {
@@ -713,9 +794,28 @@ fn visit_param(&mut self, param: &'tcx hir::Param<'tcx>) {
     }
 
     fn visit_item(&mut self, it: &'tcx hir::Item<'tcx>) {
+        // Modules loaded from another file are subject to the overrides of the
+        // lint config for that file.
+        let source_map = self.tcx.sess.source_map();
+        let config_push = match it.kind {
+            hir::ItemKind::Mod(ref module)
+                if source_map.lookup_source_file_idx(it.span.lo())
+                    != source_map.lookup_source_file_idx(module.spans.inner_span.lo()) =>
+            {
+                let config_push = self.levels.push_config_overrides(module.spans.inner_span);
+                if config_push.changed {
+                    self.levels.register_id(it.hir_id());
+                }
+                Some(config_push)
+            }
+            _ => None,
+        };
         self.with_lint_attrs(it.hir_id(), |builder| {
             intravisit::walk_item(builder, it);
         });
+        if let Some(config_push) = config_push {
+            self.levels.pop(config_push);
+        }
     }
 
     fn visit_foreign_item(&mut self, it: &'tcx hir::ForeignItem<'tcx>) {
@@ -10,7 +10,8 @@
 //! all other analyses. The `LintPass`es built into rustc are defined
 //! within [rustc_session::lint::builtin],
 //! which has further comments on how to add such a lint.
-//! rustc can also load user-defined lint plugins via the plugin mechanism.
+//! rustc can also load user-defined lint plugins with `-Z lint-plugin`, see
+//! the [plugin] module.
 //!
 //! Some of rustc's lints are defined elsewhere in the compiler and work by
 //! calling `add_lint()` on the overall `Session` object. This works when
@@ -47,6 +48,7 @@
 extern crate rustc_session;
 
 mod array_into_iter;
+mod blocking_in_async;
 pub mod builtin;
 mod context;
 mod early;
@@ -63,6 +65,7 @@
 mod noop_method_call;
 mod pass_by_value;
 mod passes;
+pub mod plugin;
 mod redundant_semicolon;
 mod traits;
 mod types;
@@ -82,6 +85,7 @@
 use rustc_span::Span;
 
 use array_into_iter::ArrayIntoIter;
+use blocking_in_async::BlockingInAsync;
 use builtin::*;
 use enum_intrinsics_non_enums::EnumIntrinsicsNonEnums;
 use hidden_unicode_codepoints::*;
@@ -183,6 +187,7 @@ macro_rules! late_lint_passes {
                 EnumIntrinsicsNonEnums: EnumIntrinsicsNonEnums,
                 InvalidAtomicOrdering: InvalidAtomicOrdering,
                 NamedAsmLabels: NamedAsmLabels,
+                BlockingInAsync: BlockingInAsync,
             ]
         );
     };
@@ -0,0 +1,115 @@
+//! Lint plugins: dynamic libraries providing additional lints and lint
+//! passes, loaded with `-Z lint-plugin=<path>`.
+//!
+//! Unlike the deprecated `#![plugin]` attribute, lint plugins are loaded from
+//! the command line, so the crates being linted need no changes, and they can
+//! only register lints, lint passes and lint groups in the `LintStore`.
+//!
+//! A lint plugin is a `dylib` crate built against the `rustc_private` crates
+//! of the compiler that loads it. It declares a registration function with
+//! `declare_lint_plugin!`:
+//!
+//! ```ignore (requires a dylib built against rustc_private crates)
+//! #![feature(rustc_private)]
+//!
+//! extern crate rustc_ast;
+//! extern crate rustc_driver;
+//! #[macro_use]
+//! extern crate rustc_lint;
+//! #[macro_use]
+//! extern crate rustc_session;
+//!
+//! use rustc_lint::{EarlyContext, EarlyLintPass, LintContext, LintStore};
+//!
+//! declare_lint!(pub NO_FOO, Warn, "items named `foo`");
+//! declare_lint_pass!(NoFoo => [NO_FOO]);
+//!
+//! impl EarlyLintPass for NoFoo {
+//!     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &rustc_ast::Item) { ... }
+//! }
+//!
+//! fn register(lint_store: &mut LintStore) {
+//!     lint_store.register_lints(&NoFoo::get_lints());
+//!     lint_store.register_early_pass(|| Box::new(NoFoo));
+//! }
+//!
+//! declare_lint_plugin!(register);
+//! ```
+//!
+//! The lints of a plugin should be declared with `declare_lint!` as above.
+//! Tool lints declared with `declare_tool_lint!` can only be referred to in
+//! crates that register the tool with `#![register_tool(...)]`, which would
+//! require changes to every crate being linted.
+//!
+//! The declaration records the version of the plugin API and of the compiler
+//! the plugin was built with. Since the plugin calls into the compiler with
+//! Rust types, whose layout is not stable, both have to match exactly, and
+//! `LintStore::register_lint_plugin` checks them before calling any code of
+//! the plugin. `LINT_PLUGIN_API_VERSION` is increased whenever the
+//! declaration itself changes.
+
+use crate::LintStore;
+
+/// The version of the lint plugin API, see `LintPluginDeclaration`.
+pub const LINT_PLUGIN_API_VERSION: u32 = 1;
+
+/// The version of the compiler, which a lint plugin has to be built with.
+pub const RUSTC_VERSION: &str = match option_env!("CFG_VERSION") {
+    Some(version) => version,
+    None => "unknown version",
+};
+
+/// The name of the static every lint plugin exports its declaration as.
+pub const LINT_PLUGIN_DECLARATION_SYMBOL: &[u8] = b"__rustc_lint_plugin_declaration";
+
+/// The declaration a lint plugin exports, see `declare_lint_plugin!`.
+///
+/// `api_version` has to stay the first field, so that it can be checked even
+/// if the rest of the declaration changed.
+#[repr(C)]
+pub struct LintPluginDeclaration {
+    pub api_version: u32,
+    pub rustc_version: &'static str,
+    pub register: fn(&mut LintStore),
+}
+
+/// Declares the function registering the lints of a lint plugin.
+#[macro_export]
+macro_rules! declare_lint_plugin {
+    ($register:expr) => {
+        #[no_mangle]
+        #[allow(non_upper_case_globals)]
+        pub static __rustc_lint_plugin_declaration: $crate::plugin::LintPluginDeclaration =
+            $crate::plugin::LintPluginDeclaration {
+                api_version: $crate::plugin::LINT_PLUGIN_API_VERSION,
+                rustc_version: $crate::plugin::RUSTC_VERSION,
+                register: $register,
+            };
+    };
+}
+
+impl LintStore {
+    /// Registers the lints of a lint plugin, after checking that it was built
+    /// for this version of the plugin API and of the compiler.
+    pub fn register_lint_plugin(
+        &mut self,
+        declaration: &LintPluginDeclaration,
+    ) -> Result<(), String> {
+        if declaration.api_version != LINT_PLUGIN_API_VERSION {
+            return Err(format!(
+                "lint plugin uses version {} of the lint plugin API, but this compiler \
+                 supports version {}",
+                declaration.api_version, LINT_PLUGIN_API_VERSION
+            ));
+        }
+        if declaration.rustc_version != RUSTC_VERSION {
+            return Err(format!(
+                "lint plugin was built with rustc {}, but this is rustc {}",
+                declaration.rustc_version, RUSTC_VERSION
+            ));
+        }
+
+        (declaration.register)(self);
+        Ok(())
+    }
+}
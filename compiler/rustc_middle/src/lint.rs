@@ -12,6 +12,7 @@
     builtin::{self, FORBIDDEN_LINT_GROUPS},
     FutureIncompatibilityReason, Level, Lint, LintExpectationId, LintId,
 };
+use rustc_session::lint_config;
 use rustc_session::Session;
 use rustc_span::hygiene::MacroKind;
 use rustc_span::source_map::{DesugaringKind, ExpnKind};
@@ -31,6 +32,11 @@ pub enum LintLevelSource {
     /// The provided `Level` is the level specified on the command line.
     /// (The actual level may be lower due to `--cap-lints`.)
     CommandLine(Symbol, Level),
+
+    /// Lint level was set by the lint config passed with `-Z lint-config`.
+    /// The provided `Level` is the level specified in the config, and the
+    /// optional `Symbol` the pattern of the override that specified it.
+    Config(Symbol, Level, Option<Symbol>),
 }
 
 impl LintLevelSource {
@@ -39,6 +45,7 @@ pub fn name(&self) -> Symbol {
             LintLevelSource::Default => symbol::kw::Default,
             LintLevelSource::Node(name, _, _) => name,
             LintLevelSource::CommandLine(name, _) => name,
+            LintLevelSource::Config(name, _, _) => name,
         }
     }
 
@@ -47,6 +54,7 @@ pub fn span(&self) -> Span {
             LintLevelSource::Default => DUMMY_SP,
             LintLevelSource::Node(_, span, _) => span,
             LintLevelSource::CommandLine(_, _) => DUMMY_SP,
+            LintLevelSource::Config(_, _, _) => DUMMY_SP,
         }
     }
 }
@@ -280,6 +288,24 @@ pub fn explain_lint_level_source(
                 ));
             }
         }
+        LintLevelSource::Config(lint_config_name, orig_level, pattern) => {
+            let entry = format!("{} = \"{}\"", lint_config_name, orig_level.as_str());
+            let table = lint_config::table_name(pattern.as_ref().map(Symbol::as_str));
+            if lint_config_name.as_str() == name {
+                err.note_once(&format!(
+                    "requested by `{}` in the `{}` table of the lint config",
+                    entry, table
+                ));
+            } else {
+                err.note_once(&format!(
+                    "`#[{}({})]` implied by `{}` in the `{}` table of the lint config",
+                    level.as_str(),
+                    name,
+                    entry,
+                    table
+                ));
+            }
+        }
         LintLevelSource::Node(lint_attr_name, src, reason) => {
             if let Some(rationale) = reason {
                 err.note(rationale.as_str());
@@ -770,6 +770,10 @@
         desc { |tcx| "checking intrinsics in {}", describe_as_module(key, tcx) }
     }
 
+    query check_mod_fmt_custom_specs(key: LocalDefId) -> () {
+        desc { |tcx| "checking custom format specs in {}", describe_as_module(key, tcx) }
+    }
+
     query check_mod_liveness(key: LocalDefId) -> () {
         desc { |tcx| "checking liveness of variables in {}", describe_as_module(key, tcx) }
     }
@@ -10,6 +10,7 @@
 use rustc_index::bit_set::BitSet;
 use rustc_index::vec::{Idx, IndexVec};
 use rustc_session::{config::OptLevel, DataTypeKind, FieldInfo, SizeKind, VariantInfo};
+use rustc_session::{NicheInfo, TagEncodingInfo, TagInfo};
 use rustc_span::symbol::Symbol;
 use rustc_span::{Span, DUMMY_SP};
 use rustc_target::abi::call::{
@@ -1772,9 +1773,9 @@ fn generator_layout(
     /// layout of each type.
     #[inline(always)]
     fn record_layout_for_printing(&self, layout: TyAndLayout<'tcx>) {
-        // If we are running with `-Zprint-type-sizes`, maybe record layouts
-        // for dumping later.
-        if self.tcx.sess.opts.debugging_opts.print_type_sizes {
+        // If we are running with `-Zprint-type-sizes` or `--print=type-sizes`,
+        // maybe record layouts for dumping later.
+        if self.tcx.sess.record_type_sizes() {
             self.record_layout_for_printing_outlined(layout)
         }
     }
@@ -1787,8 +1788,16 @@ fn record_layout_for_printing_outlined(&self, layout: TyAndLayout<'tcx>) {
             return;
         }
 
+        let niche = layout.largest_niche.map(|niche| NicheInfo {
+            offset: niche.offset.bytes(),
+            size: niche.value.size(self).bytes(),
+            valid_range_start: niche.valid_range.start,
+            valid_range_end: niche.valid_range.end,
+            available: niche.available(self),
+        });
+
         // (delay format until we actually need it)
-        let record = |kind, packed, opt_discr_size, variants| {
+        let record = |kind, packed, opt_discr_size, variants, tag| {
             let type_desc = format!("{:?}", layout.ty);
             self.tcx.sess.code_stats.record_type_size(
                 kind,
@@ -1798,6 +1807,8 @@ fn record_layout_for_printing_outlined(&self, layout: TyAndLayout<'tcx>) {
                 packed,
                 opt_discr_size,
                 variants,
+                niche.clone(),
+                tag,
             );
         };
 
@@ -1809,7 +1820,7 @@ fn record_layout_for_printing_outlined(&self, layout: TyAndLayout<'tcx>) {
 
             ty::Closure(..) => {
                 debug!("print-type-size t: `{:?}` record closure", layout.ty);
-                record(DataTypeKind::Closure, false, None, vec![]);
+                record(DataTypeKind::Closure, false, None, vec![], None);
                 return;
             }
 
@@ -1867,15 +1878,16 @@ fn record_layout_for_printing_outlined(&self, layout: TyAndLayout<'tcx>) {
                         adt_packed,
                         None,
                         vec![build_variant_info(Some(variant_def.name), &fields, layout)],
+                        None,
                     );
                 } else {
                     // (This case arises for *empty* enums; so give it
                     // zero variants.)
-                    record(adt_kind.into(), adt_packed, None, vec![]);
+                    record(adt_kind.into(), adt_packed, None, vec![], None);
                 }
             }
 
-            Variants::Multiple { tag, ref tag_encoding, .. } => {
+            Variants::Multiple { tag, ref tag_encoding, tag_field, .. } => {
                 debug!(
                     "print-type-size `{:#?}` adt general variants def {}",
                     layout.ty,
@@ -1893,6 +1905,28 @@ fn record_layout_for_printing_outlined(&self, layout: TyAndLayout<'tcx>) {
                         )
                     })
                     .collect();
+                let variant_name = |i: VariantIdx| adt_def.variant(i).name.to_string();
+                let tag_info = TagInfo {
+                    offset: layout.fields.offset(tag_field).bytes(),
+                    size: tag.size(self).bytes(),
+                    valid_range_start: tag.valid_range(self).start,
+                    valid_range_end: tag.valid_range(self).end,
+                    encoding: match *tag_encoding {
+                        TagEncoding::Direct => TagEncodingInfo::Direct,
+                        TagEncoding::Niche { dataful_variant, ref niche_variants, niche_start } => {
+                            TagEncodingInfo::Niche {
+                                dataful_variant: variant_name(dataful_variant),
+                                niche_variants: adt_def
+                                    .variants()
+                                    .indices()
+                                    .filter(|i| niche_variants.contains(i))
+                                    .map(variant_name)
+                                    .collect(),
+                                niche_start,
+                            }
+                        }
+                    },
+                };
                 record(
                     adt_kind.into(),
                     adt_packed,
@@ -1901,6 +1935,7 @@ fn record_layout_for_printing_outlined(&self, layout: TyAndLayout<'tcx>) {
                         _ => None,
                     },
                     variant_infos,
+                    Some(tag_info),
                 );
             }
         }
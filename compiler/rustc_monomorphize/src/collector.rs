@@ -268,6 +268,17 @@ pub fn with_inlining_candidates<F>(&self, source: MonoItem<'tcx>, mut f: F)
         }
     }
 
+    // Internally iterate over all items accessed by `source`, whether they are
+    // made available for inlining or not.
+    pub fn with_accesses<F>(&self, source: MonoItem<'tcx>, f: F)
+    where
+        F: FnMut(MonoItem<'tcx>),
+    {
+        if let Some(range) = self.index.get(&source) {
+            self.targets[range.clone()].iter().copied().for_each(f);
+        }
+    }
+
     // Internally iterate over all items and the things each accesses.
     pub fn iter_accesses<F>(&self, mut f: F)
     where
@@ -277,6 +288,19 @@ pub fn iter_accesses<F>(&self, mut f: F)
             f(accessor, &self.targets[range.clone()])
         }
     }
+
+    // Internally iterate over all accesses, along with whether the accessed
+    // item is made available for inlining into the accessor.
+    pub fn iter_access_edges<F>(&self, mut f: F)
+    where
+        F: FnMut(MonoItem<'tcx>, MonoItem<'tcx>, bool),
+    {
+        for (&accessor, range) in &self.index {
+            for (i, &target) in self.targets[range.clone()].iter().enumerate() {
+                f(accessor, target, self.inlines.contains(range.start + i))
+            }
+        }
+    }
 }
 
 pub fn collect_crate_mono_items(
@@ -20,6 +20,7 @@
 mod collector;
 mod partitioning;
 mod polymorphize;
+mod report;
 mod util;
 
 fn custom_coerce_unsize_info<'tcx>(
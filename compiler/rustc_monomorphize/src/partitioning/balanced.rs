@@ -0,0 +1,229 @@
+//! The `balanced` partitioning strategy, selected with
+//! `-Zcgu-partitioning-strategy=balanced`.
+//!
+//! Roots are placed and inlined items are copied exactly like in the default
+//! strategy. The difference is in how the initial, per-module codegen units
+//! are merged down to the requested number: instead of repeatedly merging the
+//! two smallest codegen units, this strategy treats the codegen units as the
+//! nodes of a graph whose edges are weighted by the number of references
+//! between them. It then greedily merges the pair of codegen units with the
+//! most references between them, as long as the result does not exceed a cost
+//! limit derived from the average cost of a codegen unit.
+//!
+//! The cost of a codegen unit approximates the time LLVM will spend on it: it
+//! is the size estimate (the number of MIR statements) of its roots, plus the
+//! size estimate of every item that will be copied into it for inlining. A
+//! root that pulls in many inlined items is thus more expensive than its own
+//! size suggests.
+//!
+//! Every reference between two merged codegen units becomes a reference within
+//! a single LLVM module, which gives LLVM more opportunities to optimize and
+//! results in fewer symbols that have to be exported.
+
+use std::cmp;
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_middle::mir::mono::{CodegenUnit, MonoItem};
+use rustc_span::symbol::Symbol;
+
+use super::PartitioningCx;
+use crate::partitioning::default::DefaultPartitioning;
+use crate::partitioning::merging;
+use crate::partitioning::{Partitioner, PostInliningPartitioning, PreInliningPartitioning};
+
+pub struct BalancedPartitioning;
+
+impl<'tcx> Partitioner<'tcx> for BalancedPartitioning {
+    fn place_root_mono_items(
+        &mut self,
+        cx: &PartitioningCx<'_, 'tcx>,
+        mono_items: &mut dyn Iterator<Item = MonoItem<'tcx>>,
+    ) -> PreInliningPartitioning<'tcx> {
+        DefaultPartitioning.place_root_mono_items(cx, mono_items)
+    }
+
+    fn merge_codegen_units(
+        &mut self,
+        cx: &PartitioningCx<'_, 'tcx>,
+        initial_partitioning: &mut PreInliningPartitioning<'tcx>,
+    ) {
+        merge_codegen_units(cx, initial_partitioning);
+    }
+
+    fn place_inlined_mono_items(
+        &mut self,
+        cx: &PartitioningCx<'_, 'tcx>,
+        initial_partitioning: PreInliningPartitioning<'tcx>,
+    ) -> PostInliningPartitioning<'tcx> {
+        DefaultPartitioning.place_inlined_mono_items(cx, initial_partitioning)
+    }
+
+    fn internalize_symbols(
+        &mut self,
+        cx: &PartitioningCx<'_, 'tcx>,
+        partitioning: &mut PostInliningPartitioning<'tcx>,
+    ) {
+        DefaultPartitioning.internalize_symbols(cx, partitioning)
+    }
+}
+
+/// The graph of codegen units that is being merged. Node `i` corresponds to
+/// the codegen unit at index `i` in the (name-sorted) initial partitioning.
+struct CguGraph {
+    /// The estimated cost of every codegen unit.
+    costs: Vec<usize>,
+    /// For every codegen unit, the number of references to and from each other
+    /// codegen unit.
+    edges: Vec<FxHashMap<usize, usize>>,
+}
+
+impl CguGraph {
+    fn build<'tcx>(cx: &PartitioningCx<'_, 'tcx>, codegen_units: &[CodegenUnit<'tcx>]) -> Self {
+        let home_cgu: FxHashMap<MonoItem<'tcx>, usize> = codegen_units
+            .iter()
+            .enumerate()
+            .flat_map(|(index, cgu)| cgu.items().keys().map(move |item| (*item, index)))
+            .collect();
+
+        let mut costs = Vec::with_capacity(codegen_units.len());
+        let mut edges = vec![FxHashMap::default(); codegen_units.len()];
+
+        for (index, cgu) in codegen_units.iter().enumerate() {
+            // Walk everything the roots of this codegen unit reference. Items
+            // that are not roots will be copied into this codegen unit, so
+            // their references are followed as well.
+            let mut visited = FxHashSet::default();
+            let mut stack: Vec<_> = cgu.items().keys().copied().collect();
+            let mut cost = 0;
+
+            while let Some(item) = stack.pop() {
+                if !visited.insert(item) {
+                    continue;
+                }
+
+                match home_cgu.get(&item) {
+                    Some(&home) if home != index => {
+                        // A reference to a root in another codegen unit.
+                        *edges[index].entry(home).or_default() += 1;
+                        *edges[home].entry(index).or_default() += 1;
+                        continue;
+                    }
+                    _ => cost += item.size_estimate(cx.tcx),
+                }
+
+                cx.inlining_map.with_accesses(item, |accessee| stack.push(accessee));
+            }
+
+            costs.push(cost);
+        }
+
+        CguGraph { costs, edges }
+    }
+
+    /// Finds the pair of codegen units with the most references between them
+    /// that can be merged without exceeding `max_cost`. Ties are broken in
+    /// favour of the cheaper result, and then of the lower indices, so that
+    /// the result is deterministic.
+    fn best_merge(&self, max_cost: usize) -> Option<(usize, usize)> {
+        let mut best: Option<(usize, usize, usize, usize)> = None;
+
+        for (a, edges) in self.edges.iter().enumerate() {
+            for (&b, &weight) in edges {
+                if b < a {
+                    continue;
+                }
+                let cost = self.costs[a] + self.costs[b];
+                if cost > max_cost {
+                    continue;
+                }
+                let is_better = match best {
+                    None => true,
+                    Some((best_weight, best_cost, best_a, best_b)) => {
+                        (weight, cmp::Reverse(cost), cmp::Reverse((a, b)))
+                            > (best_weight, cmp::Reverse(best_cost), cmp::Reverse((best_a, best_b)))
+                    }
+                };
+                if is_better {
+                    best = Some((weight, cost, a, b));
+                }
+            }
+        }
+
+        best.map(|(_, _, a, b)| (a, b))
+    }
+
+    /// Merges node `b` into node `a`.
+    fn merge(&mut self, a: usize, b: usize) {
+        self.costs[a] += self.costs[b];
+        self.costs[b] = 0;
+
+        for (other, weight) in std::mem::take(&mut self.edges[b]) {
+            self.edges[other].remove(&b);
+            if other != a {
+                *self.edges[a].entry(other).or_default() += weight;
+                *self.edges[other].entry(a).or_default() += weight;
+            }
+        }
+    }
+}
+
+fn merge_codegen_units<'tcx>(
+    cx: &PartitioningCx<'_, 'tcx>,
+    initial_partitioning: &mut PreInliningPartitioning<'tcx>,
+) {
+    assert!(cx.target_cgu_count >= 1);
+    let codegen_units = &mut initial_partitioning.codegen_units;
+
+    // Sort by name, so that node indices and thus the tie-breaking below are
+    // deterministic.
+    codegen_units.sort_by(|a, b| a.name().as_str().partial_cmp(b.name().as_str()).unwrap());
+
+    let mut cgu_contents: FxHashMap<Symbol, Vec<Symbol>> =
+        codegen_units.iter().map(|cgu| (cgu.name(), vec![cgu.name()])).collect();
+
+    if codegen_units.len() > cx.target_cgu_count {
+        let mut graph = CguGraph::build(cx, codegen_units);
+
+        // Allow codegen units to grow a bit beyond the average, so that closely
+        // connected codegen units can still be merged, but never prevent the
+        // most expensive codegen unit from being merged at all.
+        let total_cost: usize = graph.costs.iter().sum();
+        let average_cost = total_cost / cx.target_cgu_count;
+        let max_cost = cmp::max(
+            average_cost + average_cost / 4,
+            graph.costs.iter().copied().max().unwrap_or(0),
+        );
+
+        let mut live: Vec<Option<CodegenUnit<'tcx>>> = codegen_units.drain(..).map(Some).collect();
+        let mut live_count = live.len();
+
+        while live_count > cx.target_cgu_count {
+            let (a, b) = graph.best_merge(max_cost).unwrap_or_else(|| {
+                // No connected pair fits, fall back to merging the two cheapest
+                // codegen units.
+                let mut cheapest: Vec<usize> =
+                    (0..live.len()).filter(|&i| live[i].is_some()).collect();
+                cheapest.sort_by_key(|&i| (graph.costs[i], i));
+                (cmp::min(cheapest[0], cheapest[1]), cmp::max(cheapest[0], cheapest[1]))
+            });
+
+            let mut consumed = live[b].take().unwrap();
+            let target = live[a].as_mut().unwrap();
+            target.modify_size_estimate(consumed.size_estimate());
+            for (k, v) in consumed.items_mut().drain() {
+                target.items_mut().insert(k, v);
+            }
+            graph.merge(a, b);
+            live_count -= 1;
+
+            let mut consumed_cgu_names = cgu_contents.remove(&consumed.name()).unwrap();
+            cgu_contents.get_mut(&target.name()).unwrap().append(&mut consumed_cgu_names);
+
+            debug!("CodegenUnit {} merged into CodegenUnit {}", consumed.name(), target.name());
+        }
+
+        codegen_units.extend(live.into_iter().flatten());
+    }
+
+    merging::rename_merged_codegen_units(cx, codegen_units, cgu_contents);
+}
@@ -55,6 +55,18 @@ pub fn merge_codegen_units<'tcx>(
         );
     }
 
+    rename_merged_codegen_units(cx, codegen_units, cgu_contents);
+}
+
+/// Gives the codegen units their final names once merging is done.
+///
+/// `cgu_contents` maps the current name of every codegen unit to the names of
+/// all the initial codegen units that were merged into it.
+pub fn rename_merged_codegen_units<'tcx>(
+    cx: &PartitioningCx<'_, 'tcx>,
+    codegen_units: &mut [CodegenUnit<'tcx>],
+    cgu_contents: FxHashMap<Symbol, Vec<Symbol>>,
+) {
     let cgu_name_builder = &mut CodegenUnitNameBuilder::new(cx.tcx);
 
     if cx.tcx.sess.opts.incremental.is_some() {
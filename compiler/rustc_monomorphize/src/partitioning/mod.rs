@@ -92,6 +92,7 @@
 //! source-level module, functions from the same module will be available for
 //! inlining, even when they are not marked `#[inline]`.
 
+mod balanced;
 mod default;
 mod merging;
 
@@ -103,10 +104,12 @@
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::TyCtxt;
+use rustc_session::config::PrintRequest;
 use rustc_span::symbol::Symbol;
 
 use crate::collector::InliningMap;
 use crate::collector::{self, MonoItemCollectionMode};
+use crate::report::print_monomorphization_report;
 
 pub struct PartitioningCx<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
@@ -148,6 +151,7 @@ fn get_partitioner<'tcx>(tcx: TyCtxt<'tcx>) -> Box<dyn Partitioner<'tcx>> {
 
     match strategy {
         "default" => Box::new(default::DefaultPartitioning),
+        "balanced" => Box::new(balanced::BalancedPartitioning),
         _ => tcx.sess.fatal("unknown partitioning strategy"),
     }
 }
@@ -377,6 +381,10 @@ fn collect_and_partition_mono_items<'tcx>(
 
     tcx.sess.abort_if_errors();
 
+    if tcx.sess.opts.prints.contains(&PrintRequest::MonomorphizationReport) {
+        print_monomorphization_report(tcx, &items, &inlining_map);
+    }
+
     let (codegen_units, _) = tcx.sess.time("partition_and_assert_distinct_symbols", || {
         sync::join(
             || {
@@ -462,11 +470,60 @@ fn collect_and_partition_mono_items<'tcx>(
         for item in item_keys {
             println!("MONO_ITEM {}", item);
         }
+
+        print_codegen_unit_stats(tcx, codegen_units, &inlining_map);
     }
 
     (tcx.arena.alloc(mono_items), codegen_units)
 }
 
+/// Prints, for every codegen unit, the number of items it contains, its size
+/// estimate and the number of references from its items to items that are not
+/// available in it, followed by the totals. This is printed together with the
+/// `-Zprint-mono-items` output, so that partitioning strategies can be
+/// compared.
+fn print_codegen_unit_stats<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    codegen_units: &[CodegenUnit<'tcx>],
+    inlining_map: &InliningMap<'tcx>,
+) {
+    let mut cgus: Vec<_> = codegen_units.iter().collect();
+    cgus.sort_by(|a, b| a.name().as_str().cmp(b.name().as_str()));
+
+    let mut total_cross_cgu_references = 0;
+    let mut max_size_estimate: usize = 0;
+    for cgu in &cgus {
+        let mut cross_cgu_references = 0;
+        for &item in cgu.items().keys() {
+            inlining_map.with_accesses(item, |accessee| {
+                if !cgu.items().contains_key(&accessee) {
+                    cross_cgu_references += 1;
+                }
+            });
+        }
+        total_cross_cgu_references += cross_cgu_references;
+        max_size_estimate = max_size_estimate.max(cgu.size_estimate());
+
+        println!(
+            "CODEGEN_UNIT {} items={} size_estimate={} cross_cgu_references={}",
+            cgu.name(),
+            cgu.items().len(),
+            cgu.size_estimate(),
+            cross_cgu_references
+        );
+    }
+
+    println!(
+        "PARTITIONING strategy={} codegen_units={} size_estimate={} max_size_estimate={} \
+         cross_cgu_references={}",
+        tcx.sess.opts.debugging_opts.cgu_partitioning_strategy.as_deref().unwrap_or("default"),
+        cgus.len(),
+        cgus.iter().map(|cgu| cgu.size_estimate()).sum::<usize>(),
+        max_size_estimate,
+        total_cross_cgu_references
+    );
+}
+
 fn codegened_and_inlined_items<'tcx>(tcx: TyCtxt<'tcx>, (): ()) -> &'tcx DefIdSet {
     let (items, cgus) = tcx.collect_and_partition_mono_items(());
     let mut visited = DefIdSet::default();
@@ -0,0 +1,280 @@
+//! The monomorphization report, printed with `--print=monomorphization-report`.
+//!
+//! `-Z print-mono-items` lists the items that were collected, but not why. The
+//! report additionally records, for every mono item, the accesses recorded in
+//! the `InliningMap` that caused it to be collected, and aggregates the number
+//! of instantiations and their estimated size per generic definition and per
+//! requesting crate. This makes it possible to find out which generics are
+//! responsible for most of the code generated for a crate.
+//!
+//! The report is a single JSON object of the form:
+//!
+//! ```json
+//! {
+//!     "crate": "foo",
+//!     "total": { "instantiations": 3, "size_estimate": 120 },
+//!     "generics": [
+//!         {
+//!             "def": "alloc::vec::Vec::<T, A>::push",
+//!             "crate": "alloc",
+//!             "instantiations": 2,
+//!             "size_estimate": 80,
+//!             "requested_by": [
+//!                 { "crate": "foo", "instantiations": 2, "size_estimate": 80 }
+//!             ]
+//!         }
+//!     ],
+//!     "crates": [
+//!         { "crate": "foo", "instantiations": 3, "size_estimate": 120 }
+//!     ],
+//!     "items": [
+//!         {
+//!             "item": "fn alloc::vec::Vec::<u8>::push",
+//!             "generic": "alloc::vec::Vec::<T, A>::push",
+//!             "size_estimate": 40,
+//!             "root": false,
+//!             "attributed_to": [ "foo" ],
+//!             "requested_by": [ { "item": "fn foo::main", "inlined": false } ]
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! An item is attributed to the crates that ultimately cause its
+//! instantiation. Non-generic items and items defined in the local crate are
+//! attributed to the crate defining them, and items without any recorded
+//! access are roots, attributed to the local crate. Upstream generics inherit
+//! the attribution of the items using them, transitively: `RawVec::<MyType>`,
+//! only used by `Vec::<MyType>::push`, itself used by the local `main`, is
+//! attributed to the local crate, not to `alloc`. An item attributed to
+//! several crates is counted once for each of them in `crates` and
+//! `requested_by`.
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
+use rustc_middle::mir::mono::MonoItem;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::TyCtxt;
+use rustc_serialize::json::{Json, ToJson};
+use std::collections::BTreeMap;
+
+use crate::collector::InliningMap;
+
+/// The number of instantiations and their total size estimate.
+#[derive(Default, Clone, Copy)]
+struct Totals {
+    instantiations: usize,
+    size_estimate: usize,
+}
+
+impl Totals {
+    fn add(&mut self, size_estimate: usize) {
+        self.instantiations += 1;
+        self.size_estimate += size_estimate;
+    }
+
+    fn to_json_with(self, mut object: BTreeMap<String, Json>) -> Json {
+        object.insert("instantiations".to_string(), self.instantiations.to_json());
+        object.insert("size_estimate".to_string(), self.size_estimate.to_json());
+        Json::Object(object)
+    }
+}
+
+impl ToJson for Totals {
+    fn to_json(&self) -> Json {
+        self.to_json_with(BTreeMap::new())
+    }
+}
+
+#[derive(Default)]
+struct GenericTotals {
+    totals: Totals,
+    by_crate: FxHashMap<CrateNum, Totals>,
+}
+
+/// Computes the crates every item is attributed to, by carrying the attribution
+/// of the non-generic and local items, and of the roots, along the accesses to
+/// the upstream generics they use.
+fn attribute_items<'tcx>(
+    items: &FxHashSet<MonoItem<'tcx>>,
+    inlining_map: &InliningMap<'tcx>,
+) -> FxHashMap<MonoItem<'tcx>, FxHashSet<CrateNum>> {
+    let mut accesses: FxHashMap<MonoItem<'tcx>, Vec<MonoItem<'tcx>>> = Default::default();
+    let mut accessed = FxHashSet::default();
+    inlining_map.iter_access_edges(|source, target, _| {
+        accesses.entry(source).or_default().push(target);
+        accessed.insert(target);
+    });
+    let inherits_attribution = |item: MonoItem<'tcx>| {
+        item.is_generic_fn() && item.krate() != LOCAL_CRATE && accessed.contains(&item)
+    };
+
+    let mut attributed: FxHashMap<MonoItem<'tcx>, FxHashSet<CrateNum>> = Default::default();
+    let mut worklist = Vec::new();
+    for &item in items {
+        if !inherits_attribution(item) {
+            let krate = if accessed.contains(&item) { item.krate() } else { LOCAL_CRATE };
+            attributed.entry(item).or_default().insert(krate);
+            worklist.push(item);
+        }
+    }
+    while let Some(source) = worklist.pop() {
+        let crates = attributed[&source].clone();
+        for &target in accesses.get(&source).into_iter().flatten() {
+            if !inherits_attribution(target) {
+                continue;
+            }
+            let target_crates = attributed.entry(target).or_default();
+            let len = target_crates.len();
+            target_crates.extend(&crates);
+            if target_crates.len() != len {
+                worklist.push(target);
+            }
+        }
+    }
+    attributed
+}
+
+/// Converts per-crate totals into a JSON array, largest first.
+fn crates_to_json(tcx: TyCtxt<'_>, by_crate: &FxHashMap<CrateNum, Totals>) -> Json {
+    let mut crates: Vec<_> = by_crate
+        .iter()
+        .map(|(&krate, &totals)| (tcx.crate_name(krate).to_string(), totals))
+        .collect();
+    crates.sort_by(|(a_name, a), (b_name, b)| {
+        b.size_estimate.cmp(&a.size_estimate).then_with(|| a_name.cmp(b_name))
+    });
+    Json::Array(
+        crates
+            .into_iter()
+            .map(|(name, totals)| {
+                let mut object = BTreeMap::new();
+                object.insert("crate".to_string(), name.to_json());
+                totals.to_json_with(object)
+            })
+            .collect(),
+    )
+}
+
+pub fn print_monomorphization_report<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    items: &FxHashSet<MonoItem<'tcx>>,
+    inlining_map: &InliningMap<'tcx>,
+) {
+    let mut requested_by: FxHashMap<MonoItem<'tcx>, Vec<(MonoItem<'tcx>, bool)>> =
+        Default::default();
+    inlining_map.iter_access_edges(|source, target, inlined| {
+        requested_by.entry(target).or_default().push((source, inlined));
+    });
+    let mut attributed = attribute_items(items, inlining_map);
+
+    let mut total = Totals::default();
+    let mut by_crate: FxHashMap<CrateNum, Totals> = Default::default();
+    let mut by_generic: FxHashMap<DefId, GenericTotals> = Default::default();
+    let mut item_reports = Vec::with_capacity(items.len());
+
+    for &item in items {
+        let size_estimate = item.size_estimate(tcx);
+        total.add(size_estimate);
+
+        let mut sources: Vec<_> = requested_by
+            .remove(&item)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(source, inlined)| (with_no_trimmed_paths!(source.to_string()), source, inlined))
+            .collect();
+        sources.sort_by(|a, b| a.0.cmp(&b.0));
+        sources.dedup_by(|a, b| a.0 == b.0);
+
+        // Upstream generics that no attributed item leads to fall back to the
+        // local crate, like roots.
+        let mut requesting_crates: Vec<_> =
+            attributed.remove(&item).unwrap_or_default().into_iter().collect();
+        if requesting_crates.is_empty() {
+            requesting_crates.push(LOCAL_CRATE);
+        }
+        requesting_crates.sort();
+        for &krate in &requesting_crates {
+            by_crate.entry(krate).or_default().add(size_estimate);
+        }
+
+        let generic = match item {
+            MonoItem::Fn(instance) if item.is_generic_fn() => Some(instance.def_id()),
+            _ => None,
+        };
+        if let Some(def_id) = generic {
+            let generic_totals = by_generic.entry(def_id).or_default();
+            generic_totals.totals.add(size_estimate);
+            for &krate in &requesting_crates {
+                generic_totals.by_crate.entry(krate).or_default().add(size_estimate);
+            }
+        }
+
+        let mut object = BTreeMap::new();
+        let name = with_no_trimmed_paths!(item.to_string());
+        object.insert("item".to_string(), name.to_json());
+        object.insert(
+            "generic".to_string(),
+            generic.map(|def_id| with_no_trimmed_paths!(tcx.def_path_str(def_id))).to_json(),
+        );
+        object.insert("size_estimate".to_string(), size_estimate.to_json());
+        object.insert("root".to_string(), sources.is_empty().to_json());
+        object.insert(
+            "attributed_to".to_string(),
+            requesting_crates
+                .iter()
+                .map(|&krate| tcx.crate_name(krate).to_string())
+                .collect::<Vec<_>>()
+                .to_json(),
+        );
+        object.insert(
+            "requested_by".to_string(),
+            Json::Array(
+                sources
+                    .into_iter()
+                    .map(|(source, _, inlined)| {
+                        let mut edge = BTreeMap::new();
+                        edge.insert("item".to_string(), source.to_json());
+                        edge.insert("inlined".to_string(), inlined.to_json());
+                        Json::Object(edge)
+                    })
+                    .collect(),
+            ),
+        );
+        item_reports.push((name, Json::Object(object)));
+    }
+    item_reports.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut generics: Vec<_> = by_generic
+        .into_iter()
+        .map(|(def_id, generic_totals)| {
+            (with_no_trimmed_paths!(tcx.def_path_str(def_id)), def_id, generic_totals)
+        })
+        .collect();
+    generics.sort_by(|(a_name, _, a), (b_name, _, b)| {
+        b.totals.size_estimate.cmp(&a.totals.size_estimate).then_with(|| a_name.cmp(b_name))
+    });
+    let generics = generics
+        .into_iter()
+        .map(|(name, def_id, generic_totals)| {
+            let mut object = BTreeMap::new();
+            object.insert("def".to_string(), name.to_json());
+            object.insert("crate".to_string(), tcx.crate_name(def_id.krate).to_string().to_json());
+            let requested_by = crates_to_json(tcx, &generic_totals.by_crate);
+            object.insert("requested_by".to_string(), requested_by);
+            generic_totals.totals.to_json_with(object)
+        })
+        .collect();
+
+    let mut report = BTreeMap::new();
+    report.insert("crate".to_string(), tcx.crate_name(LOCAL_CRATE).to_string().to_json());
+    report.insert("total".to_string(), total.to_json());
+    report.insert("generics".to_string(), Json::Array(generics));
+    report.insert("crates".to_string(), crates_to_json(tcx, &by_crate));
+    report.insert(
+        "items".to_string(),
+        Json::Array(item_reports.into_iter().map(|(_, item)| item).collect()),
+    );
+
+    println!("{}", Json::Object(report).pretty());
+}
@@ -81,7 +81,8 @@ pub struct FormatSpec<'a> {
     pub width_span: Option<InnerSpan>,
     /// The descriptor string representing the name of the format desired for
     /// this argument, this can be empty or any number of characters, although
-    /// it is required to be one word.
+    /// it is required to be one word. A custom spec is stored here verbatim,
+    /// starting with `%`.
     pub ty: &'a str,
     /// The span of the descriptor string (for diagnostics).
     pub ty_span: Option<InnerSpan>,
@@ -606,6 +607,19 @@ fn format(&mut self) -> FormatSpec<'a> {
             }
         } else if self.consume('?') {
             spec.ty = "?";
+        } else if let Some(&(start, '%')) = self.cur.peek() {
+            // A custom spec runs up to the closing brace and is kept verbatim,
+            // leading `%` included.
+            let mut end = self.input.len();
+            while let Some(&(pos, c)) = self.cur.peek() {
+                if c == '}' {
+                    end = pos;
+                    break;
+                }
+                self.cur.next();
+            }
+            spec.ty = &self.input[start..end];
+            spec.ty_span = Some(self.to_span_index(start).to(self.to_span_index(end)));
         } else {
             spec.ty = self.word();
             let ty_span_end = self.cur.peek().map(|(pos, _)| *pos);
@@ -270,6 +270,27 @@ fn format_flags() {
     );
 }
 #[test]
+fn format_custom() {
+    same(
+        "{:>8%Y-%m d}",
+        &[NextArgument(Argument {
+            position: ArgumentImplicitlyIs(0),
+            format: FormatSpec {
+                fill: None,
+                align: AlignRight,
+                flags: 0,
+                precision: CountImplied,
+                width: CountIs(8),
+                precision_span: None,
+                width_span: None,
+                ty: "%Y-%m d",
+                ty_span: Some(InnerSpan::new(5, 12)),
+            },
+        })],
+    );
+    musterr("{:%Y");
+}
+#[test]
 fn format_mixture() {
     same(
         "abcd {3:x} efg",
@@ -119,6 +119,7 @@ fn check_attributes(
                     self.check_default_method_body_is_const(attr, span, target)
                 }
                 sym::must_not_suspend => self.check_must_not_suspend(&attr, span, target),
+                sym::blocking => self.check_blocking(&attr, span, target),
                 sym::must_use => self.check_must_use(hir_id, &attr, span, target),
                 sym::rustc_pass_by_value => self.check_pass_by_value(&attr, span, target),
                 sym::rustc_allow_incoherent_impl => {
@@ -1184,6 +1185,24 @@ fn check_must_not_suspend(&self, attr: &Attribute, span: Span, target: Target) -
         }
     }
 
+    /// Checks if `#[blocking]` is applied to a function. Returns `true` if valid.
+    fn check_blocking(&self, attr: &Attribute, span: Span, target: Target) -> bool {
+        match target {
+            Target::Fn | Target::Method(..) => true,
+            _ => {
+                self.tcx
+                    .sess
+                    .struct_span_err(
+                        attr.span,
+                        "`blocking` attribute should be applied to a function",
+                    )
+                    .span_label(span, "not a function")
+                    .emit();
+                false
+            }
+        }
+    }
+
     /// Checks if `#[cold]` is applied to a non-function. Returns `true` if valid.
     fn check_cold(&self, hir_id: HirId, attr: &Attribute, span: Span, target: Target) {
         match target {
@@ -0,0 +1,143 @@
+//! Checks the spec of every `{:%...}` placeholder against the `CustomFormat`
+//! implementation of its argument.
+//!
+//! `format_args!` expands such a placeholder to a call to
+//! `ArgumentV1::new_custom`, passing a value of a fresh type that carries the
+//! spec and is spanned on it. `new_custom` also forces `CustomFormat::CHECK_SPEC`
+//! when it is monomorphized, but that only happens in full builds and for
+//! functions that are actually instantiated. Evaluating the check here instead
+//! reports a rejected spec at the placeholder, in `cargo check` as well, unless
+//! the argument's type depends on generic parameters.
+//!
+//! Only bodies containing a call to a function named `new_custom` are looked at
+//! with their type-check results, so that the pass costs next to nothing for
+//! code that doesn't use custom format specs.
+
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_middle::mir::interpret::get_slice_bytes;
+use rustc_middle::ty::query::Providers;
+use rustc_middle::ty::{self, TyCtxt, TypeFoldable};
+use rustc_span::sym;
+
+fn check_mod_fmt_custom_specs(tcx: TyCtxt<'_>, module_def_id: LocalDefId) {
+    let (Some(new_custom), Some(spec_error)) = (
+        tcx.get_diagnostic_item(sym::fmt_argument_new_custom),
+        tcx.get_diagnostic_item(sym::fmt_custom_spec_error),
+    ) else {
+        return;
+    };
+    let mut visitor = ItemVisitor { tcx, new_custom, spec_error };
+    tcx.hir().visit_item_likes_in_module(module_def_id, &mut visitor.as_deep_visitor());
+}
+
+pub fn provide(providers: &mut Providers) {
+    *providers = Providers { check_mod_fmt_custom_specs, ..*providers };
+}
+
+struct ItemVisitor<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    new_custom: DefId,
+    spec_error: DefId,
+}
+
+struct ExprVisitor<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    new_custom: DefId,
+    spec_error: DefId,
+}
+
+impl<'tcx> ExprVisitor<'tcx> {
+    /// Evaluates `CheckSpec::<T, S>::ERROR` for a call to `new_custom::<T, S>`,
+    /// and reports a non-empty message at `spec`, the value carrying `S`.
+    fn check_spec(&self, callee: &'tcx hir::Expr<'tcx>, spec: &'tcx hir::Expr<'tcx>) {
+        // The early-bound lifetime of `ArgumentV1<'a>` is skipped, leaving the
+        // type parameters of `new_custom`, which are those of `CheckSpec`.
+        let substs = self.typeck_results.node_substs(callee.hir_id);
+        let substs = self.tcx.mk_substs(substs.types().map(Into::into));
+        if substs.needs_subst() || substs.references_error() {
+            // Checked once `new_custom` is monomorphized.
+            return;
+        }
+        let substs = self.tcx.erase_regions(substs);
+
+        let unevaluated =
+            ty::Unevaluated::new(ty::WithOptConstParam::unknown(self.spec_error), substs);
+        let Ok(error) =
+            self.tcx.const_eval_resolve(ty::ParamEnv::reveal_all(), unevaluated, Some(spec.span))
+        else {
+            // Errors are reported by const-eval itself.
+            return;
+        };
+        let error = get_slice_bytes(&self.tcx, error);
+        if error.is_empty() {
+            return;
+        }
+
+        let msg = String::from_utf8_lossy(error);
+        self.tcx
+            .sess
+            .struct_span_err(
+                spec.span,
+                &format!("invalid format spec for `{}`", substs.type_at(0)),
+            )
+            .span_label(spec.span, &*msg)
+            .emit();
+    }
+}
+
+/// Looks for calls that may be of `new_custom`, without type-checking.
+struct CallFinder {
+    found: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for CallFinder {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Call(callee, [_, _]) = expr.kind
+            && let hir::ExprKind::Path(hir::QPath::TypeRelative(_, segment)) = callee.kind
+            && segment.ident.name == sym::new_custom
+        {
+            self.found = true;
+        } else if !self.found {
+            intravisit::walk_expr(self, expr);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for ItemVisitor<'tcx> {
+    fn visit_nested_body(&mut self, body_id: hir::BodyId) {
+        let body = self.tcx.hir().body(body_id);
+        let mut finder = CallFinder { found: false };
+        finder.visit_body(body);
+        if finder.found {
+            let owner_def_id = self.tcx.hir().body_owner_def_id(body_id);
+            let typeck_results = self.tcx.typeck(owner_def_id);
+            ExprVisitor {
+                tcx: self.tcx,
+                typeck_results,
+                new_custom: self.new_custom,
+                spec_error: self.spec_error,
+            }
+            .visit_body(body);
+        }
+        self.visit_body(body);
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for ExprVisitor<'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Call(callee, [_, spec]) = expr.kind
+            && let hir::ExprKind::Path(ref qpath) = callee.kind
+            && let Res::Def(DefKind::AssocFn, def_id) =
+                self.typeck_results.qpath_res(qpath, callee.hir_id)
+            && def_id == self.new_custom
+        {
+            self.check_spec(callee, spec);
+        }
+
+        intravisit::walk_expr(self, expr);
+    }
+}
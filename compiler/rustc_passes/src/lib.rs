@@ -28,6 +28,7 @@
 pub mod dead;
 mod diagnostic_items;
 pub mod entry;
+mod fmt_custom_spec;
 pub mod hir_id_validator;
 pub mod hir_stats;
 mod intrinsicck;
@@ -49,6 +50,7 @@ pub fn provide(providers: &mut Providers) {
     dead::provide(providers);
     diagnostic_items::provide(providers);
     entry::provide(providers);
+    fmt_custom_spec::provide(providers);
     lang_items::provide(providers);
     lib_features::provide(providers);
     loops::provide(providers);
@@ -94,6 +94,12 @@ struct DepGraphData<K: DepKind> {
     /// a particular query result was decoded from disk
     /// (not just marked green)
     debug_loaded_from_disk: Lock<FxHashSet<DepNode<K>>>,
+
+    /// Used to explain why queries were re-executed: for every node of the
+    /// previous session that could not be marked green, this records the
+    /// dependency that prevented it. Only present if requested when creating
+    /// the graph.
+    invalidated_by: Option<Lock<FxHashMap<SerializedDepNodeIndex, SerializedDepNodeIndex>>>,
 }
 
 pub fn hash_result<R>(hcx: &mut StableHashingContext<'_>, result: &R) -> Fingerprint
@@ -113,6 +119,7 @@ pub fn new(
         encoder: FileEncoder,
         record_graph: bool,
         record_stats: bool,
+        record_invalidations: bool,
     ) -> DepGraph<K> {
         let prev_graph_node_count = prev_graph.node_count();
 
@@ -142,6 +149,7 @@ pub fn new(
                 previous: prev_graph,
                 colors: DepNodeColorMap::new(prev_graph_node_count),
                 debug_loaded_from_disk: Default::default(),
+                invalidated_by: record_invalidations.then(Default::default),
             })),
             virtual_dep_node_index: Lrc::new(AtomicU32::new(0)),
         }
@@ -719,7 +727,12 @@ fn try_mark_previous_green<Ctxt: QueryContext<DepKind = K>>(
         let prev_deps = data.previous.edge_targets_from(prev_dep_node_index);
 
         for &dep_dep_node_index in prev_deps {
-            self.try_mark_parent_green(tcx, data, dep_dep_node_index, dep_node)?
+            if self.try_mark_parent_green(tcx, data, dep_dep_node_index, dep_node).is_none() {
+                if let Some(invalidated_by) = &data.invalidated_by {
+                    invalidated_by.lock().insert(prev_dep_node_index, dep_dep_node_index);
+                }
+                return None;
+            }
         }
 
         // If we got here without hitting a `return` that means that all
@@ -829,6 +842,34 @@ pub fn exec_cache_promotions<Ctxt: DepContext<DepKind = K>>(&self, tcx: Ctxt) {
         }
     }
 
+    /// Returns, for every node of the previous session that could not be marked
+    /// green and thus had to be re-executed, the chain of nodes that caused
+    /// this. Each node in a chain could not be marked green because of the
+    /// next one, and the last node is the one that changed in its own right
+    /// (or could not be forced).
+    ///
+    /// This is only recorded if the graph was created with
+    /// `record_invalidations`, otherwise no chains are returned.
+    pub fn invalidation_chains(&self) -> Vec<Vec<DepNode<K>>> {
+        let Some(data) = &self.data else { return Vec::new() };
+        let Some(invalidated_by) = &data.invalidated_by else { return Vec::new() };
+        let invalidated_by = invalidated_by.lock();
+
+        invalidated_by
+            .keys()
+            .map(|&start| {
+                let mut chain = vec![data.previous.index_to_node(start)];
+                let mut current = start;
+                // The graph is acyclic, so this always terminates.
+                while let Some(&next) = invalidated_by.get(&current) {
+                    chain.push(data.previous.index_to_node(next));
+                    current = next;
+                }
+                chain
+            })
+            .collect()
+    }
+
     pub fn print_incremental_info(&self) {
         if let Some(data) = &self.data {
             data.current.encoder.borrow().print_incremental_info(
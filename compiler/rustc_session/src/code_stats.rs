@@ -1,7 +1,9 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lock;
+use rustc_serialize::json::{Json, ToJson};
 use rustc_target::abi::{Align, Size};
 use std::cmp::{self, Ordering};
+use std::collections::BTreeMap;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct VariantInfo {
@@ -26,6 +28,39 @@ pub struct FieldInfo {
     pub align: u64,
 }
 
+/// The largest niche of a type: a scalar at `offset` whose values outside of
+/// `valid_range_start..=valid_range_end` (which may wrap around) are invalid,
+/// and can thus be used to encode the discriminant of an enclosing enum.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NicheInfo {
+    pub offset: u64,
+    pub size: u64,
+    pub valid_range_start: u128,
+    pub valid_range_end: u128,
+    /// The number of invalid values.
+    pub available: u128,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TagEncodingInfo {
+    /// The tag directly stores the discriminant.
+    Direct,
+    /// The tag is a niche in the fields of `dataful_variant`, and the variants
+    /// in `niche_variants` are encoded as the invalid values starting at
+    /// `niche_start`.
+    Niche { dataful_variant: String, niche_variants: Vec<String>, niche_start: u128 },
+}
+
+/// Where and how the variant of an enum is stored.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TagInfo {
+    pub offset: u64,
+    pub size: u64,
+    pub valid_range_start: u128,
+    pub valid_range_end: u128,
+    pub encoding: TagEncodingInfo,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum DataTypeKind {
     Struct,
@@ -43,6 +78,8 @@ pub struct TypeSizeInfo {
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
     pub variants: Vec<VariantInfo>,
+    pub niche: Option<NicheInfo>,
+    pub tag: Option<TagInfo>,
 }
 
 #[derive(Default)]
@@ -60,6 +97,8 @@ pub fn record_type_size<S: ToString>(
         packed: bool,
         opt_discr_size: Option<Size>,
         mut variants: Vec<VariantInfo>,
+        niche: Option<NicheInfo>,
+        tag: Option<TagInfo>,
     ) {
         // Sort variants so the largest ones are shown first. A stable sort is
         // used here so that source code order is preserved for all variants
@@ -73,25 +112,15 @@ pub fn record_type_size<S: ToString>(
             packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
             variants,
+            niche,
+            tag,
         };
         self.type_sizes.borrow_mut().insert(info);
     }
 
     pub fn print_type_sizes(&self) {
         let type_sizes = self.type_sizes.borrow();
-        let mut sorted: Vec<_> = type_sizes.iter().collect();
-
-        // Primary sort: large-to-small.
-        // Secondary sort: description (dictionary order)
-        sorted.sort_by(|info1, info2| {
-            // (reversing cmp order to get large-to-small ordering)
-            match info2.overall_size.cmp(&info1.overall_size) {
-                Ordering::Equal => info1.type_description.cmp(&info2.type_description),
-                other => other,
-            }
-        });
-
-        for info in sorted {
+        for info in sorted_type_sizes(&type_sizes) {
             let TypeSizeInfo { type_description, overall_size, align, kind, variants, .. } = info;
             println!(
                 "print-type-size type: `{type_description}`: {overall_size} bytes, alignment: {align} bytes"
@@ -178,4 +207,155 @@ pub fn print_type_sizes(&self) {
             }
         }
     }
+
+    /// Prints the same information as `print_type_sizes`, as a JSON array
+    /// with one object per type, extended with the padding between fields,
+    /// the largest niche of each type and the tag encoding of enums.
+    pub fn print_type_sizes_json(&self) {
+        let type_sizes = self.type_sizes.borrow();
+        let types = sorted_type_sizes(&type_sizes).into_iter().map(type_size_to_json).collect();
+        println!("{}", Json::Array(types).pretty());
+    }
+}
+
+/// Sorts type sizes large-to-small, and then by description (dictionary
+/// order).
+fn sorted_type_sizes(type_sizes: &FxHashSet<TypeSizeInfo>) -> Vec<&TypeSizeInfo> {
+    let mut sorted: Vec<_> = type_sizes.iter().collect();
+    sorted.sort_by(|info1, info2| {
+        // (reversing cmp order to get large-to-small ordering)
+        match info2.overall_size.cmp(&info1.overall_size) {
+            Ordering::Equal => info1.type_description.cmp(&info2.type_description),
+            other => other,
+        }
+    });
+    sorted
+}
+
+/// Values that may not fit in a JSON number are emitted as strings.
+fn u128_to_json(n: u128) -> Json {
+    match u64::try_from(n) {
+        Ok(n) => n.to_json(),
+        Err(_) => n.to_string().to_json(),
+    }
+}
+
+fn valid_range_to_json(start: u128, end: u128) -> Json {
+    let mut object = BTreeMap::new();
+    object.insert("start".to_string(), u128_to_json(start));
+    object.insert("end".to_string(), u128_to_json(end));
+    Json::Object(object)
+}
+
+fn type_size_to_json(info: &TypeSizeInfo) -> Json {
+    let discr_size = info.opt_discr_size.unwrap_or(0);
+    let mut max_variant_size = discr_size;
+
+    let variants = info
+        .variants
+        .iter()
+        .map(|variant| {
+            max_variant_size = cmp::max(max_variant_size, variant.size);
+
+            // Fields by increasing offset, zero-sized ones first, as when
+            // printing them as text.
+            let mut fields = variant.fields.clone();
+            fields.sort_by_key(|f| (f.offset, f.size));
+
+            let mut padding = Vec::new();
+            let mut min_offset = discr_size;
+            for field in &fields {
+                if field.offset > min_offset {
+                    let mut hole = BTreeMap::new();
+                    hole.insert("offset".to_string(), min_offset.to_json());
+                    hole.insert("size".to_string(), (field.offset - min_offset).to_json());
+                    padding.push(Json::Object(hole));
+                }
+                // Union fields overlap, so the next field may start before
+                // the end of this one.
+                min_offset = cmp::max(min_offset, field.offset + field.size);
+            }
+
+            let fields = fields
+                .into_iter()
+                .map(|field| {
+                    let mut object = BTreeMap::new();
+                    object.insert("name".to_string(), field.name.to_json());
+                    object.insert("offset".to_string(), field.offset.to_json());
+                    object.insert("size".to_string(), field.size.to_json());
+                    object.insert("align".to_string(), field.align.to_json());
+                    Json::Object(object)
+                })
+                .collect();
+
+            let kind = match variant.kind {
+                SizeKind::Exact => "exact",
+                SizeKind::Min => "min",
+            };
+            let mut object = BTreeMap::new();
+            object.insert("name".to_string(), variant.name.to_json());
+            object.insert("kind".to_string(), kind.to_json());
+            object.insert("size".to_string(), variant.size.to_json());
+            object.insert("align".to_string(), variant.align.to_json());
+            object.insert("fields".to_string(), Json::Array(fields));
+            object.insert("padding".to_string(), Json::Array(padding));
+            Json::Object(object)
+        })
+        .collect();
+
+    let niche = info.niche.as_ref().map(|niche| {
+        let mut object = BTreeMap::new();
+        object.insert("offset".to_string(), niche.offset.to_json());
+        object.insert("size".to_string(), niche.size.to_json());
+        object.insert(
+            "valid_range".to_string(),
+            valid_range_to_json(niche.valid_range_start, niche.valid_range_end),
+        );
+        object.insert("available".to_string(), u128_to_json(niche.available));
+        Json::Object(object)
+    });
+
+    let tag = info.tag.as_ref().map(|tag| {
+        let mut object = BTreeMap::new();
+        object.insert("offset".to_string(), tag.offset.to_json());
+        object.insert("size".to_string(), tag.size.to_json());
+        object.insert(
+            "valid_range".to_string(),
+            valid_range_to_json(tag.valid_range_start, tag.valid_range_end),
+        );
+        match tag.encoding {
+            TagEncodingInfo::Direct => {
+                object.insert("encoding".to_string(), "direct".to_json());
+            }
+            TagEncodingInfo::Niche { ref dataful_variant, ref niche_variants, niche_start } => {
+                object.insert("encoding".to_string(), "niche".to_json());
+                object.insert("dataful_variant".to_string(), dataful_variant.to_json());
+                object.insert("niche_variants".to_string(), niche_variants.to_json());
+                object.insert("niche_start".to_string(), u128_to_json(niche_start));
+            }
+        }
+        Json::Object(object)
+    });
+
+    let kind = match info.kind {
+        DataTypeKind::Struct => "struct",
+        DataTypeKind::Union => "union",
+        DataTypeKind::Enum => "enum",
+        DataTypeKind::Closure => "closure",
+    };
+    let mut object = BTreeMap::new();
+    object.insert("type".to_string(), info.type_description.to_json());
+    object.insert("kind".to_string(), kind.to_json());
+    object.insert("size".to_string(), info.overall_size.to_json());
+    object.insert("align".to_string(), info.align.to_json());
+    object.insert("packed".to_string(), info.packed.to_json());
+    object.insert("discriminant_size".to_string(), info.opt_discr_size.to_json());
+    object.insert("variants".to_string(), Json::Array(variants));
+    object.insert(
+        "end_padding".to_string(),
+        info.overall_size.saturating_sub(max_variant_size).to_json(),
+    );
+    object.insert("niche".to_string(), niche.to_json());
+    object.insert("tag".to_string(), tag.to_json());
+    Json::Object(object)
 }
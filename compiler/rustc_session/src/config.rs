@@ -3,6 +3,7 @@
 
 pub use crate::options::*;
 
+use crate::lint_config::LintConfig;
 use crate::search_paths::SearchPath;
 use crate::utils::{CanonicalizedPath, NativeLib, NativeLibKind};
 use crate::{early_error, early_warn, Session};
@@ -540,6 +541,8 @@ pub enum PrintRequest {
     NativeStaticLibs,
     StackProtectorStrategies,
     LinkArgs,
+    MonomorphizationReport,
+    TypeSizes,
 }
 
 #[derive(Copy, Clone)]
@@ -727,6 +730,7 @@ fn default() -> Options {
             debuginfo: DebugInfo::None,
             lint_opts: Vec::new(),
             lint_cap: None,
+            lint_config: None,
             describe_lints: false,
             output_types: OutputTypes(BTreeMap::new()),
             search_paths: vec![],
@@ -1353,7 +1357,7 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
             "[crate-name|file-names|sysroot|target-libdir|cfg|target-list|\
              target-cpus|target-features|relocation-models|code-models|\
              tls-models|target-spec-json|native-static-libs|stack-protector-strategies|\
-             link-args]",
+             link-args|monomorphization-report|type-sizes]",
         ),
         opt::flagmulti_s("g", "", "Equivalent to -C debuginfo=2"),
         opt::flagmulti_s("O", "", "Equivalent to -C opt-level=2"),
@@ -1775,6 +1779,28 @@ fn collect_print_requests(
             }
         }
         "link-args" => PrintRequest::LinkArgs,
+        "monomorphization-report" => {
+            if dopts.unstable_options {
+                PrintRequest::MonomorphizationReport
+            } else {
+                early_error(
+                    error_format,
+                    "the `-Z unstable-options` flag must also be passed to \
+                     enable the monomorphization-report print option",
+                );
+            }
+        }
+        "type-sizes" => {
+            if dopts.unstable_options {
+                PrintRequest::TypeSizes
+            } else {
+                early_error(
+                    error_format,
+                    "the `-Z unstable-options` flag must also be passed to \
+                     enable the type-sizes print option",
+                );
+            }
+        }
         req => early_error(error_format, &format!("unknown print request `{req}`")),
     }));
 
@@ -2215,6 +2241,10 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
 
     check_debug_option_stability(&debugging_opts, error_format, json_rendered);
 
+    let lint_config = debugging_opts.lint_config.as_ref().map(|path| {
+        LintConfig::load(path).unwrap_or_else(|err| early_error(error_format, &err))
+    });
+
     if !debugging_opts.unstable_options && json_unused_externs {
         early_error(
             error_format,
@@ -2469,6 +2499,7 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         debuginfo,
         lint_opts,
         lint_cap,
+        lint_config,
         describe_lints,
         output_types,
         search_paths,
@@ -2735,6 +2766,7 @@ pub fn needs_analysis(&self) -> bool {
         SymbolManglingVersion, TrimmedDefPaths,
     };
     use crate::lint;
+    use crate::lint_config::LintConfig;
     use crate::options::WasiExecModel;
     use crate::utils::{NativeLib, NativeLibKind};
     use rustc_errors::LanguageIdentifier;
@@ -2795,6 +2827,7 @@ fn hash(
         String,
         PathBuf,
         lint::Level,
+        LintConfig,
         WasiExecModel,
         u32,
         RelocModel,
@@ -0,0 +1,199 @@
+//! Attributes compile time to the items being compiled, enabled with
+//! `-Z time-items=N`.
+//!
+//! `-Z time-passes` reports the total time spent in each pass, but not which
+//! items were expensive to compile. With `-Z time-items`, the type checking,
+//! borrow checking, MIR optimization and codegen of every item is timed, and
+//! the `N` items that took the longest are listed at the end of the session.
+//!
+//! Queries invoked from a timed section can run other timed sections, e.g.
+//! the MIR optimization of a callee that is inlined. Only the self time of a
+//! section is attributed to its item, so that nothing is counted twice.
+//!
+//! The time LLVM spends on the functions of an item is attributed using the
+//! `-Z llvm-time-trace` profiler, whose events carry the symbol name of the
+//! function they belong to. Every instantiation of an item that is codegened
+//! records its symbol name here, which is also used to count the
+//! instantiations of generic items.
+//!
+//! Since the report is only printed after LLVM has finished, which happens
+//! after the `TyCtxt` has been dropped, the items have to be described with
+//! `describe_items` while the `TyCtxt` is still alive.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::Lock;
+use rustc_span::def_id::DefId;
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// The phases of the compilation of an item that are timed.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ItemPhase {
+    Typeck,
+    Borrowck,
+    MirOpt,
+    Codegen,
+    Llvm,
+}
+
+impl ItemPhase {
+    const ALL: [ItemPhase; 5] = [
+        ItemPhase::Typeck,
+        ItemPhase::Borrowck,
+        ItemPhase::MirOpt,
+        ItemPhase::Codegen,
+        ItemPhase::Llvm,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            ItemPhase::Typeck => "typeck",
+            ItemPhase::Borrowck => "borrowck",
+            ItemPhase::MirOpt => "mir-opt",
+            ItemPhase::Codegen => "codegen",
+            ItemPhase::Llvm => "llvm",
+        }
+    }
+}
+
+thread_local! {
+    /// For every timed section currently running on this thread, innermost
+    /// last, the time spent in the timed sections nested within it.
+    static NESTED_TIME: RefCell<Vec<Duration>> = RefCell::new(Vec::new());
+}
+
+#[derive(Default)]
+struct ItemTiming {
+    phases: [Duration; ItemPhase::ALL.len()],
+}
+
+impl ItemTiming {
+    fn total(&self) -> Duration {
+        self.phases.iter().sum()
+    }
+}
+
+#[derive(Default)]
+pub struct ItemTimings {
+    items: Lock<FxHashMap<DefId, ItemTiming>>,
+    /// The item every codegened symbol is an instantiation of.
+    symbols: Lock<FxHashMap<String, DefId>>,
+    /// The path and the span of every item, see `describe_items`.
+    descriptions: Lock<FxHashMap<DefId, (String, String)>>,
+}
+
+impl ItemTimings {
+    pub fn start(&self, phase: ItemPhase, def_id: DefId) -> ItemTimer<'_> {
+        NESTED_TIME.with(|nested| nested.borrow_mut().push(Duration::ZERO));
+        ItemTimer(Some((self, phase, def_id, Instant::now())))
+    }
+
+    pub fn record(&self, phase: ItemPhase, def_id: DefId, duration: Duration) {
+        let mut items = self.items.lock();
+        items.entry(def_id).or_default().phases[phase as usize] += duration;
+    }
+
+    /// Records that `symbol_name` is an instantiation of the item `def_id`.
+    pub fn record_symbol(&self, def_id: DefId, symbol_name: &str) {
+        let mut symbols = self.symbols.lock();
+        if !symbols.contains_key(symbol_name) {
+            symbols.insert(symbol_name.to_owned(), def_id);
+        }
+    }
+
+    /// Returns the item the symbol `symbol_name` is an instantiation of, if it
+    /// has been codegened in this session.
+    pub fn symbol_item(&self, symbol_name: &str) -> Option<DefId> {
+        self.symbols.lock().get(symbol_name).copied()
+    }
+
+    /// Describes all items that time has been attributed to so far, using
+    /// `describe` to compute their path and span.
+    pub fn describe_items(&self, describe: impl Fn(DefId) -> (String, String)) {
+        let items = self.items.lock();
+        let symbols = self.symbols.lock();
+        let mut descriptions = self.descriptions.lock();
+        for &def_id in items.keys().chain(symbols.values()) {
+            descriptions.entry(def_id).or_insert_with(|| describe(def_id));
+        }
+    }
+
+    /// Prints the `count` items that took the longest to compile.
+    pub fn print_report(&self, count: usize) {
+        let items = self.items.lock();
+        let descriptions = self.descriptions.lock();
+
+        let mut instantiations: FxHashMap<DefId, usize> = FxHashMap::default();
+        for &def_id in self.symbols.lock().values() {
+            *instantiations.entry(def_id).or_default() += 1;
+        }
+
+        let mut items: Vec<_> = items
+            .iter()
+            .map(|(def_id, timing)| {
+                let (path, span) = descriptions
+                    .get(def_id)
+                    .cloned()
+                    .unwrap_or_else(|| (format!("{:?}", def_id), String::new()));
+                (timing, path, span, instantiations.get(def_id).copied().unwrap_or(0))
+            })
+            .collect();
+        items.sort_by(|(a, a_path, ..), (b, b_path, ..)| {
+            b.total().cmp(&a.total()).then_with(|| a_path.cmp(b_path))
+        });
+
+        let total: Duration = items.iter().map(|(timing, ..)| timing.total()).sum();
+        eprintln!(
+            "time-items: {} of {} items, {:.3}ms in total",
+            count.min(items.len()),
+            items.len(),
+            millis(total)
+        );
+
+        let mut header = format!("{:>10}", "total");
+        for phase in ItemPhase::ALL {
+            header.push_str(&format!(" {:>10}", phase.name()));
+        }
+        eprintln!("{} {:>6}  item", header, "inst");
+
+        for (timing, path, span, instantiations) in items.into_iter().take(count) {
+            let mut line = format!("{:>10.3}", millis(timing.total()));
+            for duration in timing.phases {
+                line.push_str(&format!(" {:>10.3}", millis(duration)));
+            }
+            eprintln!("{} {:>6}  {} ({})", line, instantiations, path, span);
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Times a section of the compilation of an item until dropped.
+#[must_use]
+pub struct ItemTimer<'a>(Option<(&'a ItemTimings, ItemPhase, DefId, Instant)>);
+
+impl ItemTimer<'_> {
+    pub fn none() -> Self {
+        ItemTimer(None)
+    }
+}
+
+impl Drop for ItemTimer<'_> {
+    fn drop(&mut self) {
+        if let Some((timings, phase, def_id, start)) = self.0 {
+            let elapsed = start.elapsed();
+            let nested = NESTED_TIME.with(|nested| {
+                let mut nested = nested.borrow_mut();
+                let own_nested = nested.pop().unwrap();
+                if let Some(parent_nested) = nested.last_mut() {
+                    *parent_nested += elapsed;
+                }
+                own_nested
+            });
+            timings.record(phase, def_id, elapsed.saturating_sub(nested));
+        }
+    }
+}
@@ -20,10 +20,12 @@
 pub mod parse;
 
 mod code_stats;
+mod item_timings;
 #[macro_use]
 pub mod config;
 pub mod cstore;
 pub mod filesearch;
+pub mod lint_config;
 mod options;
 pub mod search_paths;
 
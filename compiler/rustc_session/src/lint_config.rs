@@ -0,0 +1,328 @@
+//! Lint levels read from a configuration file, passed with
+//! `-Z lint-config=<path>`.
+//!
+//! The configuration file is written in a subset of TOML: tables, keys and
+//! basic strings. The `[lints]` table sets the level of lints and lint groups
+//! for the whole crate, and every `[overrides."<glob>"]` table sets them for
+//! the modules loaded from files matching the glob:
+//!
+//! ```toml
+//! [lints]
+//! unsafe_code = "deny"
+//! "clippy::pedantic" = "warn"
+//!
+//! [overrides."src/ffi/**"]
+//! unsafe_code = "allow"
+//! ```
+//!
+//! The levels of the `[lints]` table are applied as if they were passed on
+//! the command line before all `-A`, `-W`, `-D` and `-F` flags, so these flags
+//! take precedence over them. The levels of an override are applied to a
+//! module loaded from a matching file as if they were attributes preceding
+//! the inner attributes of the module, so lint attributes within the module
+//! take precedence over them. Overrides listed later in the file take
+//! precedence over earlier ones.
+//!
+//! Globs are matched against the path of a file relative to the directory
+//! containing the configuration file, with `/` as the separator. `*` matches
+//! any sequence of characters except `/`, `?` matches any single character
+//! except `/`, and a `**` component matches any number of path components.
+
+use crate::lint::Level;
+
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::path::PathBuf;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug)]
+pub struct LintConfig {
+    /// The directory the globs of the overrides are relative to.
+    base_dir: PathBuf,
+    /// The path of the configuration file relative to the working directory,
+    /// or its absolute path if it is outside of the working directory.
+    relative_path: PathBuf,
+    /// The lint levels of the `[lints]` table.
+    pub lints: Vec<(String, Level)>,
+    pub overrides: Vec<LintConfigOverride>,
+}
+
+/// The config is part of the tracked options, so it is hashed without the
+/// absolute `base_dir`. Otherwise, the same crate compiled in different
+/// directories would not share the incremental cache.
+impl Hash for LintConfig {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        let LintConfig { base_dir: _, relative_path, lints, overrides } = self;
+        relative_path.hash(hasher);
+        lints.hash(hasher);
+        overrides.hash(hasher);
+    }
+}
+
+/// An `[overrides."<glob>"]` table.
+#[derive(Clone, Debug, Hash)]
+pub struct LintConfigOverride {
+    pub pattern: String,
+    pub lints: Vec<(String, Level)>,
+}
+
+impl LintConfig {
+    pub fn load(path: &Path) -> Result<LintConfig, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read lint config `{}`: {}", path.display(), err))?;
+        let working_dir = env::current_dir().ok();
+        let path = match &working_dir {
+            Some(dir) => dir.join(path),
+            None => path.into(),
+        };
+        let relative_path = working_dir
+            .and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf))
+            .unwrap_or_else(|| path.clone());
+        let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        parse(&contents, base_dir, relative_path)
+            .map_err(|(line, msg)| format!("{}:{}: {}", path.display(), line, msg))
+    }
+
+    /// Returns the overrides applying to the source file at the absolute path
+    /// `file`, in the order they have to be applied.
+    pub fn overrides_for(&self, file: &Path) -> impl Iterator<Item = &LintConfigOverride> {
+        let relative_path = file.strip_prefix(&self.base_dir).ok().map(|path| {
+            let components: Vec<_> = path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect();
+            components.join("/")
+        });
+        self.overrides.iter().filter(move |lint_override| match relative_path {
+            Some(ref path) => glob_matches(&lint_override.pattern, path),
+            None => false,
+        })
+    }
+}
+
+/// Returns the name of the table in which a lint level was set, with the
+/// pattern of the override if it was set in an override.
+pub fn table_name(pattern: Option<&str>) -> String {
+    match pattern {
+        Some(pattern) => format!("[overrides.{:?}]", pattern),
+        None => "[lints]".to_string(),
+    }
+}
+
+/// Parses a configuration file, returning the line and message of the first
+/// error.
+fn parse(
+    contents: &str,
+    base_dir: PathBuf,
+    relative_path: PathBuf,
+) -> Result<LintConfig, (usize, String)> {
+    let mut config =
+        LintConfig { base_dir, relative_path, lints: Vec::new(), overrides: Vec::new() };
+    let mut seen_lints_table = false;
+    // The table the current line belongs to, `None` before the first table.
+    let mut current = None;
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let mut parser = Parser { rest: line };
+        parser.skip_whitespace();
+        if parser.at_end() {
+            continue;
+        }
+
+        if parser.eat('[') {
+            let mut keys = vec![parser.parse_key().map_err(|msg| (line_number, msg))?];
+            while parser.eat('.') {
+                keys.push(parser.parse_key().map_err(|msg| (line_number, msg))?);
+            }
+            if !parser.eat(']') || !parser.at_end() {
+                return Err((line_number, "expected `]` at the end of the table header".into()));
+            }
+
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            current = match keys[..] {
+                ["lints"] if !seen_lints_table => {
+                    seen_lints_table = true;
+                    Some(Table::Lints)
+                }
+                ["overrides", pattern]
+                    if !config.overrides.iter().any(|o| o.pattern == pattern) =>
+                {
+                    config
+                        .overrides
+                        .push(LintConfigOverride { pattern: pattern.to_string(), lints: vec![] });
+                    Some(Table::Override(config.overrides.len() - 1))
+                }
+                ["lints"] | ["overrides", _] => {
+                    return Err((line_number, "duplicate table".into()));
+                }
+                _ => {
+                    return Err((
+                        line_number,
+                        "unknown table, expected `[lints]` or `[overrides.\"<glob>\"]`".into(),
+                    ));
+                }
+            };
+            continue;
+        }
+
+        let lint_name = parser.parse_key().map_err(|msg| (line_number, msg))?;
+        if !parser.eat('=') {
+            return Err((line_number, "expected `=` after the lint name".into()));
+        }
+        let level = parser.parse_string().map_err(|msg| (line_number, msg))?;
+        if !parser.at_end() {
+            return Err((line_number, "unexpected characters after the lint level".into()));
+        }
+
+        let lints = match current {
+            Some(Table::Lints) => &mut config.lints,
+            Some(Table::Override(index)) => &mut config.overrides[index].lints,
+            None => {
+                return Err((line_number, "lint levels have to be set within a table".into()));
+            }
+        };
+        let Some(level) = Level::from_str(&level) else {
+            return Err((
+                line_number,
+                format!(
+                    "unknown lint level `{}`, expected `allow`, `warn`, `deny` or `forbid`",
+                    level
+                ),
+            ));
+        };
+        let lint_name = lint_name.replace('-', "_");
+        if lints.iter().any(|(name, _)| *name == lint_name) {
+            return Err((line_number, format!("duplicate lint `{}`", lint_name)));
+        }
+        lints.push((lint_name, level));
+    }
+
+    Ok(config)
+}
+
+#[derive(Clone, Copy)]
+enum Table {
+    Lints,
+    Override(usize),
+}
+
+/// Parses the keys and values of a line of a configuration file.
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start_matches([' ', '\t']);
+        if self.rest.starts_with('#') {
+            self.rest = "";
+        }
+    }
+
+    fn at_end(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Consumes `c` and the whitespace following it, if the line continues
+    /// with `c`.
+    fn eat(&mut self, c: char) -> bool {
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                self.skip_whitespace();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Parses a bare or quoted key.
+    fn parse_key(&mut self) -> Result<String, String> {
+        if self.rest.starts_with('"') {
+            return self.parse_string();
+        }
+        let len = self
+            .rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(self.rest.len());
+        if len == 0 {
+            return Err("expected a key".into());
+        }
+        let key = self.rest[..len].to_string();
+        self.rest = &self.rest[len..];
+        self.skip_whitespace();
+        Ok(key)
+    }
+
+    /// Parses a basic string.
+    fn parse_string(&mut self) -> Result<String, String> {
+        let Some(rest) = self.rest.strip_prefix('"') else {
+            return Err("expected a string".into());
+        };
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &rest[i + 1..];
+                    self.skip_whitespace();
+                    return Ok(value);
+                }
+                '\\' => match chars.next() {
+                    Some((_, '"')) => value.push('"'),
+                    Some((_, '\\')) => value.push('\\'),
+                    Some((_, 't')) => value.push('\t'),
+                    Some((_, 'n')) => value.push('\n'),
+                    _ => return Err("unsupported escape sequence in string".into()),
+                },
+                c => value.push(c),
+            }
+        }
+        Err("unterminated string".into())
+    }
+}
+
+/// Matches the `/`-separated `path` against the glob `pattern`.
+fn glob_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+    components_match(&pattern, &path)
+}
+
+fn components_match(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", pattern_rest)) => {
+            (0..=path.len()).any(|skipped| components_match(pattern_rest, &path[skipped..]))
+        }
+        Some((component_pattern, pattern_rest)) => match path.split_first() {
+            Some((component, path_rest)) => {
+                let component_pattern: Vec<char> = component_pattern.chars().collect();
+                let component: Vec<char> = component.chars().collect();
+                component_matches(&component_pattern, &component)
+                    && components_match(pattern_rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn component_matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', pattern_rest)) => {
+            (0..=name.len()).any(|skipped| component_matches(pattern_rest, &name[skipped..]))
+        }
+        Some(('?', pattern_rest)) => {
+            !name.is_empty() && component_matches(pattern_rest, &name[1..])
+        }
+        Some((c, pattern_rest)) => {
+            name.first() == Some(c) && component_matches(pattern_rest, &name[1..])
+        }
+    }
+}
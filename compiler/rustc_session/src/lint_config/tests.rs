@@ -0,0 +1,135 @@
+use super::*;
+
+fn parse_str(contents: &str) -> Result<LintConfig, (usize, String)> {
+    parse(contents, PathBuf::from("/base"), PathBuf::from("lints.toml"))
+}
+
+fn parse_err(contents: &str) -> (usize, String) {
+    parse_str(contents).unwrap_err()
+}
+
+#[test]
+fn test_parse() {
+    let config = parse_str(
+        r#"
+# A comment.
+[lints]
+unsafe_code = "deny"   # A trailing comment.
+non-snake-case="allow"
+"clippy::pedantic" = "warn"
+
+[ overrides . "src/ffi/**" ]
+unsafe_code = "allow"
+
+[overrides."a \"quoted\" \\ path"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.lints,
+        [
+            ("unsafe_code".to_string(), Level::Deny),
+            ("non_snake_case".to_string(), Level::Allow),
+            ("clippy::pedantic".to_string(), Level::Warn),
+        ]
+    );
+    assert_eq!(config.overrides.len(), 2);
+    assert_eq!(config.overrides[0].pattern, "src/ffi/**");
+    assert_eq!(config.overrides[0].lints, [("unsafe_code".to_string(), Level::Allow)]);
+    assert_eq!(config.overrides[1].pattern, r#"a "quoted" \ path"#);
+    assert!(config.overrides[1].lints.is_empty());
+}
+
+#[test]
+fn test_parse_empty() {
+    let config = parse_str("\n  \n# Only a comment.\n").unwrap();
+    assert!(config.lints.is_empty());
+    assert!(config.overrides.is_empty());
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(parse_err("unsafe_code = \"deny\"").0, 1);
+    assert_eq!(parse_err("[lints]\n\n[lints]"), (3, "duplicate table".to_string()));
+    assert_eq!(
+        parse_err("[overrides.\"a\"]\n[overrides.\"a\"]"),
+        (2, "duplicate table".to_string())
+    );
+    assert_eq!(
+        parse_err("[lints]\nunsafe_code = \"deny\"\nunsafe-code = \"warn\""),
+        (3, "duplicate lint `unsafe_code`".to_string())
+    );
+
+    let message = |contents: &str| parse_err(contents).1;
+    assert_eq!(message("unsafe_code = \"deny\""), "lint levels have to be set within a table");
+    assert!(message("[lint]").starts_with("unknown table"));
+    assert!(message("[overrides]").starts_with("unknown table"));
+    assert!(message("[overrides.\"a\".b]").starts_with("unknown table"));
+    assert_eq!(message("[lints"), "expected `]` at the end of the table header");
+    assert_eq!(message("[lints] x"), "expected `]` at the end of the table header");
+    assert_eq!(message("[]"), "expected a key");
+    assert_eq!(message("[overrides.\"a]"), "unterminated string");
+    assert_eq!(message("[lints]\nunsafe_code \"deny\""), "expected `=` after the lint name");
+    assert_eq!(message("[lints]\nunsafe_code = deny"), "expected a string");
+    assert_eq!(message("[lints]\nunsafe_code = \"deny"), "unterminated string");
+    assert_eq!(message("[lints]\nunsafe_code = \"\\x\""), "unsupported escape sequence in string");
+    assert_eq!(message("[lints]\nunsafe_code = \"\\"), "unsupported escape sequence in string");
+    assert_eq!(
+        message("[lints]\nunsafe_code = \"deny\" \"warn\""),
+        "unexpected characters after the lint level"
+    );
+    assert!(message("[lints]\nunsafe_code = \"loud\"").starts_with("unknown lint level `loud`"));
+}
+
+#[test]
+fn test_glob_matches() {
+    assert!(glob_matches("src/lib.rs", "src/lib.rs"));
+    assert!(!glob_matches("src/lib.rs", "src/main.rs"));
+    assert!(!glob_matches("src", "src/lib.rs"));
+    assert!(!glob_matches("src/lib.rs", "src"));
+
+    assert!(glob_matches("src/*.rs", "src/lib.rs"));
+    assert!(glob_matches("src/*.rs", "src/.rs"));
+    assert!(glob_matches("*", "lib.rs"));
+    assert!(!glob_matches("*", "src/lib.rs"));
+    assert!(!glob_matches("src/*", "src/ffi/mod.rs"));
+    assert!(glob_matches("src/*/mod.rs", "src/ffi/mod.rs"));
+    assert!(glob_matches("*a*b*", "xaybz"));
+    assert!(!glob_matches("*a*b*", "xbya"));
+
+    assert!(glob_matches("src/?.rs", "src/a.rs"));
+    assert!(glob_matches("src/?.rs", "src/é.rs"));
+    assert!(!glob_matches("src/?.rs", "src/.rs"));
+    assert!(!glob_matches("src/?.rs", "src/ab.rs"));
+    assert!(!glob_matches("src?lib.rs", "src/lib.rs"));
+}
+
+#[test]
+fn test_glob_matches_recursive() {
+    assert!(glob_matches("**", "lib.rs"));
+    assert!(glob_matches("**", "src/ffi/mod.rs"));
+    assert!(glob_matches("src/**", "src/lib.rs"));
+    assert!(glob_matches("src/**", "src/ffi/sys/mod.rs"));
+    assert!(glob_matches("src/**/mod.rs", "src/mod.rs"));
+    assert!(glob_matches("src/**/mod.rs", "src/ffi/sys/mod.rs"));
+    assert!(!glob_matches("src/**/mod.rs", "src/ffi/sys/lib.rs"));
+    assert!(glob_matches("**/*.rs", "src/lib.rs"));
+    assert!(glob_matches("src/**/**/mod.rs", "src/mod.rs"));
+    assert!(!glob_matches("src/**", "tests/lib.rs"));
+    // `**` is only special as a whole component.
+    assert!(!glob_matches("src/a**", "src/a/b.rs"));
+    assert!(glob_matches("src/a**", "src/ab.rs"));
+}
+
+#[test]
+fn test_overrides_for() {
+    let config =
+        parse_str("[overrides.\"src/**\"]\n[overrides.\"*.rs\"]\n[overrides.\"src/ffi/*.rs\"]")
+            .unwrap();
+    let patterns = |file: &str| -> Vec<String> {
+        config.overrides_for(Path::new(file)).map(|o| o.pattern.clone()).collect()
+    };
+    assert_eq!(patterns("/base/src/ffi/mod.rs"), ["src/**", "src/ffi/*.rs"]);
+    assert_eq!(patterns("/base/build.rs"), ["*.rs"]);
+    assert!(patterns("/elsewhere/src/lib.rs").is_empty());
+}
@@ -2,6 +2,7 @@
 
 use crate::early_error;
 use crate::lint;
+use crate::lint_config::LintConfig;
 use crate::search_paths::SearchPath;
 use crate::utils::NativeLib;
 use rustc_errors::LanguageIdentifier;
@@ -160,6 +161,8 @@ pub struct Options {
         debuginfo: DebugInfo [TRACKED],
         lint_opts: Vec<(String, lint::Level)> [TRACKED_NO_CRATE_HASH],
         lint_cap: Option<lint::Level> [TRACKED_NO_CRATE_HASH],
+        /// The lint levels read from the file passed with `-Z lint-config`.
+        lint_config: Option<LintConfig> [TRACKED_NO_CRATE_HASH],
         describe_lints: bool [UNTRACKED],
         output_types: OutputTypes [TRACKED],
         search_paths: Vec<SearchPath> [UNTRACKED],
@@ -1182,7 +1185,7 @@ mod parse {
     cf_protection: CFProtection = (CFProtection::None, parse_cfprotection, [TRACKED],
         "instrument control-flow architecture protection"),
     cgu_partitioning_strategy: Option<String> = (None, parse_opt_string, [TRACKED],
-        "the codegen unit partitioning strategy to use"),
+        "the codegen unit partitioning strategy to use (`default` or `balanced`)"),
     chalk: bool = (false, parse_bool, [TRACKED],
         "enable the experimental Chalk-based trait solving engine"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
@@ -1241,6 +1244,10 @@ mod parse {
         an additional `.html` file showing the computed coverage spans."),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
         "emit a section containing stack size metadata (default: no)"),
+    explain_query_reexecution: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write why each query had to be re-executed in an incremental session to \
+        `reexecuted-queries.json` in the given directory, and a summary by changed \
+        source file to `reexecuted-queries.txt`"),
     fewer_names: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "reduce memory use by retaining fewer names within compilation artifacts (LLVM-IR) \
         (default: no)"),
@@ -1271,6 +1278,10 @@ mod parse {
         (default: no)"),
     incremental_relative_spans: bool = (false, parse_bool, [TRACKED],
         "hash spans relative to their parent item for incr. comp. (default: no)"),
+    incremental_shared_cache: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "also store the incremental compilation cache in the given content-addressed \
+        directory, which can be shared between working directories and machines \
+        (use `--remap-path-prefix` to make paths match)"),
     incremental_verify_ich: bool = (false, parse_bool, [UNTRACKED],
         "verify incr. comp. hashes of green query instances (default: no)"),
     inline_mir: Option<bool> = (None, parse_opt_bool, [TRACKED],
@@ -1299,6 +1310,10 @@ mod parse {
         "link native libraries in the linker invocation (default: yes)"),
     link_only: bool = (false, parse_bool, [TRACKED],
         "link the `.rlink` file generated by `-Z no-link` (default: no)"),
+    lint_config: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "read lint levels from the `[lints]` and `[overrides]` tables of the given TOML file"),
+    lint_plugin: Vec<String> = (Vec::new(), parse_string_push, [TRACKED_NO_CRATE_HASH],
+        "load a lint plugin from the given dynamic library (may be specified multiple times)"),
     llvm_plugins: Vec<String> = (Vec::new(), parse_list, [TRACKED],
         "a list LLVM plugins to enable (space separated)"),
     llvm_time_trace: bool = (false, parse_bool, [UNTRACKED],
@@ -1433,6 +1448,10 @@ mod parse {
     self_profile: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
         parse_switch_with_opt_path, [UNTRACKED],
         "run the self profiler and output the raw event data"),
+    self_profile_chrome_trace: bool = (false, parse_bool, [UNTRACKED],
+        "also write the events recorded by the self profiler as a Chrome trace \
+        (`{crate}-{pid}.trace.json`), which can be opened in `chrome://tracing` or \
+        https://ui.perfetto.dev (default: no)"),
     /// keep this in sync with the event filter names in librustc_data_structures/profiling.rs
     self_profile_events: Option<Vec<String>> = (None, parse_opt_comma_list, [UNTRACKED],
         "specify the events recorded by the self profiler;
@@ -1497,6 +1516,9 @@ mod parse {
         "use a thread pool with N threads"),
     time: bool = (false, parse_bool, [UNTRACKED],
         "measure time of rustc processes (default: no)"),
+    time_items: Option<usize> = (None, parse_opt_number, [UNTRACKED],
+        "print the N items that took the longest to type check, borrow check, optimize \
+        and codegen, including the time LLVM spent on their functions"),
     time_llvm_passes: bool = (false, parse_bool, [UNTRACKED],
         "measure time of each LLVM pass (default: no)"),
     time_passes: bool = (false, parse_bool, [UNTRACKED],
@@ -1,7 +1,9 @@
 use crate::cgu_reuse_tracker::CguReuseTracker;
 use crate::code_stats::CodeStats;
 pub use crate::code_stats::{DataTypeKind, FieldInfo, SizeKind, VariantInfo};
+pub use crate::code_stats::{NicheInfo, TagEncodingInfo, TagInfo};
 use crate::config::{self, CrateType, OutputType, SwitchWithOptPath};
+pub use crate::item_timings::{ItemPhase, ItemTimer, ItemTimings};
 use crate::parse::ParseSess;
 use crate::search_paths::{PathKind, SearchPath};
 use crate::{filesearch, lint};
@@ -162,6 +164,10 @@ pub struct Session {
     /// Data about code being compiled, gathered during compilation.
     pub code_stats: CodeStats,
 
+    /// Compile time attributed to the items being compiled, used by
+    /// `-Z time-items`.
+    pub item_timings: ItemTimings,
+
     /// Tracks fuel info if `-zfuel=crate=n` is specified.
     optimization_fuel: Lock<OptimizationFuel>,
 
@@ -251,6 +257,9 @@ fn check_miri_unleashed_features(&self) {
     /// Invoked all the way at the end to finish off diagnostics printing.
     pub fn finish_diagnostics(&self, registry: &Registry) {
         self.check_miri_unleashed_features();
+        if let Err(err) = self.prof.write_chrome_trace() {
+            self.warn(&err.to_string());
+        }
         self.diagnostic().print_error_count(registry);
         self.emit_future_breakage();
     }
@@ -481,7 +490,7 @@ pub fn delay_span_bug<S: Into<MultiSpan>>(
     /// warnings or errors are emitted. If no messages are emitted ("good path"), then
     /// it's likely a bug.
     pub fn delay_good_path_bug(&self, msg: impl Into<DiagnosticMessage>) {
-        if self.opts.debugging_opts.print_type_sizes
+        if self.record_type_sizes()
             || self.opts.debugging_opts.query_dep_graph
             || self.opts.debugging_opts.dump_mir.is_some()
             || self.opts.debugging_opts.unpretty.is_some()
@@ -529,9 +538,20 @@ pub fn time_passes(&self) -> bool {
     pub fn instrument_mcount(&self) -> bool {
         self.opts.debugging_opts.instrument_mcount
     }
+    /// Whether layouts should be recorded in `code_stats`, for either
+    /// `-Z print-type-sizes` or `--print=type-sizes`.
+    pub fn record_type_sizes(&self) -> bool {
+        self.opts.debugging_opts.print_type_sizes
+            || self.opts.prints.contains(&config::PrintRequest::TypeSizes)
+    }
     pub fn time_llvm_passes(&self) -> bool {
         self.opts.debugging_opts.time_llvm_passes
     }
+    /// Whether the LLVM time trace profiler should run, either for
+    /// `-Z llvm-time-trace` or to attribute LLVM time for `-Z time-items`.
+    pub fn llvm_time_trace(&self) -> bool {
+        self.opts.debugging_opts.llvm_time_trace || self.opts.debugging_opts.time_items.is_some()
+    }
     pub fn meta_stats(&self) -> bool {
         self.opts.debugging_opts.meta_stats
     }
@@ -1241,6 +1261,7 @@ pub fn build_session(
             directory,
             sopts.crate_name.as_deref(),
             &sopts.debugging_opts.self_profile_events,
+            sopts.debugging_opts.self_profile_chrome_trace,
         );
         match profiler {
             Ok(profiler) => Some(Arc::new(profiler)),
@@ -1255,6 +1276,11 @@ pub fn build_session(
 
     let mut parse_sess = ParseSess::with_span_handler(span_diagnostic, source_map);
     parse_sess.assume_incomplete_release = sopts.debugging_opts.assume_incomplete_release;
+    if let Some(path) = &sopts.debugging_opts.lint_config {
+        // Like the files read by `include_str!`, the lint config is a
+        // dependency of the crate.
+        parse_sess.file_depinfo.get_mut().insert(Symbol::intern(&path.to_string_lossy()));
+    }
 
     let host_triple = config::host_triple();
     let target_triple = sopts.target_triple.triple();
@@ -1321,6 +1347,7 @@ pub fn build_session(
             normalize_projection_ty: AtomicUsize::new(0),
         },
         code_stats: Default::default(),
+        item_timings: Default::default(),
         optimization_fuel,
         print_fuel,
         jobserver: jobserver::client(),
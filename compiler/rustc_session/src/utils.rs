@@ -1,9 +1,10 @@
 use crate::parse::ParseSess;
-use crate::session::Session;
+use crate::session::{ItemPhase, ItemTimer, Session};
 use rustc_ast::token::{self, DelimToken, Nonterminal, Token};
 use rustc_ast::tokenstream::CanSynthesizeMissingTokens;
 use rustc_ast::tokenstream::{DelimSpan, TokenStream, TokenTree};
 use rustc_data_structures::profiling::VerboseTimingGuard;
+use rustc_span::def_id::DefId;
 use std::path::{Path, PathBuf};
 
 pub type NtToTokenstream = fn(&Nonterminal, &ParseSess, CanSynthesizeMissingTokens) -> TokenStream;
@@ -15,6 +16,15 @@ pub fn timer<'a>(&'a self, what: &'static str) -> VerboseTimingGuard<'a> {
     pub fn time<R>(&self, what: &'static str, f: impl FnOnce() -> R) -> R {
         self.prof.verbose_generic_activity(what).run(f)
     }
+    /// Attributes the time until the returned guard is dropped to `phase` of
+    /// the item `def_id`, if `-Z time-items` is enabled.
+    pub fn item_timer(&self, phase: ItemPhase, def_id: DefId) -> ItemTimer<'_> {
+        if self.opts.debugging_opts.time_items.is_some() {
+            self.item_timings.start(phase, def_id)
+        } else {
+            ItemTimer::none()
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Encodable, Decodable)]
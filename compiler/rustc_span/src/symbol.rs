@@ -161,6 +161,7 @@
         Copy,
         Count,
         Cow,
+        CustomSpec,
         Debug,
         DebugStruct,
         DebugTuple,
@@ -249,10 +250,12 @@
         Right,
         RustcDecodable,
         RustcEncodable,
+        SPEC,
         Send,
         SeqCst,
         SliceIndex,
         Some,
+        Spec,
         String,
         StructuralEq,
         StructuralPartialEq,
@@ -391,6 +394,7 @@
         bitxor_assign,
         black_box,
         block,
+        blocking,
         bool,
         borrowck_graphviz_format,
         borrowck_graphviz_postflow,
@@ -685,7 +689,9 @@
         fmaf32,
         fmaf64,
         fmt,
+        fmt_argument_new_custom,
         fmt_as_str,
+        fmt_custom_spec_error,
         fmt_internals,
         fmul_fast,
         fn_align,
@@ -939,6 +945,7 @@
         never_type,
         never_type_fallback,
         new,
+        new_custom,
         new_unchecked,
         next,
         nll,
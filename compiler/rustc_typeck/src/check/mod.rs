@@ -118,7 +118,7 @@
 use rustc_middle::ty::{self, Ty, TyCtxt, UserType};
 use rustc_session::config;
 use rustc_session::parse::feature_err;
-use rustc_session::Session;
+use rustc_session::{ItemPhase, Session};
 use rustc_span::source_map::DUMMY_SP;
 use rustc_span::symbol::{kw, Ident};
 use rustc_span::{self, BytePos, Span};
@@ -356,6 +356,8 @@ fn typeck_with_fallback<'tcx>(
         return tcx.typeck(typeck_root_def_id);
     }
 
+    let _timer = tcx.sess.item_timer(ItemPhase::Typeck, def_id.to_def_id());
+
     let id = tcx.hir().local_def_id_to_hir_id(def_id);
     let span = tcx.hir().span(id);
 
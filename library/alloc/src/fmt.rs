@@ -313,11 +313,13 @@
 //! sign := '+' | '-'
 //! width := count
 //! precision := count | '*'
-//! type := '' | '?' | 'x?' | 'X?' | identifier
+//! type := '' | '?' | 'x?' | 'X?' | identifier | custom
+//! custom := '%' text
 //! count := parameter | integer
 //! parameter := argument '$'
 //! ```
-//! In the above grammar, `text` must not contain any `'{'` or `'}'` characters.
+//! In the above grammar, `text` must not contain any `'{'` or `'}'` characters,
+//! except that the `text` of a `custom` type may contain `'{'`.
 //!
 //! # Formatting traits
 //!
@@ -337,6 +339,7 @@
 //! * `b` ⇒ [`Binary`]
 //! * `e` ⇒ [`LowerExp`]
 //! * `E` ⇒ [`UpperExp`]
+//! * `%...` ⇒ [`CustomFormat`] (unstable), which receives the spec verbatim
 //!
 //! What this means is that any type of argument which implements the
 //! [`fmt::Binary`][`Binary`] trait can then be formatted with `{:b}`. Implementations
@@ -548,6 +551,8 @@
 pub use core::fmt::{write, ArgumentV1, Arguments};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{Binary, Octal};
+#[unstable(feature = "fmt_custom_spec", issue = "none")]
+pub use core::fmt::{CustomFormat, CustomSpec};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{Debug, Display};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -345,6 +345,15 @@ pub fn new<'b, T>(x: &'b T, f: fn(&T, &mut Formatter<'_>) -> Result) -> Argument
     arg_new!(new_lower_exp, LowerExp);
     arg_new!(new_upper_exp, UpperExp);
 
+    #[doc(hidden)]
+    #[unstable(feature = "fmt_internals", reason = "internal to format_args!", issue = "none")]
+    #[inline]
+    #[rustc_diagnostic_item = "fmt_argument_new_custom"]
+    pub fn new_custom<'b, T: CustomFormat<S>, S: CustomSpec>(x: &'b T, _spec: S) -> ArgumentV1<'b> {
+        let () = CheckSpec::<T, S>::OK;
+        Self::new(x, <T as CustomFormat<S>>::fmt)
+    }
+
     #[doc(hidden)]
     #[unstable(feature = "fmt_internals", reason = "internal to format_args!", issue = "none")]
     pub fn from_usize(x: &usize) -> ArgumentV1<'_> {
@@ -1145,6 +1154,128 @@ pub trait UpperExp {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result;
 }
 
+/// A custom format spec, as written in a `{:%...}` placeholder.
+///
+/// `format_args!` implements this trait on a type it generates for every
+/// placeholder whose spec starts with `%`. [`SPEC`] holds the spec verbatim,
+/// from the `%` up to the closing brace. Fill, alignment, width and precision
+/// may still precede it and are available through the [`Formatter`] as usual.
+///
+/// [`SPEC`]: CustomSpec::SPEC
+#[unstable(feature = "fmt_custom_spec", issue = "none")]
+pub trait CustomSpec {
+    /// The spec string, including the leading `%`.
+    #[unstable(feature = "fmt_custom_spec", issue = "none")]
+    const SPEC: &'static str;
+}
+
+/// `%...` format.
+///
+/// `CustomFormat` lets a type accept its own spec language after a `%`, such
+/// as `{:%Y-%m-%d}` for a timestamp. The spec is given by the [`CustomSpec`]
+/// parameter `S`, so implementations are usually generic over it.
+///
+/// The spec is checked when the program is compiled: [`CHECK_SPEC`] is
+/// evaluated for every placeholder the type is formatted with, and an `Err`
+/// is reported at the placeholder as a compilation error carrying its message.
+/// If the type of the argument depends on generic parameters, the check is
+/// deferred until the surrounding function is instantiated. `fmt` can thus
+/// assume `S::SPEC` is valid.
+///
+/// [`CHECK_SPEC`]: CustomFormat::CHECK_SPEC
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_custom_spec)]
+/// use std::fmt::{self, CustomFormat, CustomSpec};
+///
+/// struct Date {
+///     year: u16,
+///     month: u8,
+///     day: u8,
+/// }
+///
+/// const fn check(spec: &str) -> Result<(), &'static str> {
+///     let spec = spec.as_bytes();
+///     let mut i = 0;
+///     while i < spec.len() {
+///         if spec[i] == b'%' {
+///             if i + 1 == spec.len() {
+///                 return Err("incomplete conversion at the end of the spec");
+///             }
+///             match spec[i + 1] {
+///                 b'Y' | b'm' | b'd' | b'%' => {}
+///                 _ => return Err("unknown conversion, expected one of `%Y`, `%m`, `%d`"),
+///             }
+///             i += 1;
+///         }
+///         i += 1;
+///     }
+///     Ok(())
+/// }
+///
+/// impl<S: CustomSpec> CustomFormat<S> for Date {
+///     const CHECK_SPEC: Result<(), &'static str> = check(S::SPEC);
+///
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         let mut chars = S::SPEC.chars();
+///         while let Some(c) = chars.next() {
+///             match c {
+///                 '%' => match chars.next() {
+///                     Some('Y') => write!(f, "{:04}", self.year)?,
+///                     Some('m') => write!(f, "{:02}", self.month)?,
+///                     Some('d') => write!(f, "{:02}", self.day)?,
+///                     _ => f.write_str("%")?,
+///                 },
+///                 c => write!(f, "{c}")?,
+///             }
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let date = Date { year: 2022, month: 3, day: 9 };
+/// assert_eq!(format!("{date:%Y-%m-%d}"), "2022-03-09");
+/// assert_eq!(format!("{date:%d/%m}"), "09/03");
+/// ```
+///
+/// A spec the type rejects, such as `{date:%H}` above, fails to compile.
+#[unstable(feature = "fmt_custom_spec", issue = "none")]
+pub trait CustomFormat<S: CustomSpec> {
+    /// Checks whether `S::SPEC` is a valid spec for this type.
+    ///
+    /// This is evaluated at compile time, and an `Err` aborts compilation
+    /// with the given message.
+    #[unstable(feature = "fmt_custom_spec", issue = "none")]
+    const CHECK_SPEC: result::Result<(), &'static str>;
+
+    /// Formats the value using the given formatter and the spec `S::SPEC`.
+    #[unstable(feature = "fmt_custom_spec", issue = "none")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
+// Checks `CustomFormat::CHECK_SPEC` for every `{:%...}` placeholder.
+//
+// rustc evaluates `ERROR` for every call to `ArgumentV1::new_custom` whose
+// types are known before monomorphization, and reports a non-empty message
+// at the placeholder. `OK` is still forced by `new_custom` itself, so that
+// placeholders in generic code are checked once they are instantiated.
+struct CheckSpec<T, S>(PhantomData<(fn(&T), S)>);
+
+impl<T: CustomFormat<S>, S: CustomSpec> CheckSpec<T, S> {
+    #[rustc_diagnostic_item = "fmt_custom_spec_error"]
+    const ERROR: &'static str = match T::CHECK_SPEC {
+        Ok(()) => "",
+        Err(msg) => msg,
+    };
+
+    const OK: () = match T::CHECK_SPEC {
+        Ok(()) => (),
+        Err(msg) => panic!("{}", msg),
+    };
+}
+
 /// The `write` function takes an output stream, and an `Arguments` struct
 /// that can be precompiled with the `format_args!` macro.
 ///
@@ -1344,7 +1475,21 @@ fn wrap_buf<'b, 'c, F>(&'b mut self, wrap: F) -> Formatter<'c>
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn pad_integral(&mut self, is_nonnegative: bool, prefix: &str, buf: &str) -> Result {
-        let mut width = buf.len();
+        self.pad_integral_with(is_nonnegative, prefix, buf.len(), |f| f.buf.write_str(buf))
+    }
+
+    /// Like [`pad_integral`](Self::pad_integral), but for numbers whose digits
+    /// don't fit in a buffer on the stack: instead of taking the digits as a
+    /// string, this takes their count, `len`, and a closure that writes them
+    /// once the padding, sign and prefix before them have been emitted.
+    pub(crate) fn pad_integral_with(
+        &mut self,
+        is_nonnegative: bool,
+        prefix: &str,
+        len: usize,
+        write_digits: impl FnOnce(&mut Self) -> Result,
+    ) -> Result {
+        let mut width = len;
 
         let mut sign = None;
         if !is_nonnegative {
@@ -1377,13 +1522,13 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
             // write the bytes.
             None => {
                 write_prefix(self, sign, prefix)?;
-                self.buf.write_str(buf)
+                write_digits(self)
             }
             // Check if we're over the minimum width, if so then we can also
             // just write the bytes.
             Some(min) if width >= min => {
                 write_prefix(self, sign, prefix)?;
-                self.buf.write_str(buf)
+                write_digits(self)
             }
             // The sign and prefix goes before the padding if the fill character
             // is zero
@@ -1392,7 +1537,7 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
                 let old_align = crate::mem::replace(&mut self.align, rt::v1::Alignment::Right);
                 write_prefix(self, sign, prefix)?;
                 let post_padding = self.padding(min - width, rt::v1::Alignment::Right)?;
-                self.buf.write_str(buf)?;
+                write_digits(self)?;
                 post_padding.write(self)?;
                 self.fill = old_fill;
                 self.align = old_align;
@@ -1402,7 +1547,7 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
             Some(min) => {
                 let post_padding = self.padding(min - width, rt::v1::Alignment::Right)?;
                 write_prefix(self, sign, prefix)?;
-                self.buf.write_str(buf)?;
+                write_digits(self)?;
                 post_padding.write(self)
             }
         }
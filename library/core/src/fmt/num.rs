@@ -2,6 +2,7 @@
 
 use crate::fmt;
 use crate::mem::MaybeUninit;
+use crate::num::Uint;
 use crate::num::fmt as numfmt;
 use crate::ops::{Div, Rem, Sub};
 use crate::ptr;
@@ -681,3 +682,133 @@ fn u128_mulhi(x: u128, y: u128) -> u128 {
 
     x_hi as u128 * y_hi as u128 + high1 + high2
 }
+
+/// Writes the digits of `n` in a power-of-two radix, most significant first,
+/// where each digit is `bits` wide and rendered with `R::digit`.
+fn fmt_uint_pow2<R: GenericRadix, const LIMBS: usize>(
+    n: &Uint<LIMBS>,
+    bits: u32,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let limbs = n.as_limbs();
+    let significant = Uint::<LIMBS>::BITS - n.leading_zeros();
+    let len = crate::cmp::max(1, (significant + bits - 1) / bits);
+    // Extracts the digit starting at bit `pos`, which may straddle two limbs.
+    let digit = |pos: u32| {
+        let (limb, shift) = ((pos / u64::BITS) as usize, pos % u64::BITS);
+        // `Uint<0>` has no limbs at all, and is written as a single `0`.
+        let mut d = limbs.get(limb).map_or(0, |&l| l >> shift);
+        if shift + bits > u64::BITS && limb + 1 < LIMBS {
+            d |= limbs[limb + 1] << (u64::BITS - shift);
+        }
+        R::digit((d & ((1 << bits) - 1)) as u8)
+    };
+    f.pad_integral_with(true, R::PREFIX, len as usize, |f| {
+        // Digits are written in batches to avoid a call per character.
+        let mut buf = [0u8; 64];
+        let mut i = len;
+        while i > 0 {
+            let mut filled = 0;
+            while filled < buf.len() && i > 0 {
+                i -= 1;
+                buf[filled] = digit(i * bits);
+                filled += 1;
+            }
+            // SAFETY: `GenericRadix::digit` only returns ASCII digits and letters.
+            f.write_str(unsafe { str::from_utf8_unchecked(&buf[..filled]) })?;
+        }
+        Ok(())
+    })
+}
+
+/// Writes `n` in decimal, most significant digit first, splitting it into
+/// chunks of 19 digits with `Uint::div_rem_u64`.
+fn fmt_uint_dec<const LIMBS: usize>(n: &Uint<LIMBS>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    const CHUNK: u64 = 1e19 as u64;
+
+    fn write_chunks<const LIMBS: usize>(
+        n: Uint<LIMBS>,
+        f: &mut fmt::Formatter<'_>,
+        buf: &mut [u8; 20],
+    ) -> fmt::Result {
+        let (quot, rem) = n.div_rem_u64(CHUNK);
+        if quot == Uint::MIN {
+            return write_u64(rem, false, f, buf);
+        }
+        write_chunks(quot, f, buf)?;
+        write_u64(rem, true, f, buf)
+    }
+
+    fn write_u64(
+        mut n: u64,
+        zero_pad: bool,
+        f: &mut fmt::Formatter<'_>,
+        buf: &mut [u8; 20],
+    ) -> fmt::Result {
+        let mut curr = buf.len();
+        loop {
+            curr -= 1;
+            buf[curr] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 && (!zero_pad || curr == buf.len() - 19) {
+                break;
+            }
+        }
+        // SAFETY: only ASCII digits were written to `buf[curr..]`.
+        f.write_str(unsafe { str::from_utf8_unchecked(&buf[curr..]) })
+    }
+
+    // Count the digits first, so that the padding can be written before them.
+    let mut len = 1;
+    let mut rest = *n;
+    loop {
+        let (quot, rem) = rest.div_rem_u64(CHUNK);
+        if quot == Uint::MIN {
+            len += rem.checked_log10().unwrap_or(0) as usize;
+            break;
+        }
+        len += 19;
+        rest = quot;
+    }
+
+    f.pad_integral_with(true, "", len, |f| write_chunks(*n, f, &mut [0; 20]))
+}
+
+macro_rules! uint_fmt_pow2 {
+    ($($Trait:ident $Radix:ident $bits:literal;)*) => {$(
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> fmt::$Trait for Uint<LIMBS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_uint_pow2::<$Radix, LIMBS>(self, $bits, f)
+            }
+        }
+    )*};
+}
+
+uint_fmt_pow2! {
+    Binary Binary 1;
+    Octal Octal 3;
+    LowerHex LowerHex 4;
+    UpperHex UpperHex 4;
+}
+
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+impl<const LIMBS: usize> fmt::Display for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_uint_dec(self, f)
+    }
+}
+
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+impl<const LIMBS: usize> fmt::Debug for Uint<LIMBS> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.debug_lower_hex() {
+            fmt::LowerHex::fmt(self, f)
+        } else if f.debug_upper_hex() {
+            fmt::UpperHex::fmt(self, f)
+        } else {
+            fmt::Display::fmt(self, f)
+        }
+    }
+}
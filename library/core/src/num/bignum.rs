@@ -9,8 +9,8 @@
 //! inputs, but we don't do so to avoid the code bloat. Each bignum is still
 //! tracked for the actual usages, so it normally doesn't matter.
 
-// This module is only for dec2flt and flt2dec, and only public because of coretests.
-// It is not intended to ever be stabilized.
+// This module is only for dec2flt, flt2dec and the limb arithmetic of `Uint`, and only public
+// because of coretests. It is not intended to ever be stabilized.
 #![doc(hidden)]
 #![unstable(
     feature = "core_private_bignum",
@@ -34,7 +34,7 @@ fn full_div_rem(self, other: Self, borrow: Self)
 macro_rules! impl_full_ops {
     ($($ty:ty: add($addfn:path), mul/div($bigty:ident);)*) => (
         $(
-            impl FullOps for $ty {
+            impl const FullOps for $ty {
                 fn full_mul_add(self, other: $ty, other2: $ty, carry: $ty) -> ($ty, $ty) {
                     // This cannot overflow;
                     // the output is between `0` and `2^nbits * (2^nbits - 1)`.
@@ -59,8 +59,7 @@ fn full_div_rem(self, other: $ty, borrow: $ty) -> ($ty, $ty) {
     u8:  add(intrinsics::u8_add_with_overflow),  mul/div(u16);
     u16: add(intrinsics::u16_add_with_overflow), mul/div(u32);
     u32: add(intrinsics::u32_add_with_overflow), mul/div(u64);
-    // See RFC #521 for enabling this.
-    // u64: add(intrinsics::u64_add_with_overflow), mul/div(u128);
+    u64: add(intrinsics::u64_add_with_overflow), mul/div(u128);
 }
 
 /// Table of powers of 5 representable in digits. Specifically, the largest {u8, u16, u32} value
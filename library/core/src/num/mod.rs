@@ -46,10 +46,13 @@ macro_rules! unlikely {
 mod nonzero;
 #[unstable(feature = "saturating_int_impl", issue = "87920")]
 mod saturating;
+mod uint;
 mod wrapping;
 
 #[unstable(feature = "saturating_int_impl", issue = "87920")]
 pub use saturating::Saturating;
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+pub use uint::Uint;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use wrapping::Wrapping;
 
@@ -0,0 +1,1889 @@
+//! Definitions of `Uint<LIMBS>`, a fixed-width unsigned integer.
+//!
+//! The arithmetic below works limb by limb on `u64`s, using the digit
+//! operations of `bignum` for the intermediate products and quotients, and
+//! mirrors the semantics of the primitive unsigned integers defined in
+//! `uint_macros.rs`.
+
+use crate::cmp::Ordering;
+use crate::convert::TryFrom;
+use crate::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign};
+use crate::ops::{BitXor, BitXorAssign, Div, DivAssign};
+use crate::ops::{Mul, MulAssign, Not, Rem, RemAssign};
+use crate::ops::{Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+use crate::str::FromStr;
+
+use super::bignum::FullOps;
+use super::{from_str_radix, FromStrRadixHelper, ParseIntError, TryFromIntError};
+
+/// An unsigned integer of `64 * LIMBS` bits.
+///
+/// `Uint` offers the method surface of the primitive unsigned integers, such
+/// as [`u128`], for widths those don't cover: `Uint<4>` is a 256-bit integer,
+/// `Uint<8>` a 512-bit one, and so on. The value is stored inline as `LIMBS`
+/// 64-bit limbs, least significant first, so no allocation is ever needed.
+///
+/// Checked, wrapping, overflowing and saturating variants of the arithmetic
+/// operations are provided, along with bit counting, shifts and rotations,
+/// parsing with [`from_str_radix`] and [`FromStr`], and formatting with all of
+/// `{}`, `{:?}`, `{:x}`, `{:X}`, `{:o}` and `{:b}`.
+///
+/// Like those of the primitive types, the arithmetic operators of `Uint`
+/// panic on overflow if overflow checks are enabled in the crate using them,
+/// as in debug builds, and wrap otherwise. Use the explicit methods to opt
+/// into a specific behavior.
+///
+/// [`from_str_radix`]: Uint::from_str_radix
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fixed_width_uint)]
+/// use std::num::Uint;
+///
+/// type U256 = Uint<4>;
+///
+/// let a = U256::from_limbs([u64::MAX, u64::MAX, 0, 0]);
+/// let b = U256::try_from(1u8).unwrap();
+/// assert_eq!(a + b, U256::from_limbs([0, 0, 1, 0]));
+/// assert_eq!((a + b).to_string(), "340282366920938463463374607431768211456");
+///
+/// assert_eq!(U256::MAX.checked_add(b), None);
+/// assert_eq!(U256::MAX.wrapping_add(b), U256::MIN);
+/// assert_eq!(U256::BITS, 256);
+/// ```
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Uint<const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// The smallest value that can be represented by this integer type, zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::<2>::MIN, Uint::from_limbs([0, 0]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    pub const MIN: Self = Self { limbs: [0; LIMBS] };
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>64 × LIMBS</sup> &minus; 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::<2>::MAX, Uint::try_from(u128::MAX).unwrap());
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    pub const MAX: Self = Self { limbs: [u64::MAX; LIMBS] };
+
+    /// The size of this integer type in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::<4>::BITS, 256);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    pub const BITS: u32 = LIMBS as u32 * u64::BITS;
+
+    const ONE: Self = {
+        let mut limbs = [0; LIMBS];
+        if LIMBS > 0 {
+            limbs[0] = 1;
+        }
+        Self { limbs }
+    };
+
+    /// Creates an integer from its limbs, least significant first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::from_limbs([1, 2]);
+    /// assert_eq!(n, Uint::try_from(2u128 << 64 | 1).unwrap());
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self { limbs }
+    }
+
+    /// Returns the limbs of this integer, least significant first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::try_from(2u128 << 64 | 1).unwrap();
+    /// assert_eq!(n.to_limbs(), [1, 2]);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn to_limbs(self) -> [u64; LIMBS] {
+        self.limbs
+    }
+
+    /// Returns a reference to the limbs of this integer, least significant
+    /// first.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_limbs(&self) -> &[u64; LIMBS] {
+        &self.limbs
+    }
+
+    /// Converts a string slice in a given base to an integer.
+    ///
+    /// This behaves like [`u128::from_str_radix`]: the string is expected to
+    /// be an optional `+` sign followed by digits, and an error is returned
+    /// if the value doesn't fit.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<4>::from_str_radix("ffffffffffffffffffffffffffffffffff", 16).unwrap();
+    /// assert_eq!(n, Uint::from_limbs([u64::MAX, u64::MAX, 0xff, 0]));
+    /// assert!(Uint::<1>::from_str_radix("10000000000000000", 16).is_err());
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        from_str_radix(src, radix)
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([0b1011, 1]).count_ones(), 4);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[doc(alias = "popcount")]
+    #[doc(alias = "popcnt")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn count_ones(self) -> u32 {
+        let mut n = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            n += self.limbs[i].count_ones();
+            i += 1;
+        }
+        n
+    }
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::<2>::MIN.count_zeros(), 128);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn count_zeros(self) -> u32 {
+        Self::BITS - self.count_ones()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([u64::MAX, 1]).leading_zeros(), 63);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn leading_zeros(self) -> u32 {
+        let mut n = 0;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if self.limbs[i] != 0 {
+                return n + self.limbs[i].leading_zeros();
+            }
+            n += u64::BITS;
+        }
+        n
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([0, 0b1000]).trailing_zeros(), 67);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn trailing_zeros(self) -> u32 {
+        let mut n = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            if self.limbs[i] != 0 {
+                return n + self.limbs[i].trailing_zeros();
+            }
+            n += u64::BITS;
+            i += 1;
+        }
+        n
+    }
+
+    /// Returns the number of leading ones in the binary representation of
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([0, u64::MAX]).leading_ones(), 64);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn leading_ones(self) -> u32 {
+        self.bit_not().leading_zeros()
+    }
+
+    /// Returns the number of trailing ones in the binary representation of
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([u64::MAX, 0b11]).trailing_ones(), 66);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn trailing_ones(self) -> u32 {
+        self.bit_not().trailing_zeros()
+    }
+
+    /// Shifts the bits to the left by a specified amount, `n`, wrapping the
+    /// truncated bits to the end of the resulting integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::from_limbs([0x1234, 0xab << 56]);
+    /// assert_eq!(n.rotate_left(8), Uint::from_limbs([0x1234_ab, 0]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn rotate_left(self, n: u32) -> Self {
+        if Self::BITS == 0 || n % Self::BITS == 0 {
+            return self;
+        }
+        let n = n % Self::BITS;
+        self.shl_unchecked(n).bit_or(self.shr_unchecked(Self::BITS - n))
+    }
+
+    /// Shifts the bits to the right by a specified amount, `n`, wrapping the
+    /// truncated bits to the beginning of the resulting integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::from_limbs([0x1234_ab, 0]);
+    /// assert_eq!(n.rotate_right(8), Uint::from_limbs([0x1234, 0xab << 56]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn rotate_right(self, n: u32) -> Self {
+        if Self::BITS == 0 || n % Self::BITS == 0 {
+            return self;
+        }
+        self.rotate_left(Self::BITS - n % Self::BITS)
+    }
+
+    /// Reverses the byte order of the integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::from_limbs([0x12, 0x34]);
+    /// assert_eq!(n.swap_bytes(), Uint::from_limbs([0x34 << 56, 0x12 << 56]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn swap_bytes(self) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = self.limbs[LIMBS - 1 - i].swap_bytes();
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    /// Reverses the order of bits in the integer. The least significant bit
+    /// becomes the most significant bit, second least-significant bit becomes
+    /// second most-significant bit, etc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::from_limbs([1, 0]);
+    /// assert_eq!(n.reverse_bits(), Uint::from_limbs([0, 1 << 63]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn reverse_bits(self) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = self.limbs[LIMBS - 1 - i].reverse_bits();
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if
+    /// overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!((Uint::MAX - one).checked_add(one), Some(Uint::MAX));
+    /// assert_eq!(Uint::MAX.checked_add(one), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_add(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None`
+    /// if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(one.checked_sub(one), Some(Uint::MIN));
+    /// assert_eq!(Uint::MIN.checked_sub(one), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_sub(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer multiplication. Computes `self * rhs`, returning
+    /// `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let two = Uint::<2>::from_limbs([2, 0]);
+    /// assert_eq!(two.checked_mul(two), Some(Uint::from_limbs([4, 0])));
+    /// assert_eq!(Uint::MAX.checked_mul(two), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_mul(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if
+    /// `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::from_limbs([0, 128]);
+    /// assert_eq!(n.checked_div(Uint::from_limbs([2, 0])), Some(Uint::from_limbs([0, 64])));
+    /// assert_eq!(n.checked_div(Uint::MIN), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self.div_rem(rhs).0) }
+    }
+
+    /// Checked Euclidean division. Computes `self.div_euclid(rhs)`, returning
+    /// `None` if `rhs == 0`.
+    ///
+    /// For unsigned types, this is the same as [`checked_div`](Self::checked_div).
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if
+    /// `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::from_limbs([5, 1]);
+    /// assert_eq!(n.checked_rem(Uint::from_limbs([2, 0])), Some(Uint::from_limbs([1, 0])));
+    /// assert_eq!(n.checked_rem(Uint::MIN), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self.div_rem(rhs).1) }
+    }
+
+    /// Checked Euclidean modulo. Computes `self.rem_euclid(rhs)`, returning
+    /// `None` if `rhs == 0`.
+    ///
+    /// For unsigned types, this is the same as [`checked_rem`](Self::checked_rem).
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        self.checked_rem(rhs)
+    }
+
+    /// Returns the logarithm of the number with respect to base 2.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([0, 2]).log2(), 65);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn log2(self) -> u32 {
+        match self.checked_log2() {
+            Some(n) => n,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    /// Returns the logarithm of the number with respect to base 2, or `None`
+    /// if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([0, 2]).checked_log2(), Some(65));
+    /// assert_eq!(Uint::<2>::MIN.checked_log2(), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_log2(self) -> Option<u32> {
+        if self.is_zero() { None } else { Some(Self::BITS - 1 - self.leading_zeros()) }
+    }
+
+    /// Checked negation. Computes `-self`, returning `None` unless `self ==
+    /// 0`.
+    ///
+    /// Note that negating any positive integer will overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::<2>::MIN.checked_neg(), Some(Uint::MIN));
+    /// assert_eq!(Uint::<2>::MAX.checked_neg(), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        let (a, b) = self.overflowing_neg();
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked shift left. Computes `self << rhs`, returning `None` if `rhs`
+    /// is larger than or equal to the number of bits in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(one.checked_shl(68), Some(Uint::from_limbs([0, 0x10])));
+    /// assert_eq!(one.checked_shl(128), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        let (a, b) = self.overflowing_shl(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked shift right. Computes `self >> rhs`, returning `None` if `rhs`
+    /// is larger than or equal to the number of bits in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::from_limbs([0, 0x10]);
+    /// assert_eq!(n.checked_shr(68), Some(Uint::from_limbs([1, 0])));
+    /// assert_eq!(n.checked_shr(129), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        let (a, b) = self.overflowing_shr(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if
+    /// overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let two = Uint::<2>::from_limbs([2, 0]);
+    /// assert_eq!(two.checked_pow(127), Some(Uint::from_limbs([0, 1 << 63])));
+    /// assert_eq!(two.checked_pow(128), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        let (a, b) = self.overflowing_pow(exp);
+        if b { None } else { Some(a) }
+    }
+
+    /// Saturating integer addition. Computes `self + rhs`, saturating at the
+    /// numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(one.saturating_add(one), Uint::from_limbs([2, 0]));
+    /// assert_eq!(Uint::MAX.saturating_add(one), Uint::MAX);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(x) => x,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating integer subtraction. Computes `self - rhs`, saturating at
+    /// the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(Uint::MIN.saturating_sub(one), Uint::MIN);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(x) => x,
+            None => Self::MIN,
+        }
+    }
+
+    /// Saturating integer multiplication. Computes `self * rhs`, saturating
+    /// at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let two = Uint::<2>::from_limbs([2, 0]);
+    /// assert_eq!(Uint::MAX.saturating_mul(two), Uint::MAX);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(x) => x,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating integer division. Computes `self / rhs`, saturating at the
+    /// numeric bounds instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        // on unsigned types, there is no overflow in integer division
+        self.wrapping_div(rhs)
+    }
+
+    /// Saturating integer exponentiation. Computes `self.pow(exp)`,
+    /// saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let two = Uint::<2>::from_limbs([2, 0]);
+    /// assert_eq!(two.saturating_pow(128), Uint::MAX);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(x) => x,
+            None => Self::MAX,
+        }
+    }
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at
+    /// the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(Uint::MAX.wrapping_add(one), Uint::MIN);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around
+    /// at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(Uint::MIN.wrapping_sub(one), Uint::MAX);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping
+    /// around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::from_limbs([0, 3]);
+    /// assert_eq!(n.wrapping_mul(Uint::from_limbs([0, 1])), Uint::MIN);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        self.widening_mul(rhs).0
+    }
+
+    /// Wrapping (modular) division. Computes `self / rhs`.
+    ///
+    /// Wrapped division on unsigned types is just normal division. There's no
+    /// way wrapping could ever happen. This function exists, so that all
+    /// operations are accounted for in the wrapping operations.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        match self.checked_div(rhs) {
+            Some(x) => x,
+            None => panic!("attempt to divide by zero"),
+        }
+    }
+
+    /// Wrapping Euclidean division. Computes `self.div_euclid(rhs)`.
+    ///
+    /// For unsigned types, this is the same as [`wrapping_div`](Self::wrapping_div).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_div_euclid(self, rhs: Self) -> Self {
+        self.wrapping_div(rhs)
+    }
+
+    /// Wrapping (modular) remainder. Computes `self % rhs`.
+    ///
+    /// Wrapped remainder calculation on unsigned types is just the regular
+    /// remainder calculation. There's no way wrapping could ever happen.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        match self.checked_rem(rhs) {
+            Some(x) => x,
+            None => panic!("attempt to calculate the remainder with a divisor of zero"),
+        }
+    }
+
+    /// Wrapping Euclidean modulo. Computes `self.rem_euclid(rhs)`.
+    ///
+    /// For unsigned types, this is the same as [`wrapping_rem`](Self::wrapping_rem).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_rem_euclid(self, rhs: Self) -> Self {
+        self.wrapping_rem(rhs)
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the
+    /// boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(one.wrapping_neg(), Uint::MAX);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    /// Panic-free bitwise shift-left; yields `self << mask(rhs)`, where
+    /// `mask` removes any high-order bits of `rhs` that would cause the shift
+    /// to exceed the bitwidth of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(one.wrapping_shl(129), Uint::from_limbs([2, 0]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        self.overflowing_shl(rhs).0
+    }
+
+    /// Panic-free bitwise shift-right; yields `self >> mask(rhs)`, where
+    /// `mask` removes any high-order bits of `rhs` that would cause the shift
+    /// to exceed the bitwidth of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::from_limbs([4, 0]);
+    /// assert_eq!(n.wrapping_shr(129), Uint::from_limbs([2, 0]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        self.overflowing_shr(rhs).0
+    }
+
+    /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping
+    /// around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let two = Uint::<2>::from_limbs([2, 0]);
+    /// assert_eq!(two.wrapping_pow(128), Uint::MIN);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether
+    /// an arithmetic overflow would occur. If an overflow would have occurred
+    /// then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(Uint::MAX.overflowing_add(one), (Uint::MIN, true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        self.carrying_add(rhs, false)
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating
+    /// whether an arithmetic overflow would occur. If an overflow would have
+    /// occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(Uint::MIN.overflowing_sub(one), (Uint::MAX, true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.borrowing_sub(rhs, false)
+    }
+
+    /// Calculates the multiplication of `self` and `rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating
+    /// whether an arithmetic overflow would occur. If an overflow would have
+    /// occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::from_limbs([0, 3]);
+    /// assert_eq!(n.overflowing_mul(Uint::from_limbs([0, 1])), (Uint::MIN, true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (lo, hi) = self.widening_mul(rhs);
+        (lo, !hi.is_zero())
+    }
+
+    /// Calculates the divisor when `self` is divided by `rhs`.
+    ///
+    /// Returns a tuple of the divisor along with a boolean indicating whether
+    /// an arithmetic overflow would occur. Note that for unsigned integers
+    /// overflow never occurs, so the second value is always `false`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_div(rhs), false)
+    }
+
+    /// Calculates the quotient of Euclidean division `self.div_euclid(rhs)`.
+    ///
+    /// For unsigned types, this is the same as
+    /// [`overflowing_div`](Self::overflowing_div).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) {
+        self.overflowing_div(rhs)
+    }
+
+    /// Calculates the remainder when `self` is divided by `rhs`.
+    ///
+    /// Returns a tuple of the remainder after dividing along with a boolean
+    /// indicating whether an arithmetic overflow would occur. Note that for
+    /// unsigned integers overflow never occurs, so the second value is always
+    /// `false`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_rem(rhs), false)
+    }
+
+    /// Calculates the remainder `self.rem_euclid(rhs)` as if by Euclidean
+    /// division.
+    ///
+    /// For unsigned types, this is the same as
+    /// [`overflowing_rem`](Self::overflowing_rem).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_rem_euclid(self, rhs: Self) -> (Self, bool) {
+        self.overflowing_rem(rhs)
+    }
+
+    /// Negates self in a wrapping fashion.
+    ///
+    /// Returns `!self + 1` using wrapping operations to return the value that
+    /// represents the negation of this unsigned value. Note that for positive
+    /// unsigned values overflow always occurs, but negating 0 does not
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(Uint::<2>::MIN.overflowing_neg(), (Uint::MIN, false));
+    /// assert_eq!(one.overflowing_neg(), (Uint::MAX, true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        (Self::MIN.wrapping_sub(self), !self.is_zero())
+    }
+
+    /// Shifts self left by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of self along with a boolean
+    /// indicating whether the shift value was larger than or equal to the
+    /// number of bits. If the shift value is too large, then value is masked
+    /// (N-1) where N is the number of bits, and this value is then used to
+    /// perform the shift.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let one = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(one.overflowing_shl(64), (Uint::from_limbs([0, 1]), false));
+    /// assert_eq!(one.overflowing_shl(129), (Uint::from_limbs([2, 0]), true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        if Self::BITS == 0 {
+            return (self, true);
+        }
+        (self.shl_unchecked(rhs % Self::BITS), rhs >= Self::BITS)
+    }
+
+    /// Shifts self right by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of self along with a boolean
+    /// indicating whether the shift value was larger than or equal to the
+    /// number of bits. If the shift value is too large, then value is masked
+    /// (N-1) where N is the number of bits, and this value is then used to
+    /// perform the shift.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let n = Uint::<2>::from_limbs([0, 1]);
+    /// assert_eq!(n.overflowing_shr(64), (Uint::from_limbs([1, 0]), false));
+    /// assert_eq!(n.overflowing_shr(129), (Uint::from_limbs([1 << 63, 0]), true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        if Self::BITS == 0 {
+            return (self, true);
+        }
+        (self.shr_unchecked(rhs % Self::BITS), rhs >= Self::BITS)
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// Returns a tuple of the exponentiation along with a bool indicating
+    /// whether an overflow happened.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let three = Uint::<1>::from_limbs([3]);
+    /// assert_eq!(three.overflowing_pow(40), (Uint::from_limbs([3u64.pow(40)]), false));
+    /// assert_eq!(three.overflowing_pow(41), (Uint::from_limbs([3u64.wrapping_pow(41)]), true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        if exp == 0 {
+            return (Self::ONE, false);
+        }
+        let mut base = self;
+        let mut acc = Self::ONE;
+        let mut overflown = false;
+        // Scratch space for storing results of overflowing_mul.
+        let mut r;
+
+        while exp > 1 {
+            if (exp & 1) == 1 {
+                r = acc.overflowing_mul(base);
+                acc = r.0;
+                overflown |= r.1;
+            }
+            exp /= 2;
+            r = base.overflowing_mul(base);
+            base = r.0;
+            overflown |= r.1;
+        }
+
+        // since exp!=0, finally the exp must be 1.
+        // Deal with the final bit of the exponent separately, since
+        // squaring the base afterwards is not necessary.
+        r = acc.overflowing_mul(base);
+        r.1 |= overflown;
+
+        r
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let ten = Uint::<2>::from_limbs([10, 0]);
+    /// assert_eq!(ten.pow(30).to_string(), "1000000000000000000000000000000");
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(x) => x,
+            None => panic!("attempt to multiply with overflow"),
+        }
+    }
+
+    /// Performs Euclidean division.
+    ///
+    /// Since, for the positive integers, all common definitions of division
+    /// are equal, this is exactly equal to `self / rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn div_euclid(self, rhs: Self) -> Self {
+        self.wrapping_div(rhs)
+    }
+
+    /// Calculates the least remainder of `self (mod rhs)`.
+    ///
+    /// Since, for the positive integers, all common definitions of division
+    /// are equal, this is exactly equal to `self % rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is 0.
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn rem_euclid(self, rhs: Self) -> Self {
+        self.wrapping_rem(rhs)
+    }
+
+    /// Computes the absolute difference between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let a = Uint::<2>::from_limbs([0, 1]);
+    /// let b = Uint::<2>::from_limbs([1, 0]);
+    /// assert_eq!(a.abs_diff(b), Uint::from_limbs([u64::MAX, 0]));
+    /// assert_eq!(b.abs_diff(a), Uint::from_limbs([u64::MAX, 0]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn abs_diff(self, other: Self) -> Self {
+        match self.overflowing_sub(other) {
+            (diff, false) => diff,
+            (_, true) => other.wrapping_sub(self),
+        }
+    }
+
+    /// Returns `true` if and only if `self == 2^k` for some `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert!(Uint::from_limbs([0, 16]).is_power_of_two());
+    /// assert!(!Uint::from_limbs([1, 16]).is_power_of_two());
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_power_of_two(self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result does not fit in the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs([3, 1]).next_power_of_two(), Uint::from_limbs([0, 2]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn next_power_of_two(self) -> Self {
+        match self.checked_next_power_of_two() {
+            Some(x) => x,
+            None => panic!("attempt to add with overflow"),
+        }
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`. If
+    /// the next power of two is greater than the type's maximum value,
+    /// `None` is returned, otherwise the power of two is wrapped in `Some`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// assert_eq!(Uint::<2>::MIN.checked_next_power_of_two(), Some(Uint::from_limbs([1, 0])));
+    /// assert_eq!(Uint::<2>::MAX.checked_next_power_of_two(), None);
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_next_power_of_two(self) -> Option<Self> {
+        if self.is_power_of_two() {
+            return Some(self);
+        }
+        if self.is_zero() {
+            return if Self::BITS == 0 { None } else { Some(Self::ONE) };
+        }
+        let bits = Self::BITS - self.leading_zeros();
+        if bits == Self::BITS { None } else { Some(Self::ONE.shl_unchecked(bits)) }
+    }
+
+    /// Calculates `self + rhs + carry` without the ability to overflow.
+    ///
+    /// Performs "ternary addition" which takes in an extra bit to add, and
+    /// may return an additional bit of overflow. This allows for chaining
+    /// together multiple additions to create wider integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let (sum, carry) = Uint::<2>::MAX.carrying_add(Uint::MIN, true);
+    /// assert_eq!((sum, carry), (Uint::MIN, true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn carrying_add(self, rhs: Self, mut carry: bool) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let (a, b) = self.limbs[i].overflowing_add(rhs.limbs[i]);
+            let (c, d) = a.overflowing_add(carry as u64);
+            limbs[i] = c;
+            carry = b | d;
+            i += 1;
+        }
+        (Self { limbs }, carry)
+    }
+
+    /// Calculates `self - rhs - borrow` without the ability to overflow.
+    ///
+    /// Performs "ternary subtraction" which takes in an extra bit to
+    /// subtract, and may return an additional bit of overflow. This allows
+    /// for chaining together multiple subtractions to create wider integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let (diff, borrow) = Uint::<2>::MIN.borrowing_sub(Uint::MIN, true);
+    /// assert_eq!((diff, borrow), (Uint::MAX, true));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn borrowing_sub(self, rhs: Self, mut borrow: bool) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let (a, b) = self.limbs[i].overflowing_sub(rhs.limbs[i]);
+            let (c, d) = a.overflowing_sub(borrow as u64);
+            limbs[i] = c;
+            borrow = b | d;
+            i += 1;
+        }
+        (Self { limbs }, borrow)
+    }
+
+    /// Calculates the complete product `self * rhs` without the possibility
+    /// to overflow.
+    ///
+    /// This returns the low-order (wrapping) bits and the high-order
+    /// (overflow) bits of the result as two separate values, in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fixed_width_uint)]
+    /// use std::num::Uint;
+    ///
+    /// let (lo, hi) = Uint::<2>::MAX.widening_mul(Uint::MAX);
+    /// assert_eq!(lo, Uint::from_limbs([1, 0]));
+    /// assert_eq!(hi, Uint::from_limbs([u64::MAX - 1, u64::MAX]));
+    /// ```
+    #[unstable(feature = "fixed_width_uint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        // Schoolbook multiplication into the double-width `hi:lo`, where the
+        // `k`th limb of the product lives in `lo` if `k < LIMBS` and in `hi`
+        // otherwise.
+        let mut lo = [0; LIMBS];
+        let mut hi = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let a = self.limbs[i];
+            let mut carry = 0;
+            if a != 0 {
+                let mut j = 0;
+                while j < LIMBS {
+                    let k = i + j;
+                    let cur = if k < LIMBS { lo[k] } else { hi[k - LIMBS] };
+                    let (c, v) = a.full_mul_add(rhs.limbs[j], cur, carry);
+                    if k < LIMBS {
+                        lo[k] = v;
+                    } else {
+                        hi[k - LIMBS] = v;
+                    }
+                    carry = c;
+                    j += 1;
+                }
+            }
+            // The limb at `i + LIMBS` is always in `hi`, and hasn't been
+            // written to by the previous rows.
+            hi[i] = carry;
+            i += 1;
+        }
+        (Self { limbs: lo }, Self { limbs: hi })
+    }
+
+    /// Divides `self` by a single limb, returning the quotient and the
+    /// remainder.
+    ///
+    /// `rhs` must not be zero.
+    pub(crate) const fn div_rem_u64(self, rhs: u64) -> (Self, u64) {
+        let mut limbs = [0; LIMBS];
+        let mut rem = 0u64;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            (limbs[i], rem) = self.limbs[i].full_div_rem(rhs, rem);
+        }
+        (Self { limbs }, rem)
+    }
+
+    /// Divides `self` by `rhs`, returning the quotient and the remainder.
+    ///
+    /// `rhs` must not be zero.
+    const fn div_rem(self, rhs: Self) -> (Self, Self) {
+        if self.lt(&rhs) {
+            return (Self::MIN, self);
+        }
+        if rhs.leading_zeros() >= Self::BITS - u64::BITS {
+            let (q, r) = self.div_rem_u64(rhs.limbs[0]);
+            let mut rem = Self::MIN;
+            rem.limbs[0] = r;
+            return (q, rem);
+        }
+
+        // Binary long division, one bit of the quotient at a time. The bit
+        // shifted out of `rem` is kept in `carry`: if it is set, `rem` has
+        // exceeded `rhs` and the wrapping subtraction yields the right value.
+        let mut quot = Self::MIN;
+        let mut rem = Self::MIN;
+        let mut i = Self::BITS - self.leading_zeros();
+        while i > 0 {
+            i -= 1;
+            let carry = rem.limbs[LIMBS - 1] >> 63 != 0;
+            rem = rem.shl_unchecked(1);
+            rem.limbs[0] |= (self.limbs[(i / 64) as usize] >> (i % 64)) & 1;
+            if carry || !rem.lt(&rhs) {
+                rem = rem.wrapping_sub(rhs);
+                quot.limbs[(i / 64) as usize] |= 1 << (i % 64);
+            }
+        }
+        (quot, rem)
+    }
+
+    /// Shifts left by `rhs`, which must be less than `Self::BITS`.
+    const fn shl_unchecked(self, rhs: u32) -> Self {
+        let limb_shift = (rhs / u64::BITS) as usize;
+        let bit_shift = rhs % u64::BITS;
+        let mut limbs = [0; LIMBS];
+        let mut i = LIMBS;
+        while i > limb_shift {
+            i -= 1;
+            let src = i - limb_shift;
+            limbs[i] = self.limbs[src] << bit_shift;
+            if bit_shift != 0 && src > 0 {
+                limbs[i] |= self.limbs[src - 1] >> (u64::BITS - bit_shift);
+            }
+        }
+        Self { limbs }
+    }
+
+    /// Shifts right by `rhs`, which must be less than `Self::BITS`.
+    const fn shr_unchecked(self, rhs: u32) -> Self {
+        let limb_shift = (rhs / u64::BITS) as usize;
+        let bit_shift = rhs % u64::BITS;
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i + limb_shift < LIMBS {
+            let src = i + limb_shift;
+            limbs[i] = self.limbs[src] >> bit_shift;
+            if bit_shift != 0 && src + 1 < LIMBS {
+                limbs[i] |= self.limbs[src + 1] << (u64::BITS - bit_shift);
+            }
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    const fn bit_not(self) -> Self {
+        let mut limbs = self.limbs;
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = !limbs[i];
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    const fn bit_or(self, rhs: Self) -> Self {
+        let mut limbs = self.limbs;
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] |= rhs.limbs[i];
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.limbs[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn lt(&self, rhs: &Self) -> bool {
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if self.limbs[i] != rhs.limbs[i] {
+                return self.limbs[i] < rhs.limbs[i];
+            }
+        }
+        false
+    }
+}
+
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    #[inline]
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The most significant limb comes last.
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+// The operators that can overflow compute the wrapped result, and then repeat
+// the overflow on a `u8` with the same operator. Like for the primitive types,
+// that panics if overflow checks are enabled in the crate the operator is
+// used from, and does nothing otherwise.
+macro_rules! uint_arith_impl {
+    ($($Trait:ident $method:ident $TraitAssign:ident $method_assign:ident
+       $Rhs:ty, $overflowing:ident, |$overflow:ident| $check:expr;)*) => {$(
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> $Trait<$Rhs> for Uint<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            fn $method(self, rhs: $Rhs) -> Self {
+                let (x, $overflow) = self.$overflowing(rhs);
+                let _ = $check;
+                x
+            }
+        }
+
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> $TraitAssign<$Rhs> for Uint<LIMBS> {
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            fn $method_assign(&mut self, rhs: $Rhs) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+    )*};
+}
+
+uint_arith_impl! {
+    Add add AddAssign add_assign Self, overflowing_add, |overflow| u8::MAX + overflow as u8;
+    Sub sub SubAssign sub_assign Self, overflowing_sub, |overflow| 0 - overflow as u8;
+    Mul mul MulAssign mul_assign Self, overflowing_mul, |overflow| (1 + overflow as u8) * 128;
+    Shl shl ShlAssign shl_assign u32, overflowing_shl, |overflow| 1u8 << overflow as u32 * 8;
+    Shr shr ShrAssign shr_assign u32, overflowing_shr, |overflow| 1u8 >> overflow as u32 * 8;
+}
+
+// Division by zero panics regardless of overflow checks.
+macro_rules! uint_div_impl {
+    ($($Trait:ident $method:ident $TraitAssign:ident $method_assign:ident
+       $checked:ident $msg:literal;)*) => {$(
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> $Trait for Uint<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: Self) -> Self {
+                match self.$checked(rhs) {
+                    Some(x) => x,
+                    None => panic!($msg),
+                }
+            }
+        }
+
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> $TraitAssign for Uint<LIMBS> {
+            #[inline]
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+    )*};
+}
+
+uint_div_impl! {
+    Div div DivAssign div_assign checked_div "attempt to divide by zero";
+    Rem rem RemAssign rem_assign checked_rem
+        "attempt to calculate the remainder with a divisor of zero";
+}
+
+macro_rules! uint_bit_impl {
+    ($($Trait:ident $method:ident $TraitAssign:ident $method_assign:ident $op:tt;)*) => {$(
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> $Trait for Uint<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, rhs: Self) -> Self {
+                for (a, b) in self.limbs.iter_mut().zip(rhs.limbs) {
+                    *a $op b;
+                }
+                self
+            }
+        }
+
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> $TraitAssign for Uint<LIMBS> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+    )*};
+}
+
+uint_bit_impl! {
+    BitAnd bitand BitAndAssign bitand_assign &=;
+    BitOr bitor BitOrAssign bitor_assign |=;
+    BitXor bitxor BitXorAssign bitxor_assign ^=;
+}
+
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+impl<const LIMBS: usize> Not for Uint<LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self.bit_not()
+    }
+}
+
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+impl<const LIMBS: usize> FromStr for Uint<LIMBS> {
+    type Err = ParseIntError;
+
+    fn from_str(src: &str) -> Result<Self, ParseIntError> {
+        from_str_radix(src, 10)
+    }
+}
+
+impl<const LIMBS: usize> FromStrRadixHelper for Uint<LIMBS> {
+    const MIN: Self = Self::MIN;
+    #[inline]
+    fn from_u32(u: u32) -> Self {
+        let mut n = Self::MIN;
+        if LIMBS > 0 {
+            n.limbs[0] = u as u64;
+        }
+        n
+    }
+    #[inline]
+    fn checked_mul(&self, other: u32) -> Option<Self> {
+        Self::checked_mul(*self, Self::from_u32(other))
+    }
+    #[inline]
+    fn checked_sub(&self, other: u32) -> Option<Self> {
+        Self::checked_sub(*self, Self::from_u32(other))
+    }
+    #[inline]
+    fn checked_add(&self, other: u32) -> Option<Self> {
+        Self::checked_add(*self, Self::from_u32(other))
+    }
+}
+
+macro_rules! uint_try_from_impl {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> TryFrom<$t> for Uint<LIMBS> {
+            type Error = TryFromIntError;
+
+            /// Tries to create the target integer from a primitive integer,
+            /// failing if it doesn't fit.
+            #[inline]
+            fn try_from(u: $t) -> Result<Self, TryFromIntError> {
+                let u = u as u128;
+                // Anything left over after shifting out `BITS` bits didn't fit.
+                if u.checked_shr(Self::BITS).unwrap_or(0) != 0 {
+                    return Err(TryFromIntError(()));
+                }
+                let mut n = Self::MIN;
+                for (i, limb) in n.limbs.iter_mut().take(2).enumerate() {
+                    *limb = (u >> (i as u32 * u64::BITS)) as u64;
+                }
+                Ok(n)
+            }
+        }
+
+        #[unstable(feature = "fixed_width_uint", issue = "none")]
+        impl<const LIMBS: usize> TryFrom<Uint<LIMBS>> for $t {
+            type Error = TryFromIntError;
+
+            /// Tries to create the primitive integer from the source integer,
+            /// failing if it doesn't fit.
+            #[inline]
+            fn try_from(n: Uint<LIMBS>) -> Result<Self, TryFromIntError> {
+                if n.limbs.iter().skip(2).any(|&limb| limb != 0) {
+                    return Err(TryFromIntError(()));
+                }
+                let mut u = 0u128;
+                for (i, &limb) in n.limbs.iter().take(2).enumerate() {
+                    u |= (limb as u128) << (i as u32 * u64::BITS);
+                }
+                <$t>::try_from(u).map_err(|_| TryFromIntError(()))
+            }
+        }
+    )*};
+}
+
+uint_try_from_impl! { u8 u16 u32 u64 u128 usize }
@@ -9,7 +9,7 @@
 use crate::cmp::Ordering::{self, Greater, Less};
 use crate::intrinsics::{assert_unsafe_precondition, exact_div};
 use crate::marker::Copy;
-use crate::mem;
+use crate::mem::{self, MaybeUninit};
 use crate::num::NonZeroUsize;
 use crate::ops::{Bound, FnMut, OneSidedRange, Range, RangeBounds};
 use crate::option::Option;
@@ -78,6 +78,11 @@
 #[unstable(feature = "sort_internals", reason = "internal to sort module", issue = "none")]
 pub use sort::heapsort;
 
+// This function is public only so that `alloc` can reuse it for its stable sorts.
+#[unstable(feature = "sort_internals", reason = "internal to sort module", issue = "none")]
+#[doc(hidden)]
+pub use sort::merge_sort;
+
 #[stable(feature = "slice_get_slice", since = "1.28.0")]
 pub use index::SliceIndex;
 
@@ -2582,6 +2587,10 @@ pub fn sort_unstable_by<F>(&mut self, mut compare: F)
     /// randomization to avoid degenerate cases, but with a fixed seed to always provide
     /// deterministic behavior.
     ///
+    /// If the key is a primitive integer type, long slices are instead sorted with an in-place
+    /// radix sort (also known as American flag sort), which is *O*(*m* \* *n* \* *k*) worst-case,
+    /// where *k* is the size of the key in bytes.
+    ///
     /// Due to its key calling strategy, [`sort_unstable_by_key`](#method.sort_unstable_by_key)
     /// is likely to be slower than [`sort_by_cached_key`](#method.sort_by_cached_key) in
     /// cases where the key function is expensive.
@@ -2603,7 +2612,138 @@ pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
         F: FnMut(&T) -> K,
         K: Ord,
     {
-        sort::quicksort(self, |a, b| f(a).lt(&f(b)));
+        sort::sort_unstable_by_key(self, f);
+    }
+
+    /// Sorts the slice, using `buf` as scratch memory.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*))
+    /// worst-case. It behaves like [`sort`](slice::sort), except that it never allocates: the
+    /// temporary storage is provided by the caller instead. This makes a stable sort available
+    /// where there is no global allocator, and allows reusing the same buffer across many sorts.
+    ///
+    /// The contents of `buf` are unspecified after the sort.
+    ///
+    /// # Current implementation
+    ///
+    /// The current algorithm is an adaptive, iterative merge sort inspired by
+    /// [timsort](https://en.wikipedia.org/wiki/Timsort).
+    /// It is designed to be very fast in cases where the slice is nearly sorted, or consists of
+    /// two or more sorted sequences concatenated one after another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than half the length of the slice. Short slices are sorted in
+    /// place and never use `buf`, so they can be sorted with a shorter or even an empty buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_sort_with_buffer)]
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut v = [-5, 4, 1, -3, 2];
+    /// let mut buf = [MaybeUninit::uninit(); 2];
+    ///
+    /// v.sort_with_buffer(&mut buf);
+    /// assert!(v == [-5, -3, 1, 2, 4]);
+    /// ```
+    #[unstable(feature = "slice_sort_with_buffer", issue = "none")]
+    #[inline]
+    pub fn sort_with_buffer(&mut self, buf: &mut [MaybeUninit<T>])
+    where
+        T: Ord,
+    {
+        self.sort_by_with_buffer(buf, T::cmp);
+    }
+
+    /// Sorts the slice with a comparator function, using `buf` as scratch memory.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*))
+    /// worst-case. It behaves like [`sort_by`](slice::sort_by), except that it never allocates:
+    /// the temporary storage is provided by the caller instead.
+    ///
+    /// The comparator function must define a total ordering for the elements in the slice. If
+    /// the ordering is not total, the order of the elements is unspecified.
+    ///
+    /// The contents of `buf` are unspecified after the sort.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than half the length of the slice. Short slices are sorted in
+    /// place and never use `buf`, so they can be sorted with a shorter or even an empty buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_sort_with_buffer)]
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut v = [5, 4, 1, 3, 2];
+    /// let mut buf = [MaybeUninit::uninit(); 2];
+    ///
+    /// v.sort_by_with_buffer(&mut buf, |a, b| a.cmp(b));
+    /// assert!(v == [1, 2, 3, 4, 5]);
+    ///
+    /// // reverse sorting
+    /// v.sort_by_with_buffer(&mut buf, |a, b| b.cmp(a));
+    /// assert!(v == [5, 4, 3, 2, 1]);
+    /// ```
+    #[unstable(feature = "slice_sort_with_buffer", issue = "none")]
+    #[inline]
+    pub fn sort_by_with_buffer<F>(&mut self, buf: &mut [MaybeUninit<T>], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len();
+        // The buffer is only requested if the slice is too long to be sorted in place.
+        let get_buf = |buf_len: usize| {
+            if buf.len() < buf_len {
+                panic!(
+                    "scratch buffer of length {} is too short to sort a slice of length {}",
+                    buf.len(),
+                    len
+                );
+            }
+            buf
+        };
+        sort::merge_sort(self, |a, b| compare(a, b) == Ordering::Less, get_buf);
+    }
+
+    /// Sorts the slice with a key extraction function, using `buf` as scratch memory.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* \* log(*n*))
+    /// worst-case, where the key function is *O*(*m*). It behaves like
+    /// [`sort_by_key`](slice::sort_by_key), except that it never allocates: the temporary storage
+    /// is provided by the caller instead.
+    ///
+    /// The contents of `buf` are unspecified after the sort.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than half the length of the slice. Short slices are sorted in
+    /// place and never use `buf`, so they can be sorted with a shorter or even an empty buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_sort_with_buffer)]
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    /// let mut buf = [MaybeUninit::uninit(); 2];
+    ///
+    /// v.sort_by_key_with_buffer(&mut buf, |k| k.abs());
+    /// assert!(v == [1, 2, -3, 4, -5]);
+    /// ```
+    #[unstable(feature = "slice_sort_with_buffer", issue = "none")]
+    #[inline]
+    pub fn sort_by_key_with_buffer<K, F>(&mut self, buf: &mut [MaybeUninit<T>], mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by_with_buffer(buf, |a, b| f(a).cmp(&f(b)));
     }
 
     /// Reorder the slice such that the element at `index` is at its final sorted position.
@@ -3,8 +3,12 @@
 //! This module contains a sorting algorithm based on Orson Peters' pattern-defeating quicksort,
 //! published at: <https://github.com/orlp/pdqsort>
 //!
-//! Unstable sorting is compatible with libcore because it doesn't allocate memory, unlike our
-//! stable sorting implementation.
+//! Unstable sorting is compatible with libcore because it doesn't allocate memory. Primitive
+//! integer keys are sorted with an in-place radix sort instead.
+//!
+//! It also contains the merge sort behind our stable sorting implementation. The merge sort needs
+//! scratch memory, which is provided by the caller, either allocated by `alloc` or passed in
+//! explicitly.
 
 use crate::cmp;
 use crate::mem::{self, MaybeUninit};
@@ -924,3 +928,496 @@ pub fn partition_at_index<T, F>(
     let pivot = &mut pivot[0];
     (left, pivot, right)
 }
+
+/// Merges non-decreasing runs `v[..mid]` and `v[mid..]` using `buf` as temporary storage, and
+/// stores the result into `v[..]`.
+///
+/// # Safety
+///
+/// The two slices must be non-empty and `mid` must be in bounds. Buffer `buf` must be long enough
+/// to hold a copy of the shorter slice. Also, `T` must not be a zero-sized type.
+unsafe fn merge<T, F>(v: &mut [T], mid: usize, buf: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    let v = v.as_mut_ptr();
+    // SAFETY: `mid` and `len` are in bounds of `v`.
+    let (v_mid, v_end) = unsafe { (v.add(mid), v.add(len)) };
+
+    // The merge process first copies the shorter run into `buf`. Then it traces the newly copied
+    // run and the longer run forwards (or backwards), comparing their next unconsumed elements and
+    // copying the lesser (or greater) one into `v`.
+    //
+    // As soon as the shorter run is fully consumed, the process is done. If the longer run gets
+    // consumed first, then we must copy whatever is left of the shorter run into the remaining
+    // hole in `v`.
+    //
+    // Intermediate state of the process is always tracked by `hole`, which serves two purposes:
+    // 1. Protects integrity of `v` from panics in `is_less`.
+    // 2. Fills the remaining hole in `v` if the longer run gets consumed first.
+    //
+    // Panic safety:
+    //
+    // If `is_less` panics at any point during the process, `hole` will get dropped and fill the
+    // hole in `v` with the unconsumed range in `buf`, thus ensuring that `v` still holds every
+    // object it initially held exactly once.
+    let mut hole;
+
+    if mid <= len - mid {
+        // The left run is shorter.
+        // SAFETY: The caller guarantees that `buf` can hold `mid` elements.
+        unsafe {
+            ptr::copy_nonoverlapping(v, buf, mid);
+            hole = MergeHole { start: buf, end: buf.add(mid), dest: v };
+        }
+
+        // Initially, these pointers point to the beginnings of their arrays.
+        let left = &mut hole.start;
+        let mut right = v_mid;
+        let out = &mut hole.dest;
+
+        while *left < hole.end && right < v_end {
+            // Consume the lesser side.
+            // If equal, prefer the left run to maintain stability.
+            // SAFETY: `left` and `right` point to unconsumed elements of their runs, and `out`
+            // trails them, so it always points into the hole.
+            unsafe {
+                let to_copy = if is_less(&*right, &**left) {
+                    get_and_increment(&mut right)
+                } else {
+                    get_and_increment(left)
+                };
+                ptr::copy_nonoverlapping(to_copy, get_and_increment(out), 1);
+            }
+        }
+    } else {
+        // The right run is shorter.
+        // SAFETY: The caller guarantees that `buf` can hold `len - mid` elements.
+        unsafe {
+            ptr::copy_nonoverlapping(v_mid, buf, len - mid);
+            hole = MergeHole { start: buf, end: buf.add(len - mid), dest: v_mid };
+        }
+
+        // Initially, these pointers point past the ends of their arrays.
+        let left = &mut hole.dest;
+        let right = &mut hole.end;
+        let mut out = v_end;
+
+        while v < *left && buf < *right {
+            // Consume the greater side.
+            // If equal, prefer the right run to maintain stability.
+            // SAFETY: `left` and `right` point one past unconsumed elements of their runs, and
+            // `out` leads them, so it always points one past the hole.
+            unsafe {
+                let to_copy = if is_less(&*right.offset(-1), &*left.offset(-1)) {
+                    decrement_and_get(left)
+                } else {
+                    decrement_and_get(right)
+                };
+                ptr::copy_nonoverlapping(to_copy, decrement_and_get(&mut out), 1);
+            }
+        }
+    }
+    // Finally, `hole` gets dropped. If the shorter run was not fully consumed, whatever remains of
+    // it will now be copied into the hole in `v`.
+
+    unsafe fn get_and_increment<T>(ptr: &mut *mut T) -> *mut T {
+        let old = *ptr;
+        // SAFETY: The caller guarantees that the increment stays in bounds.
+        *ptr = unsafe { ptr.offset(1) };
+        old
+    }
+
+    unsafe fn decrement_and_get<T>(ptr: &mut *mut T) -> *mut T {
+        // SAFETY: The caller guarantees that the decrement stays in bounds.
+        *ptr = unsafe { ptr.offset(-1) };
+        *ptr
+    }
+
+    // When dropped, copies the range `start..end` into `dest..`.
+    struct MergeHole<T> {
+        start: *mut T,
+        end: *mut T,
+        dest: *mut T,
+    }
+
+    impl<T> Drop for MergeHole<T> {
+        fn drop(&mut self) {
+            // SAFETY: `T` is not a zero-sized type, and these are pointers into a slice's elements.
+            unsafe {
+                let len = self.end.offset_from(self.start) as usize;
+                ptr::copy_nonoverlapping(self.start, self.dest, len);
+            }
+        }
+    }
+}
+
+/// This merge sort borrows some (but not all) ideas from TimSort, which is described in detail
+/// [here](https://github.com/python/cpython/blob/main/Objects/listsort.txt).
+///
+/// The algorithm identifies strictly descending and non-descending subsequences, which are called
+/// natural runs. There is a stack of pending runs yet to be merged. Each newly found run is pushed
+/// onto the stack, and then some pairs of adjacent runs are merged until these two invariants are
+/// satisfied:
+///
+/// 1. for every `i` in `1..runs.len()`: `runs[i - 1].len > runs[i].len`
+/// 2. for every `i` in `2..runs.len()`: `runs[i - 2].len > runs[i - 1].len + runs[i].len`
+///
+/// The invariants ensure that the total running time is *O*(*n* \* log(*n*)) worst-case, and that
+/// the run lengths grow at least as fast as the Fibonacci numbers, so the stack of pending runs
+/// fits into a small fixed-size array and no allocation is needed for it.
+///
+/// Scratch memory for merging is requested through `get_buf`, which is called at most once, with
+/// the number of elements needed (`v.len() / 2`). Short slices are sorted with insertion sort and
+/// never request a buffer.
+///
+/// # Panics
+///
+/// Panics if the buffer returned by `get_buf` is shorter than requested.
+#[unstable(feature = "sort_internals", reason = "internal to sort module", issue = "none")]
+pub fn merge_sort<'a, T, F, B>(v: &mut [T], mut is_less: F, get_buf: B)
+where
+    T: 'a,
+    F: FnMut(&T, &T) -> bool,
+    B: FnOnce(usize) -> &'a mut [MaybeUninit<T>],
+{
+    // Slices of up to this length get sorted using insertion sort.
+    const MAX_INSERTION: usize = 20;
+    // Very short runs are extended using insertion sort to span at least this many elements.
+    const MIN_RUN: usize = 10;
+    // Upper bound on the number of pending runs. The invariants above make the lengths of the runs
+    // on the stack grow faster than the Fibonacci sequence, which exceeds `usize::MAX` long before
+    // reaching this many terms.
+    const MAX_RUNS: usize = 2 * usize::BITS as usize;
+
+    // Sorting has no meaningful behavior on zero-sized types.
+    if mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    let len = v.len();
+
+    // Short arrays get sorted in-place via insertion sort to avoid needing a buffer.
+    if len <= MAX_INSERTION {
+        if len >= 2 {
+            for i in (0..len - 1).rev() {
+                shift_head(&mut v[i..], &mut is_less);
+            }
+        }
+        return;
+    }
+
+    // The buffer holds shallow copies of the contents of `v` while merging, but as it consists of
+    // `MaybeUninit<T>` no destructors ever run on those copies, even if `is_less` panics. When
+    // merging two sorted runs, this buffer holds a copy of the shorter run, which will always have
+    // length at most `len / 2`.
+    let buf = get_buf(len / 2);
+    assert!(buf.len() >= len / 2, "merge sort scratch buffer is too short");
+    let buf = buf.as_mut_ptr() as *mut T;
+
+    // In order to identify natural runs in `v`, we traverse it backwards. That might seem like a
+    // strange decision, but consider the fact that merges more often go in the opposite direction
+    // (forwards). According to benchmarks, merging forwards is slightly faster than merging
+    // backwards. To conclude, identifying runs by traversing backwards improves performance.
+    let mut runs = [Run { start: 0, len: 0 }; MAX_RUNS];
+    let mut runs_len = 0;
+    let mut end = len;
+    while end > 0 {
+        // Find the next natural run, and reverse it if it's strictly descending.
+        let mut start = end - 1;
+        if start > 0 {
+            start -= 1;
+            // SAFETY: `start` and `start + 1` are below `end <= len`, and `start - 1` is only
+            // accessed while `start > 0`.
+            unsafe {
+                if is_less(v.get_unchecked(start + 1), v.get_unchecked(start)) {
+                    while start > 0 && is_less(v.get_unchecked(start), v.get_unchecked(start - 1)) {
+                        start -= 1;
+                    }
+                    v[start..end].reverse();
+                } else {
+                    while start > 0 && !is_less(v.get_unchecked(start), v.get_unchecked(start - 1))
+                    {
+                        start -= 1;
+                    }
+                }
+            }
+        }
+
+        // Insert some more elements into the run if it's too short. Insertion sort is faster than
+        // merge sort on short sequences, so this significantly improves performance.
+        while start > 0 && end - start < MIN_RUN {
+            start -= 1;
+            shift_head(&mut v[start..end], &mut is_less);
+        }
+
+        // Push this run onto the stack.
+        runs[runs_len] = Run { start, len: end - start };
+        runs_len += 1;
+        end = start;
+
+        // Merge some pairs of adjacent runs to satisfy the invariants.
+        while let Some(r) = collapse(&runs[..runs_len]) {
+            let left = runs[r + 1];
+            let right = runs[r];
+            // SAFETY: Both runs are non-empty and adjacent, `buf` can hold `len / 2` elements,
+            // which covers the shorter of the two runs, and `T` is not a zero-sized type.
+            unsafe {
+                merge(&mut v[left.start..right.start + right.len], left.len, buf, &mut is_less);
+            }
+            runs[r] = Run { start: left.start, len: left.len + right.len };
+            runs.copy_within(r + 2..runs_len, r + 1);
+            runs_len -= 1;
+        }
+    }
+
+    // Finally, exactly one run must remain in the stack.
+    debug_assert!(runs_len == 1 && runs[0].start == 0 && runs[0].len == len);
+
+    // Examines the stack of runs and identifies the next pair of runs to merge. More specifically,
+    // if `Some(r)` is returned, that means `runs[r]` and `runs[r + 1]` must be merged next. If the
+    // algorithm should continue building a new run instead, `None` is returned.
+    //
+    // TimSort is infamous for its buggy implementations, as described here:
+    // http://envisage-project.eu/timsort-specification-and-verification/
+    //
+    // The gist of the story is: we must enforce the invariants on the top four runs on the stack.
+    // Enforcing them on just top three is not sufficient to ensure that the invariants will still
+    // hold for *all* runs in the stack.
+    //
+    // This function correctly checks invariants for the top four runs. Additionally, if the top
+    // run starts at index 0, it will always demand a merge operation until the stack is fully
+    // collapsed, in order to complete the sort.
+    #[inline]
+    fn collapse(runs: &[Run]) -> Option<usize> {
+        let n = runs.len();
+        if n >= 2
+            && (runs[n - 1].start == 0
+                || runs[n - 2].len <= runs[n - 1].len
+                || (n >= 3 && runs[n - 3].len <= runs[n - 2].len + runs[n - 1].len)
+                || (n >= 4 && runs[n - 4].len <= runs[n - 3].len + runs[n - 2].len))
+        {
+            if n >= 3 && runs[n - 3].len < runs[n - 1].len { Some(n - 3) } else { Some(n - 2) }
+        } else {
+            None
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct Run {
+        start: usize,
+        len: usize,
+    }
+}
+
+/// Primitive integers that can be sorted by their bytes instead of by comparisons.
+trait RadixKey: Copy + Ord {
+    /// Number of bytes in the key.
+    const BYTES: usize;
+
+    /// Returns the `i`-th byte of the key, counting from the most significant one.
+    ///
+    /// Bytes are encoded such that comparing them lexicographically as unsigned integers gives
+    /// the same order as comparing the keys themselves.
+    fn radix_byte(self, i: usize) -> u8;
+}
+
+macro_rules! radix_key_impl {
+    ($($t:ty => $u:ty, $bias:expr;)*) => {$(
+        impl RadixKey for $t {
+            const BYTES: usize = mem::size_of::<$t>();
+
+            #[inline]
+            fn radix_byte(self, i: usize) -> u8 {
+                // Flipping the sign bit maps signed integers onto unsigned ones in order.
+                ((self as $u ^ $bias) >> (8 * (Self::BYTES - 1 - i))) as u8
+            }
+        }
+    )*}
+}
+
+radix_key_impl! {
+    u8 => u8, 0;
+    u16 => u16, 0;
+    u32 => u32, 0;
+    u64 => u64, 0;
+    u128 => u128, 0;
+    usize => usize, 0;
+    i8 => u8, 1 << (u8::BITS - 1);
+    i16 => u16, 1 << (u16::BITS - 1);
+    i32 => u32, 1 << (u32::BITS - 1);
+    i64 => u64, 1 << (u64::BITS - 1);
+    i128 => u128, 1 << (u128::BITS - 1);
+    isize => usize, 1 << (usize::BITS - 1);
+}
+
+/// Chooses the algorithm used by `sort_unstable_by_key` depending on the type of the key.
+trait SortUnstableByKey: Ord + Sized {
+    fn sort_unstable_by_key<T, F>(v: &mut [T], f: F)
+    where
+        F: FnMut(&T) -> Self;
+}
+
+impl<K: Ord> SortUnstableByKey for K {
+    default fn sort_unstable_by_key<T, F>(v: &mut [T], mut f: F)
+    where
+        F: FnMut(&T) -> K,
+    {
+        quicksort(v, |a, b| f(a).lt(&f(b)));
+    }
+}
+
+macro_rules! sort_unstable_by_key_radix_impl {
+    ($($t:ty)*) => {$(
+        impl SortUnstableByKey for $t {
+            fn sort_unstable_by_key<T, F>(v: &mut [T], f: F)
+            where
+                F: FnMut(&T) -> $t,
+            {
+                radix_sort_by_key(v, f);
+            }
+        }
+    )*}
+}
+
+sort_unstable_by_key_radix_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// Sorts `v` by the keys extracted with `f`, picking radix sort for primitive integer keys and
+/// pattern-defeating quicksort for everything else.
+pub fn sort_unstable_by_key<T, K, F>(v: &mut [T], f: F)
+where
+    F: FnMut(&T) -> K,
+    K: Ord,
+{
+    K::sort_unstable_by_key(v, f);
+}
+
+/// Slices shorter than this are sorted using quicksort rather than radix sort.
+const RADIX_MIN_LEN: usize = 1024;
+
+/// Sorts `v` using an in-place most-significant-digit radix sort (also known as American flag
+/// sort) on the bytes of the keys extracted with `f`.
+///
+/// The sort is *O*(*n* \* *k*) worst-case, where *k* is the size of the key in bytes. It moves
+/// elements by swapping them only, so `v` always holds every element exactly once, even if `f`
+/// panics.
+fn radix_sort_by_key<T, K, F>(v: &mut [T], mut f: F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    // Sorting has no meaningful behavior on zero-sized types.
+    if mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    radix_recurse(v, &mut f, 0);
+}
+
+/// Sorts `v`, whose keys all share their first `byte` bytes, using radix sort.
+///
+/// The recursion depth is bounded by the number of bytes in the key, and the stack frame of every
+/// level only holds a few words.
+fn radix_recurse<T, K, F>(v: &mut [T], f: &mut F, mut byte: usize)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    // Skip over bytes that are the same for all keys.
+    loop {
+        if v.len() < RADIX_MIN_LEN {
+            quicksort(v, |a, b| f(a).lt(&f(b)));
+            return;
+        }
+        if byte == K::BYTES {
+            // All keys are equal.
+            return;
+        }
+        if radix_partition(v, f, byte) {
+            break;
+        }
+        byte += 1;
+    }
+
+    // The buckets are sorted by the last byte already.
+    if byte + 1 == K::BYTES {
+        return;
+    }
+
+    // Recurse into each bucket. Their boundaries are found again by binary search rather than
+    // being kept around, so that this frame stays small.
+    let mut start = 0;
+    while start < v.len() {
+        let digit = f(&v[start]).radix_byte(byte);
+        let len = v[start..].partition_point(|x| f(x).radix_byte(byte) == digit);
+        // Always make progress, even if `f` is inconsistent and yields different keys.
+        let len = cmp::max(len, 1);
+        radix_recurse(&mut v[start..start + len], f, byte + 1);
+        start += len;
+    }
+}
+
+/// Permutes `v` into 256 buckets according to the `byte`-th byte of the keys.
+///
+/// Returns `false` without touching `v` if all keys have the same byte.
+#[inline(never)]
+fn radix_partition<T, K, F>(v: &mut [T], f: &mut F, byte: usize) -> bool
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    let len = v.len();
+
+    let mut counts = [0usize; 256];
+    for x in v.iter() {
+        counts[f(x).radix_byte(byte) as usize] += 1;
+    }
+    if counts.contains(&len) {
+        return false;
+    }
+
+    // The slots of bucket `d` before `heads[d]` are known to hold elements that belong there, and
+    // `ends[d]` is one past the last slot of the bucket.
+    let mut heads = [0usize; 256];
+    let mut ends = [0usize; 256];
+    let mut sum = 0;
+    for d in 0..256 {
+        heads[d] = sum;
+        sum += counts[d];
+        ends[d] = sum;
+    }
+
+    // Rather than following each cycle of misplaced elements to its end, every pass walks over
+    // the unplaced part of each bucket and swaps each element to the head of its target bucket.
+    // This avoids a chain of dependent loads per element and is considerably faster, at the cost
+    // of needing several passes until every bucket is full.
+    loop {
+        let mut done = true;
+        for d in 0..256 {
+            let end = ends[d];
+            let mut i = heads[d];
+            while i < end {
+                done = false;
+                let target = f(&v[i]).radix_byte(byte) as usize;
+                let head = heads[target];
+                if head < ends[target] {
+                    v.swap(i, head);
+                    heads[target] = head + 1;
+                } else {
+                    // If `f` is inconsistent, the target bucket may already be full. The element
+                    // is put into the current bucket then, which leaves the order unspecified but
+                    // still guarantees progress.
+                    v.swap(i, heads[d]);
+                    heads[d] += 1;
+                }
+                i += 1;
+            }
+        }
+        if done {
+            break;
+        }
+    }
+
+    true
+}
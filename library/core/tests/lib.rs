@@ -31,6 +31,7 @@
 #![feature(duration_constants)]
 #![feature(exact_size_is_empty)]
 #![feature(extern_types)]
+#![feature(fixed_width_uint)]
 #![feature(flt2dec)]
 #![feature(fmt_internals)]
 #![feature(float_minimum_maximum)]
@@ -44,6 +45,7 @@
 #![feature(pattern)]
 #![feature(pin_macro)]
 #![feature(sort_internals)]
+#![feature(slice_sort_with_buffer)]
 #![feature(slice_take)]
 #![feature(slice_from_ptr_range)]
 #![feature(split_as_slice)]
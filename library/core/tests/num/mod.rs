@@ -33,6 +33,7 @@
 mod flt2dec;
 mod int_log;
 mod ops;
+mod uint;
 mod wrapping;
 
 mod ieee754;
@@ -0,0 +1,197 @@
+use core::num::Uint;
+
+type U128 = Uint<2>;
+type U256 = Uint<4>;
+
+fn u(n: u128) -> U128 {
+    U128::try_from(n).unwrap()
+}
+
+const SAMPLES: [u128; 10] = [
+    0,
+    1,
+    2,
+    10,
+    0xffff_ffff_ffff_ffff,
+    0x1_0000_0000_0000_0000,
+    0x1234_5678_9abc_def0_0fed_cba9_8765_4321,
+    1 << 127,
+    u128::MAX - 1,
+    u128::MAX,
+];
+
+#[test]
+fn test_consts() {
+    assert_eq!(U256::BITS, 256);
+    assert_eq!(U256::MIN.to_limbs(), [0; 4]);
+    assert_eq!(U256::MAX.to_limbs(), [u64::MAX; 4]);
+    assert_eq!(U256::default(), U256::MIN);
+    assert_eq!(Uint::<0>::BITS, 0);
+}
+
+#[test]
+fn test_matches_u128() {
+    for &a in &SAMPLES {
+        assert_eq!(u(a).count_ones(), a.count_ones());
+        assert_eq!(u(a).leading_zeros(), a.leading_zeros());
+        assert_eq!(u(a).trailing_zeros(), a.trailing_zeros());
+        assert_eq!(u(a).leading_ones(), a.leading_ones());
+        assert_eq!(u(a).trailing_ones(), a.trailing_ones());
+        assert_eq!(u(a).swap_bytes(), u(a.swap_bytes()));
+        assert_eq!(u(a).reverse_bits(), u(a.reverse_bits()));
+        assert_eq!(u(a).checked_next_power_of_two(), a.checked_next_power_of_two().map(u));
+        assert_eq!(u(a).checked_log2(), a.checked_log2());
+        assert_eq!(u(a).overflowing_neg(), (u(a.wrapping_neg()), a != 0));
+
+        for n in [0, 1, 7, 63, 64, 65, 127, 128, 200] {
+            assert_eq!(u(a).rotate_left(n), u(a.rotate_left(n)));
+            assert_eq!(u(a).rotate_right(n), u(a.rotate_right(n)));
+            assert_eq!(u(a).overflowing_shl(n), (u(a.wrapping_shl(n)), n >= 128));
+            assert_eq!(u(a).overflowing_shr(n), (u(a.wrapping_shr(n)), n >= 128));
+        }
+        for exp in 0..4 {
+            let (n, o) = a.overflowing_pow(exp);
+            assert_eq!(u(a).overflowing_pow(exp), (u(n), o));
+        }
+
+        for &b in &SAMPLES {
+            let (n, o) = a.overflowing_add(b);
+            assert_eq!(u(a).overflowing_add(u(b)), (u(n), o));
+            let (n, o) = a.overflowing_sub(b);
+            assert_eq!(u(a).overflowing_sub(u(b)), (u(n), o));
+            let (n, o) = a.overflowing_mul(b);
+            assert_eq!(u(a).overflowing_mul(u(b)), (u(n), o));
+            assert_eq!(u(a).checked_div(u(b)), a.checked_div(b).map(u));
+            assert_eq!(u(a).checked_rem(u(b)), a.checked_rem(b).map(u));
+            assert_eq!(u(a).abs_diff(u(b)), u(a.abs_diff(b)));
+            assert_eq!(u(a).cmp(&u(b)), a.cmp(&b));
+            assert_eq!(u(a) & u(b), u(a & b));
+            assert_eq!(u(a) | u(b), u(a | b));
+            assert_eq!(u(a) ^ u(b), u(a ^ b));
+        }
+    }
+}
+
+#[test]
+fn test_wide_arith() {
+    let a = U256::from_limbs([0x0123_4567_89ab_cdef, u64::MAX, 0, 0x8000_0000]);
+    let b = U256::from_limbs([3, 0x1_0000, 0, 0]);
+    let (q, r) = (a / b, a % b);
+    assert!(r < b);
+    assert_eq!(q * b + r, a);
+
+    let (lo, hi) = U256::MAX.widening_mul(U256::MAX);
+    assert_eq!(lo, U256::from_limbs([1, 0, 0, 0]));
+    assert_eq!(hi, U256::from_limbs([u64::MAX - 1, u64::MAX, u64::MAX, u64::MAX]));
+
+    let one = U256::from_limbs([1, 0, 0, 0]);
+    assert_eq!(U256::MAX.carrying_add(U256::MIN, true), (U256::MIN, true));
+    assert_eq!(U256::MIN.borrowing_sub(U256::MIN, true), (U256::MAX, true));
+    assert_eq!(one << 255, U256::from_limbs([0, 0, 0, 1 << 63]));
+    assert_eq!((one << 255) >> 255, one);
+    assert_eq!(U256::from_limbs([2, 0, 0, 0]).checked_pow(255), Some(one << 255));
+    assert_eq!(U256::from_limbs([2, 0, 0, 0]).checked_pow(256), None);
+    assert_eq!(U256::MAX.saturating_add(one), U256::MAX);
+    assert_eq!(U256::MIN.saturating_sub(one), U256::MIN);
+    assert_eq!((!U256::MIN), U256::MAX);
+}
+
+#[test]
+fn test_try_from() {
+    assert_eq!(u8::try_from(u(255)), Ok(255));
+    assert!(u8::try_from(u(256)).is_err());
+    assert_eq!(u128::try_from(U256::from_limbs([1, 2, 0, 0])), Ok(2 << 64 | 1));
+    assert!(u128::try_from(U256::from_limbs([1, 2, 3, 0])).is_err());
+    assert_eq!(Uint::<1>::try_from(u64::MAX as u128), Ok(Uint::from_limbs([u64::MAX])));
+    assert!(Uint::<1>::try_from(1u128 << 64).is_err());
+    assert!(Uint::<0>::try_from(1u8).is_err());
+    assert_eq!(Uint::<0>::try_from(0u8), Ok(Uint::MIN));
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("0".parse::<U128>(), Ok(U128::MIN));
+    assert_eq!("+42".parse::<U128>(), Ok(u(42)));
+    assert_eq!(u128::MAX.to_string().parse::<U128>(), Ok(U128::MAX));
+    assert!("340282366920938463463374607431768211456".parse::<U128>().is_err());
+    assert!("".parse::<U128>().is_err());
+    assert!("-1".parse::<U128>().is_err());
+    assert!("12a".parse::<U128>().is_err());
+    assert_eq!(U256::from_str_radix(&"f".repeat(64), 16), Ok(U256::MAX));
+    assert!(U256::from_str_radix(&"1".repeat(257), 2).is_err());
+    assert_eq!(U256::from_str_radix("zz", 36), Ok(U256::from_limbs([35 * 36 + 35, 0, 0, 0])));
+}
+
+#[test]
+fn test_fmt() {
+    for &a in &SAMPLES {
+        assert_eq!(format!("{}", u(a)), format!("{}", a));
+        assert_eq!(format!("{:?}", u(a)), format!("{:?}", a));
+        assert_eq!(format!("{:x}", u(a)), format!("{:x}", a));
+        assert_eq!(format!("{:X}", u(a)), format!("{:X}", a));
+        assert_eq!(format!("{:o}", u(a)), format!("{:o}", a));
+        assert_eq!(format!("{:b}", u(a)), format!("{:b}", a));
+        assert_eq!(format!("{:#x?}", u(a)), format!("{:#x?}", a));
+        assert_eq!(format!("{:+050}", u(a)), format!("{:+050}", a));
+        assert_eq!(format!("{:<#45o}", u(a)), format!("{:<#45o}", a));
+        assert_eq!(format!("{:^140b}", u(a)), format!("{:^140b}", a));
+    }
+
+    assert_eq!(
+        U256::MAX.to_string(),
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+    );
+    assert_eq!(format!("{:#x}", U256::MAX), format!("0x{}", "f".repeat(64)));
+    assert_eq!(format!("{:b}", U256::MAX >> 1), "1".repeat(255));
+    assert_eq!(
+        format!("{}", U256::from_limbs([0, 0, 0, 1])),
+        "6277101735386680763835789423207666416102355444464034512896"
+    );
+    assert_eq!(format!("{:>5}", Uint::<0>::MIN), "    0");
+}
+
+#[test]
+fn test_fmt_zero_limbs() {
+    assert_eq!(format!("{:#x}", Uint::<0>::MIN), "0x0");
+    assert_eq!(format!("{:X}", Uint::<0>::MIN), "0");
+    assert_eq!(format!("{:o}", Uint::<0>::MIN), "0");
+    assert_eq!(format!("{:08b}", Uint::<0>::MIN), "00000000");
+    assert_eq!(format!("{:x?}", Uint::<0>::MAX), "0");
+}
+
+#[test]
+fn test_const() {
+    const TEN: U256 = U256::from_limbs([10, 0, 0, 0]);
+    const BIG: U256 = TEN.pow(70);
+    const LOG2: u32 = BIG.log2();
+    assert_eq!(LOG2, 232);
+    assert_eq!(BIG.to_string(), format!("1{}", "0".repeat(70)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_add_overflow() {
+    let _ = U256::MAX + U256::from_limbs([1, 0, 0, 0]);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn test_add_wraps_without_overflow_checks() {
+    assert_eq!(U256::MAX + U256::from_limbs([1, 0, 0, 0]), U256::MIN);
+    assert_eq!(U256::MIN - U256::from_limbs([1, 0, 0, 0]), U256::MAX);
+    assert_eq!(U256::MAX << 257, U256::MAX << 1);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_by_zero() {
+    let _ = U256::MAX / U256::MIN;
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn test_shl_overflow() {
+    let _ = U256::MAX << 256;
+}
@@ -1809,6 +1809,103 @@ fn sort_unstable() {
     assert!(v == [0xDEADBEEF]);
 }
 
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn sort_unstable_by_integer_key() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    // Long enough to exercise the radix sort for integer keys.
+    let lens = if cfg!(miri) { (2..20).chain(0..0) } else { (2..25).chain(5000..5003) };
+    let mut rng = StdRng::from_entropy();
+
+    for len in lens {
+        for &modulus in &[5, 1000, i64::MAX] {
+            let v: Vec<i64> = (0..len).map(|_| rng.gen::<i64>() % modulus).collect();
+            let mut expected = v.clone();
+            expected.sort();
+
+            let mut tmp = v.clone();
+            tmp.sort_unstable_by_key(|&x| x);
+            assert_eq!(tmp, expected);
+
+            let mut tmp = v.clone();
+            tmp.sort_unstable_by_key(|&x| x as i8);
+            assert!(tmp.windows(2).all(|w| w[0] as i8 <= w[1] as i8));
+
+            let mut tmp = v.clone();
+            tmp.sort_unstable_by_key(|&x| x as u128);
+            assert!(tmp.windows(2).all(|w| w[0] as u128 <= w[1] as u128));
+
+            // Keys only depend on part of the element.
+            let mut pairs: Vec<(u32, usize)> = v.iter().map(|&x| x as u32).zip(0..).collect();
+            pairs.sort_unstable_by_key(|&(k, _)| k);
+            assert!(pairs.windows(2).all(|w| w[0].0 <= w[1].0));
+            let mut indices: Vec<usize> = pairs.iter().map(|&(_, i)| i).collect();
+            indices.sort_unstable();
+            assert!(indices.into_iter().eq(0..len));
+        }
+    }
+
+    // An inconsistent key function reorders the elements *somehow*, but doesn't panic.
+    let mut v: Vec<u32> = (0..5000).collect();
+    v.sort_unstable_by_key(|_| rng.gen::<u32>());
+    v.sort_unstable();
+    assert!(v.into_iter().eq(0..5000));
+
+    // Should not panic.
+    [0u8; 0].sort_unstable_by_key(|&x| x);
+    [(); 5000].sort_unstable_by_key(|_| 0u64);
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn sort_with_buffer() {
+    use core::mem::MaybeUninit;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let lens = if cfg!(miri) { (2..20).chain(0..0) } else { (2..25).chain(500..510) };
+    let rounds = if cfg!(miri) { 1 } else { 10 };
+
+    let mut buf = [MaybeUninit::uninit(); 300];
+    let mut rng = StdRng::from_entropy();
+
+    for len in lens {
+        for _ in 0..rounds {
+            let v: Vec<(i32, usize)> = (0..len).map(|i| (rng.gen::<i32>() % 10, i)).collect();
+            let buf = &mut buf[..len / 2];
+
+            let mut tmp = v.clone();
+            tmp.sort_with_buffer(buf);
+            assert!(tmp.windows(2).all(|w| w[0] <= w[1]));
+
+            // Only sort on the first element, and check that equal elements kept their order.
+            let mut tmp = v.clone();
+            tmp.sort_by_with_buffer(buf, |a, b| a.0.cmp(&b.0));
+            assert!(tmp.windows(2).all(|w| w[0] <= w[1]));
+
+            let mut tmp = v.clone();
+            tmp.sort_by_key_with_buffer(buf, |&(k, _)| -k);
+            assert!(tmp.windows(2).all(|w| (-w[0].0, w[0].1) <= (-w[1].0, w[1].1)));
+        }
+    }
+
+    // Should not panic.
+    [0i32; 0].sort_with_buffer(&mut []);
+    let mut v = [3, 1, 2];
+    v.sort_with_buffer(&mut []);
+    assert_eq!(v, [1, 2, 3]);
+    [(); 100].sort_with_buffer(&mut [MaybeUninit::uninit(); 50]);
+}
+
+#[test]
+#[should_panic(expected = "too short")]
+fn sort_with_buffer_too_short() {
+    use core::mem::MaybeUninit;
+
+    let mut v = [0; 100];
+    v.sort_with_buffer(&mut [MaybeUninit::uninit(); 49]);
+}
+
 #[test]
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg_attr(miri, ignore)] // Miri is too slow
@@ -63,6 +63,21 @@
 //! variables is cached once the first backtrace is created, so altering
 //! `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` at runtime might not actually change
 //! how backtraces are captured.
+//!
+//! ## Symbolization
+//!
+//! Capturing a backtrace only records the instruction pointer of each frame.
+//! Symbol names, filenames and line numbers are looked up the first time they
+//! are needed, separately for every frame, so inspecting only the top few
+//! [`frames`] of a backtrace doesn't pay for symbolizing the rest of them.
+//!
+//! Symbolization always happens in the process that captured the backtrace,
+//! using the debug information of the modules loaded in it. Backtraces can't
+//! be serialized and symbolized later or offline, for example in another
+//! process or on another machine; this isn't supported by the standard
+//! library.
+//!
+//! [`frames`]: Backtrace::frames
 
 #![unstable(feature = "backtrace", issue = "53487")]
 
@@ -85,23 +100,25 @@
 // We attempt to amortize this cost as much as possible by delaying resolution
 // of an address to a human readable name for as long as possible. When
 // `Backtrace::create` is called to capture a backtrace it doesn't actually
-// perform any symbol resolution, but rather we lazily resolve symbols only just
-// before they're needed for printing. This way we can make capturing a
-// backtrace and throwing it away much cheaper, but actually printing a
-// backtrace is still basically the same cost.
+// perform any symbol resolution, but rather we lazily resolve the symbols of
+// each frame only just before they're needed for printing or through
+// `BacktraceFrame::symbols`. This way we can make capturing a backtrace and
+// throwing it away much cheaper, but actually printing a backtrace is still
+// basically the same cost.
 //
-// This strategy comes at the cost of some synchronization required inside of a
-// `Backtrace`, but that's a relatively small price to pay relative to capturing
-// a backtrace or actually symbolizing it.
+// This strategy comes at the cost of some synchronization required inside of
+// every `BacktraceFrame`, but that's a relatively small price to pay relative
+// to capturing a backtrace or actually symbolizing it.
 
 use crate::backtrace_rs::{self, BytesOrWideString};
-use crate::cell::UnsafeCell;
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
+use crate::lazy::SyncOnceCell;
+use crate::path::PathBuf;
+use crate::str;
 use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
-use crate::sync::Once;
-use crate::sys_common::backtrace::{lock, output_filename};
+use crate::sys_common::backtrace::{bows_to_path, lock, output_filename};
 use crate::vec::Vec;
 
 /// A captured OS thread stack backtrace.
@@ -134,12 +151,11 @@ pub enum BacktraceStatus {
 enum Inner {
     Unsupported,
     Disabled,
-    Captured(LazilyResolvedCapture),
+    Captured(Capture),
 }
 
 struct Capture {
     actual_start: usize,
-    resolved: bool,
     frames: Vec<BacktraceFrame>,
 }
 
@@ -152,7 +168,7 @@ fn _assert<T: Send + Sync>() {}
 #[unstable(feature = "backtrace_frames", issue = "79676")]
 pub struct BacktraceFrame {
     frame: RawFrame,
-    symbols: Vec<BacktraceSymbol>,
+    symbols: SyncOnceCell<Vec<BacktraceSymbol>>,
 }
 
 #[derive(Debug)]
@@ -162,13 +178,31 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol of a [`BacktraceFrame`], describing a function that the frame's
+/// instruction pointer is in.
+///
+/// A frame can have more than one symbol when functions were inlined into
+/// each other, in which case the innermost function comes first.
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
     colno: Option<u32>,
 }
 
+/// The name of a [`BacktraceSymbol`].
+///
+/// Its [`Display`] implementation prints the demangled name. The alternate
+/// flag (`{:#}`) leaves out the hash that is part of Rust symbol names.
+///
+/// [`Display`]: fmt::Display
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+#[derive(Clone, Copy)]
+pub struct BacktraceSymbolName<'a> {
+    bytes: &'a [u8],
+}
+
 enum BytesOrWide {
     Bytes(Vec<u8>),
     Wide(Vec<u16>),
@@ -179,7 +213,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let capture = match &self.inner {
             Inner::Unsupported => return fmt.write_str("<unsupported>"),
             Inner::Disabled => return fmt.write_str("<disabled>"),
-            Inner::Captured(c) => c.force(),
+            Inner::Captured(c) => c,
         };
 
         let frames = &capture.frames[capture.actual_start..];
@@ -193,7 +227,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
                 continue;
             }
 
-            dbg.entries(&frame.symbols);
+            dbg.entries(frame.symbols());
         }
 
         dbg.finish()
@@ -203,7 +237,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
 impl fmt::Debug for BacktraceFrame {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut dbg = fmt.debug_list();
-        dbg.entries(&self.symbols);
+        dbg.entries(self.symbols());
         dbg.finish()
     }
 }
@@ -238,16 +272,34 @@ impl fmt::Debug for BytesOrWide {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         output_filename(
             fmt,
-            match self {
-                BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-            },
+            self.as_bows(),
             backtrace_rs::PrintFmt::Short,
             crate::env::current_dir().as_ref().ok(),
         )
     }
 }
 
+impl BytesOrWide {
+    fn as_bows(&self) -> BytesOrWideString<'_> {
+        match self {
+            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
+            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
+        }
+    }
+}
+
+impl fmt::Debug for BacktraceSymbolName<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&backtrace_rs::SymbolName::new(self.bytes), fmt)
+    }
+}
+
+impl fmt::Display for BacktraceSymbolName<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&backtrace_rs::SymbolName::new(self.bytes), fmt)
+    }
+}
+
 impl Backtrace {
     /// Returns whether backtrace captures are enabled through environment
     /// variables.
@@ -326,10 +378,7 @@ fn create(ip: usize) -> Backtrace {
         let mut actual_start = None;
         unsafe {
             backtrace_rs::trace_unsynchronized(|frame| {
-                frames.push(BacktraceFrame {
-                    frame: RawFrame::Actual(frame.clone()),
-                    symbols: Vec::new(),
-                });
+                frames.push(BacktraceFrame::new(RawFrame::Actual(frame.clone())));
                 if frame.symbol_address().addr() == ip && actual_start.is_none() {
                     actual_start = Some(frames.len());
                 }
@@ -343,11 +392,7 @@ fn create(ip: usize) -> Backtrace {
         let inner = if frames.is_empty() {
             Inner::Unsupported
         } else {
-            Inner::Captured(LazilyResolvedCapture::new(Capture {
-                actual_start: actual_start.unwrap_or(0),
-                frames,
-                resolved: false,
-            }))
+            Inner::Captured(Capture { actual_start: actual_start.unwrap_or(0), frames })
         };
 
         Backtrace { inner }
@@ -368,10 +413,135 @@ pub fn status(&self) -> BacktraceStatus {
 
 impl<'a> Backtrace {
     /// Returns an iterator over the backtrace frames.
+    ///
+    /// This doesn't symbolize the frames; that only happens for the frames
+    /// whose [`symbols`](BacktraceFrame::symbols) are requested.
     #[must_use]
     #[unstable(feature = "backtrace_frames", issue = "79676")]
     pub fn frames(&'a self) -> &'a [BacktraceFrame] {
-        if let Inner::Captured(c) = &self.inner { &c.force().frames } else { &[] }
+        if let Inner::Captured(c) = &self.inner { &c.frames } else { &[] }
+    }
+}
+
+impl BacktraceFrame {
+    fn new(frame: RawFrame) -> BacktraceFrame {
+        BacktraceFrame { frame, symbols: SyncOnceCell::new() }
+    }
+
+    /// Returns the current instruction pointer of this frame.
+    ///
+    /// This is normally the next instruction to execute in the frame, such as
+    /// the return address of a call. Symbolization takes this into account.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the starting address of the function this frame is in, if
+    /// known without symbolizing the frame.
+    ///
+    /// Otherwise, this returns the same as [`ip`](BacktraceFrame::ip).
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        match &self.frame {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            #[cfg(test)]
+            RawFrame::Fake => self.frame.ip(),
+        }
+    }
+
+    /// Returns the base address at which the module (executable or shared
+    /// library) this frame is in was loaded, if known.
+    ///
+    /// Subtracting this from [`ip`](BacktraceFrame::ip) gives the offset of
+    /// the instruction pointer within the module, which doesn't depend on
+    /// where the module was loaded.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_base_address(&self) -> Option<*mut c_void> {
+        match &self.frame {
+            RawFrame::Actual(frame) => frame.module_base_address(),
+            #[cfg(test)]
+            RawFrame::Fake => None,
+        }
+    }
+
+    /// Returns the symbols of this frame, resolving them the first time this
+    /// is called.
+    ///
+    /// This is empty if no symbol information is available for this frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace, backtrace_frames)]
+    ///
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// for frame in backtrace.frames().iter().take(3) {
+    ///     for symbol in frame.symbols() {
+    ///         if let Some(name) = symbol.name() {
+    ///             let (file, line) = (symbol.filename(), symbol.lineno());
+    ///             println!("{:?} {name:#} at {file:?}:{line:?}", frame.ip());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        self.symbols.get_or_init(|| self.frame.resolve())
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the name of this function, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn name(&self) -> Option<BacktraceSymbolName<'_>> {
+        self.name.as_deref().map(|bytes| BacktraceSymbolName { bytes })
+    }
+
+    /// Returns the path of the source file of this function, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().map(|f| bows_to_path(f.as_bows()).into_owned())
+    }
+
+    /// Returns the line number in [`filename`](BacktraceSymbol::filename)
+    /// this frame is at, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number in [`filename`](BacktraceSymbol::filename)
+    /// this frame is at, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
+impl<'a> BacktraceSymbolName<'a> {
+    /// Returns the raw, possibly mangled, bytes of this name.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the raw, possibly mangled, name if it is valid UTF-8.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn as_str(&self) -> Option<&'a str> {
+        str::from_utf8(self.bytes).ok()
     }
 }
 
@@ -380,7 +550,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let capture = match &self.inner {
             Inner::Unsupported => return fmt.write_str("unsupported backtrace"),
             Inner::Disabled => return fmt.write_str("disabled backtrace"),
-            Inner::Captured(c) => c.force(),
+            Inner::Captured(c) => c,
         };
 
         let full = fmt.alternate();
@@ -402,17 +572,15 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut f = backtrace_rs::BacktraceFmt::new(fmt, style, &mut print_path);
         f.add_context()?;
         for frame in frames {
-            if frame.symbols.is_empty() {
+            let symbols = frame.symbols();
+            if symbols.is_empty() {
                 f.frame().print_raw(frame.frame.ip(), None, None, None)?;
             } else {
-                for symbol in frame.symbols.iter() {
+                for symbol in symbols {
                     f.frame().print_raw_with_column(
                         frame.frame.ip(),
                         symbol.name.as_ref().map(|b| backtrace_rs::SymbolName::new(b)),
-                        symbol.filename.as_ref().map(|b| match b {
-                            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-                        }),
+                        symbol.filename.as_ref().map(BytesOrWide::as_bows),
                         symbol.lineno,
                         symbol.colno,
                     )?;
@@ -424,76 +592,42 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
-struct LazilyResolvedCapture {
-    sync: Once,
-    capture: UnsafeCell<Capture>,
-}
-
-impl LazilyResolvedCapture {
-    fn new(capture: Capture) -> Self {
-        LazilyResolvedCapture { sync: Once::new(), capture: UnsafeCell::new(capture) }
-    }
-
-    fn force(&self) -> &Capture {
-        self.sync.call_once(|| {
-            // SAFETY: This exclusive reference can't overlap with any others
-            // `Once` guarantees callers will block until this closure returns
-            // `Once` also guarantees only a single caller will enter this closure
-            unsafe { &mut *self.capture.get() }.resolve();
-        });
-
-        // SAFETY: This shared reference can't overlap with the exclusive reference above
-        unsafe { &*self.capture.get() }
+impl RawFrame {
+    fn ip(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.ip(),
+            #[cfg(test)]
+            RawFrame::Fake => crate::ptr::invalid_mut(1),
+        }
     }
-}
-
-// SAFETY: Access to the inner value is synchronized using a thread-safe `Once`
-// So long as `Capture` is `Sync`, `LazilyResolvedCapture` is too
-unsafe impl Sync for LazilyResolvedCapture where Capture: Sync {}
 
-impl Capture {
-    fn resolve(&mut self) {
-        // If we're already resolved, nothing to do!
-        if self.resolved {
-            return;
-        }
-        self.resolved = true;
+    fn resolve(&self) -> Vec<BacktraceSymbol> {
+        let mut symbols = Vec::new();
+        let mut push = |symbol: &backtrace_rs::Symbol| {
+            symbols.push(BacktraceSymbol {
+                name: symbol.name().map(|m| m.as_bytes().to_vec()),
+                filename: symbol.filename_raw().map(|b| match b {
+                    BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
+                    BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
+                }),
+                lineno: symbol.lineno(),
+                colno: symbol.colno(),
+            });
+        };
 
         // Use the global backtrace lock to synchronize this as it's a
-        // requirement of the `backtrace` crate, and then actually resolve
-        // everything.
+        // requirement of the `backtrace` crate.
         // SAFETY: We don't attempt to lock this reentrantly.
         let _lock = unsafe { lock() };
-        for frame in self.frames.iter_mut() {
-            let symbols = &mut frame.symbols;
-            let frame = match &frame.frame {
-                RawFrame::Actual(frame) => frame,
+        unsafe {
+            match self {
+                RawFrame::Actual(frame) => {
+                    backtrace_rs::resolve_frame_unsynchronized(frame, &mut push)
+                }
                 #[cfg(test)]
                 RawFrame::Fake => unimplemented!(),
-            };
-            unsafe {
-                backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
-                    symbols.push(BacktraceSymbol {
-                        name: symbol.name().map(|m| m.as_bytes().to_vec()),
-                        filename: symbol.filename_raw().map(|b| match b {
-                            BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
-                            BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
-                        }),
-                        lineno: symbol.lineno(),
-                        colno: symbol.colno(),
-                    });
-                });
             }
         }
-    }
-}
-
-impl RawFrame {
-    fn ip(&self) -> *mut c_void {
-        match self {
-            RawFrame::Actual(frame) => frame.ip(),
-            #[cfg(test)]
-            RawFrame::Fake => crate::ptr::invalid_mut(1),
-        }
+        symbols
     }
 }
@@ -4,25 +4,25 @@ fn generate_fake_frames() -> Vec<BacktraceFrame> {
     vec![
         BacktraceFrame {
             frame: RawFrame::Fake,
-            symbols: vec![BacktraceSymbol {
+            symbols: SyncOnceCell::from(vec![BacktraceSymbol {
                 name: Some(b"std::backtrace::Backtrace::create".to_vec()),
                 filename: Some(BytesOrWide::Bytes(b"rust/backtrace.rs".to_vec())),
                 lineno: Some(100),
                 colno: None,
-            }],
+            }]),
         },
         BacktraceFrame {
             frame: RawFrame::Fake,
-            symbols: vec![BacktraceSymbol {
+            symbols: SyncOnceCell::from(vec![BacktraceSymbol {
                 name: Some(b"__rust_maybe_catch_panic".to_vec()),
                 filename: None,
                 lineno: None,
                 colno: None,
-            }],
+            }]),
         },
         BacktraceFrame {
             frame: RawFrame::Fake,
-            symbols: vec![
+            symbols: SyncOnceCell::from(vec![
                 BacktraceSymbol {
                     name: Some(b"std::rt::lang_start_internal".to_vec()),
                     filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
@@ -35,7 +35,7 @@ fn generate_fake_frames() -> Vec<BacktraceFrame> {
                     lineno: Some(400),
                     colno: None,
                 },
-            ],
+            ]),
         },
     ]
 }
@@ -43,11 +43,7 @@ fn generate_fake_frames() -> Vec<BacktraceFrame> {
 #[test]
 fn test_debug() {
     let backtrace = Backtrace {
-        inner: Inner::Captured(LazilyResolvedCapture::new(Capture {
-            actual_start: 1,
-            resolved: true,
-            frames: generate_fake_frames(),
-        })),
+        inner: Inner::Captured(Capture { actual_start: 1, frames: generate_fake_frames() }),
     };
 
     #[rustfmt::skip]
@@ -66,11 +62,7 @@ fn test_debug() {
 #[test]
 fn test_frames() {
     let backtrace = Backtrace {
-        inner: Inner::Captured(LazilyResolvedCapture::new(Capture {
-            actual_start: 1,
-            resolved: true,
-            frames: generate_fake_frames(),
-        })),
+        inner: Inner::Captured(Capture { actual_start: 1, frames: generate_fake_frames() }),
     };
 
     let frames = backtrace.frames();
@@ -93,3 +85,25 @@ fn test_frames() {
 
     assert!(iter.all(|(f, e)| format!("{f:#?}") == *e));
 }
+
+#[test]
+fn test_symbol_accessors() {
+    let frames = generate_fake_frames();
+
+    assert_eq!(frames[2].ip(), crate::ptr::invalid_mut(1));
+    assert_eq!(frames[2].module_base_address(), None);
+
+    let symbols = frames[2].symbols();
+    assert_eq!(symbols.len(), 2);
+    let name = symbols[0].name().unwrap();
+    assert_eq!(name.as_bytes(), b"std::rt::lang_start_internal");
+    assert_eq!(name.as_str(), Some("std::rt::lang_start_internal"));
+    assert_eq!(name.to_string(), "std::rt::lang_start_internal");
+    assert_eq!(symbols[0].filename(), Some(PathBuf::from("rust/rt.rs")));
+    assert_eq!(symbols[0].lineno(), Some(300));
+    assert_eq!(symbols[0].colno(), Some(5));
+
+    let symbols = frames[1].symbols();
+    assert_eq!(symbols[0].filename(), None);
+    assert_eq!(symbols[0].lineno(), None);
+}
@@ -241,6 +241,7 @@ pub struct DirBuilder {
 /// }
 /// ```
 #[stable(feature = "fs_read_write_bytes", since = "1.26.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
     fn inner(path: &Path) -> io::Result<Vec<u8>> {
         let mut file = File::open(path)?;
@@ -280,6 +281,7 @@ fn inner(path: &Path) -> io::Result<Vec<u8>> {
 /// }
 /// ```
 #[stable(feature = "fs_read_write", since = "1.26.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn read_to_string<P: AsRef<Path>>(path: P) -> io::Result<String> {
     fn inner(path: &Path) -> io::Result<String> {
         let mut file = File::open(path)?;
@@ -312,6 +314,7 @@ fn inner(path: &Path) -> io::Result<String> {
 /// }
 /// ```
 #[stable(feature = "fs_read_write_bytes", since = "1.26.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
     fn inner(path: &Path, contents: &[u8]) -> io::Result<()> {
         File::create(path)?.write_all(contents)
@@ -340,6 +343,7 @@ impl File {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn open<P: AsRef<Path>>(path: P) -> io::Result<File> {
         OpenOptions::new().read(true).open(path.as_ref())
     }
@@ -362,6 +366,7 @@ pub fn open<P: AsRef<Path>>(path: P) -> io::Result<File> {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn create<P: AsRef<Path>>(path: P) -> io::Result<File> {
         OpenOptions::new().write(true).create(true).truncate(true).open(path.as_ref())
     }
@@ -420,6 +425,7 @@ pub fn options() -> OpenOptions {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn sync_all(&self) -> io::Result<()> {
         self.inner.fsync()
     }
@@ -451,6 +457,7 @@ pub fn sync_all(&self) -> io::Result<()> {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn sync_data(&self) -> io::Result<()> {
         self.inner.datasync()
     }
@@ -488,6 +495,7 @@ pub fn sync_data(&self) -> io::Result<()> {
     /// Note that this method alters the content of the underlying file, even
     /// though it takes `&self` rather than `&mut self`.
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn set_len(&self, size: u64) -> io::Result<()> {
         self.inner.truncate(size)
     }
@@ -506,6 +514,7 @@ pub fn set_len(&self, size: u64) -> io::Result<()> {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn metadata(&self) -> io::Result<Metadata> {
         self.inner.file_attr().map(Metadata)
     }
@@ -587,6 +596,7 @@ pub fn try_clone(&self) -> io::Result<File> {
     /// Note that this method alters the permissions of the underlying file,
     /// even though it takes `&self` rather than `&mut self`.
     #[stable(feature = "set_permissions_atomic", since = "1.16.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn set_permissions(&self, perm: Permissions) -> io::Result<()> {
         self.inner.set_permissions(perm.0)
     }
@@ -632,14 +642,17 @@ fn buffer_capacity_required(mut file: &File) -> usize {
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for File {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
         self.inner.read_buf(buf)
     }
@@ -650,12 +663,14 @@ fn is_read_vectored(&self) -> bool {
     }
 
     // Reserves space in the buffer based on the file size when available.
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         buf.reserve(buffer_capacity_required(self));
         io::default_read_to_end(self, buf)
     }
 
     // Reserves space in the buffer based on the file size when available.
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
         buf.reserve(buffer_capacity_required(self));
         io::default_read_to_string(self, buf)
@@ -663,10 +678,12 @@ fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Write for File {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.inner.write(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
         self.inner.write_vectored(bufs)
     }
@@ -676,26 +693,31 @@ fn is_write_vectored(&self) -> bool {
         self.inner.is_write_vectored()
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn flush(&mut self) -> io::Result<()> {
         self.inner.flush()
     }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Seek for File {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
         self.inner.seek(pos)
     }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for &File {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
         self.inner.read_buf(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
@@ -706,12 +728,14 @@ fn is_read_vectored(&self) -> bool {
     }
 
     // Reserves space in the buffer based on the file size when available.
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         buf.reserve(buffer_capacity_required(self));
         io::default_read_to_end(self, buf)
     }
 
     // Reserves space in the buffer based on the file size when available.
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
         buf.reserve(buffer_capacity_required(self));
         io::default_read_to_string(self, buf)
@@ -719,10 +743,12 @@ fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Write for &File {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.inner.write(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
         self.inner.write_vectored(bufs)
     }
@@ -732,12 +758,14 @@ fn is_write_vectored(&self) -> bool {
         self.inner.is_write_vectored()
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn flush(&mut self) -> io::Result<()> {
         self.inner.flush()
     }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Seek for &File {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
         self.inner.seek(pos)
     }
@@ -960,6 +988,7 @@ pub fn create_new(&mut self, create_new: bool) -> &mut Self {
     /// [`NotFound`]: io::ErrorKind::NotFound
     /// [`PermissionDenied`]: io::ErrorKind::PermissionDenied
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn open<P: AsRef<Path>>(&self, path: P) -> io::Result<File> {
         self._open(path.as_ref())
     }
@@ -1497,6 +1526,7 @@ pub fn path(&self) -> PathBuf {
     /// }
     /// ```
     #[stable(feature = "dir_entry_ext", since = "1.1.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn metadata(&self) -> io::Result<Metadata> {
         self.0.metadata().map(Metadata)
     }
@@ -1607,6 +1637,7 @@ fn as_inner(&self) -> &fs_imp::DirEntry {
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn remove_file<P: AsRef<Path>>(path: P) -> io::Result<()> {
     fs_imp::unlink(path.as_ref())
 }
@@ -1645,6 +1676,7 @@ pub fn remove_file<P: AsRef<Path>>(path: P) -> io::Result<()> {
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn metadata<P: AsRef<Path>>(path: P) -> io::Result<Metadata> {
     fs_imp::stat(path.as_ref()).map(Metadata)
 }
@@ -1679,6 +1711,7 @@ pub fn metadata<P: AsRef<Path>>(path: P) -> io::Result<Metadata> {
 /// }
 /// ```
 #[stable(feature = "symlink_metadata", since = "1.1.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn symlink_metadata<P: AsRef<Path>>(path: P) -> io::Result<Metadata> {
     fs_imp::lstat(path.as_ref()).map(Metadata)
 }
@@ -1722,6 +1755,7 @@ pub fn symlink_metadata<P: AsRef<Path>>(path: P) -> io::Result<Metadata> {
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn rename<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<()> {
     fs_imp::rename(from.as_ref(), to.as_ref())
 }
@@ -1780,6 +1814,7 @@ pub fn rename<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<()>
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn copy<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<u64> {
     fs_imp::copy(from.as_ref(), to.as_ref())
 }
@@ -1824,6 +1859,7 @@ pub fn copy<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<u64> {
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn hard_link<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> io::Result<()> {
     fs_imp::link(original.as_ref(), link.as_ref())
 }
@@ -1890,6 +1926,7 @@ pub fn soft_link<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> io::Re
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn read_link<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
     fs_imp::readlink(path.as_ref())
 }
@@ -1931,6 +1968,7 @@ pub fn read_link<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
 /// }
 /// ```
 #[stable(feature = "fs_canonicalize", since = "1.5.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn canonicalize<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
     fs_imp::canonicalize(path.as_ref())
 }
@@ -1972,6 +2010,7 @@ pub fn canonicalize<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
 /// ```
 #[doc(alias = "mkdir")]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn create_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
     DirBuilder::new().create(path.as_ref())
 }
@@ -2016,6 +2055,7 @@ pub fn create_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn create_dir_all<P: AsRef<Path>>(path: P) -> io::Result<()> {
     DirBuilder::new().recursive(true).create(path.as_ref())
 }
@@ -2052,6 +2092,7 @@ pub fn create_dir_all<P: AsRef<Path>>(path: P) -> io::Result<()> {
 /// ```
 #[doc(alias = "rmdir")]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn remove_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
     fs_imp::rmdir(path.as_ref())
 }
@@ -2094,6 +2135,7 @@ pub fn remove_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn remove_dir_all<P: AsRef<Path>>(path: P) -> io::Result<()> {
     fs_imp::remove_dir_all(path.as_ref())
 }
@@ -2169,6 +2211,7 @@ pub fn remove_dir_all<P: AsRef<Path>>(path: P) -> io::Result<()> {
 /// }
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
     fs_imp::readdir(path.as_ref()).map(ReadDir)
 }
@@ -2204,6 +2247,7 @@ pub fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
 /// }
 /// ```
 #[stable(feature = "set_permissions", since = "1.1.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn set_permissions<P: AsRef<Path>>(path: P, perm: Permissions) -> io::Result<()> {
     fs_imp::set_perm(path.as_ref(), perm.0)
 }
@@ -2264,6 +2308,7 @@ pub fn recursive(&mut self, recursive: bool) -> &mut Self {
     /// assert!(fs::metadata(path).unwrap().is_dir());
     /// ```
     #[stable(feature = "dir_builder", since = "1.6.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn create<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
         self._create(path.as_ref())
     }
@@ -2330,6 +2375,7 @@ fn as_inner_mut(&mut self) -> &mut fs_imp::DirBuilder {
 // instead.
 #[unstable(feature = "path_try_exists", issue = "83186")]
 #[inline]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn try_exists<P: AsRef<Path>>(path: P) -> io::Result<bool> {
     fs_imp::try_exists(path.as_ref())
 }
@@ -224,6 +224,7 @@
 #![feature(allocator_internals)]
 #![feature(allow_internal_unsafe)]
 #![feature(allow_internal_unstable)]
+#![cfg_attr(not(bootstrap), feature(blocking))]
 #![feature(box_syntax)]
 #![feature(c_unwind)]
 #![feature(cfg_target_thread_local)]
@@ -316,6 +317,7 @@
 #![feature(core_panic)]
 #![feature(custom_test_frameworks)]
 #![feature(edition_panic)]
+#![feature(fixed_width_uint)]
 #![feature(format_args_nl)]
 #![feature(log_syntax)]
 #![feature(once_cell)]
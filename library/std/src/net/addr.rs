@@ -892,6 +892,7 @@ pub trait ToSocketAddrs {
     /// Note that this function may block the current thread while resolution is
     /// performed.
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking = "resolving a host name may block on a DNS lookup")]
     fn to_socket_addrs(&self) -> io::Result<Self::Iter>;
 }
 
@@ -963,6 +964,7 @@ fn resolve_socket_addr(lh: LookupHost) -> io::Result<vec::IntoIter<SocketAddr>>
 #[stable(feature = "rust1", since = "1.0.0")]
 impl ToSocketAddrs for (&str, u16) {
     type Iter = vec::IntoIter<SocketAddr>;
+    #[cfg_attr(not(bootstrap), blocking = "resolving a host name may block on a DNS lookup")]
     fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
         let (host, port) = *self;
 
@@ -983,6 +985,7 @@ fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
 #[stable(feature = "string_u16_to_socket_addrs", since = "1.46.0")]
 impl ToSocketAddrs for (String, u16) {
     type Iter = vec::IntoIter<SocketAddr>;
+    #[cfg_attr(not(bootstrap), blocking = "resolving a host name may block on a DNS lookup")]
     fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
         (&*self.0, self.1).to_socket_addrs()
     }
@@ -992,6 +995,7 @@ fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl ToSocketAddrs for str {
     type Iter = vec::IntoIter<SocketAddr>;
+    #[cfg_attr(not(bootstrap), blocking = "resolving a host name may block on a DNS lookup")]
     fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
         // try to parse as a regular SocketAddr first
         if let Ok(addr) = self.parse() {
@@ -1022,6 +1026,7 @@ fn to_socket_addrs(&self) -> io::Result<T::Iter> {
 #[stable(feature = "string_to_socket_addrs", since = "1.16.0")]
 impl ToSocketAddrs for String {
     type Iter = vec::IntoIter<SocketAddr>;
+    #[cfg_attr(not(bootstrap), blocking = "resolving a host name may block on a DNS lookup")]
     fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
         (&**self).to_socket_addrs()
     }
@@ -0,0 +1,848 @@
+// Tests for this module
+#[cfg(all(test, not(target_os = "emscripten")))]
+mod tests;
+
+use crate::error::Error;
+use crate::fmt;
+use crate::io::Write as IoWrite;
+use crate::iter::FusedIterator;
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use crate::ops::RangeInclusive;
+
+/// An IP network, either IPv4 or IPv6.
+///
+/// This enum can contain either an [`Ipv4Net`] or an [`Ipv6Net`], see their
+/// respective documentation for more details.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_network)]
+/// use std::net::{IpAddr, IpNet, Ipv4Addr, Ipv4Net};
+///
+/// let net: IpNet = "10.0.0.0/8".parse().unwrap();
+/// assert_eq!(net, IpNet::V4(Ipv4Net::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()));
+/// assert!(net.contains(&IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3))));
+/// assert!(!net.contains(&"::1".parse().unwrap()));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub enum IpNet {
+    /// An IPv4 network.
+    #[unstable(feature = "ip_network", issue = "none")]
+    V4(#[unstable(feature = "ip_network", issue = "none")] Ipv4Net),
+    /// An IPv6 network.
+    #[unstable(feature = "ip_network", issue = "none")]
+    V6(#[unstable(feature = "ip_network", issue = "none")] Ipv6Net),
+}
+
+/// An IPv4 network: an address together with the length of its network prefix.
+///
+/// The address is stored as given, so `192.168.1.7/24` keeps the host bits
+/// of `192.168.1.7`. Use [`Ipv4Net::network`] or [`Ipv4Net::trunc`] to clear
+/// them.
+///
+/// # Textual representation
+///
+/// `Ipv4Net` provides a [`FromStr`] implementation for CIDR notation: an
+/// [`Ipv4Addr`] in dot-decimal notation, followed by `/` and the prefix length
+/// in decimal, such as `10.0.0.0/8`. The prefix length can't have a leading
+/// zero.
+///
+/// [`FromStr`]: crate::str::FromStr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_network)]
+/// use std::net::{Ipv4Addr, Ipv4Net};
+///
+/// let net: Ipv4Net = "192.168.1.7/24".parse().unwrap();
+/// assert_eq!(net.addr(), Ipv4Addr::new(192, 168, 1, 7));
+/// assert_eq!(net.network(), Ipv4Addr::new(192, 168, 1, 0));
+/// assert_eq!(net.broadcast(), Ipv4Addr::new(192, 168, 1, 255));
+/// assert_eq!(net.netmask(), Ipv4Addr::new(255, 255, 255, 0));
+/// assert!(net.contains(&Ipv4Addr::new(192, 168, 1, 200)));
+/// assert_eq!(net.hosts().count(), 254);
+/// assert_eq!(net.to_string(), "192.168.1.7/24");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub struct Ipv4Net {
+    addr: Ipv4Addr,
+    prefix_len: u8,
+}
+
+/// An IPv6 network: an address together with the length of its network prefix.
+///
+/// The address is stored as given, so `2001:db8::1/32` keeps the host bits
+/// of `2001:db8::1`. Use [`Ipv6Net::network`] or [`Ipv6Net::trunc`] to clear
+/// them.
+///
+/// # Textual representation
+///
+/// `Ipv6Net` provides a [`FromStr`] implementation for CIDR notation: an
+/// [`Ipv6Addr`] followed by `/` and the prefix length in decimal, such as
+/// `2001:db8::/32`. The prefix length can't have a leading zero.
+///
+/// [`FromStr`]: crate::str::FromStr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_network)]
+/// use std::net::{Ipv6Addr, Ipv6Net};
+///
+/// let net: Ipv6Net = "2001:db8::1/32".parse().unwrap();
+/// assert_eq!(net.network(), Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0));
+/// assert!(net.contains(&"2001:db8:ffff::1".parse().unwrap()));
+/// assert!(!net.contains(&"2001:db9::1".parse().unwrap()));
+/// assert_eq!(net.to_string(), "2001:db8::1/32");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub struct Ipv6Net {
+    addr: Ipv6Addr,
+    prefix_len: u8,
+}
+
+/// An error returned when creating a network with a prefix length longer than
+/// its address.
+///
+/// This error is returned by [`Ipv4Net::new`], [`Ipv6Net::new`] and
+/// [`IpNet::new`], and by the `subnets` methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub struct PrefixLenError(());
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl fmt::Display for PrefixLenError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("invalid IP prefix length")
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl Error for PrefixLenError {}
+
+// Returns the mask with the top `prefix_len` bits of an integer set.
+macro_rules! prefix_mask {
+    ($t:ty, $prefix_len:expr) => {
+        <$t>::MAX.checked_shl(<$t>::BITS - $prefix_len as u32).unwrap_or(0)
+    };
+}
+
+impl IpNet {
+    /// Creates a new IP network from an address and a prefix length.
+    ///
+    /// Returns an error if `prefix_len` is longer than the address, that is,
+    /// more than 32 bits for IPv4 or 128 bits for IPv6.
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<IpNet, PrefixLenError> {
+        match addr {
+            IpAddr::V4(a) => Ipv4Net::new(a, prefix_len).map(IpNet::V4),
+            IpAddr::V6(a) => Ipv6Net::new(a, prefix_len).map(IpNet::V6),
+        }
+    }
+
+    /// Returns the address of this network, as it was given.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn addr(&self) -> IpAddr {
+        match self {
+            IpNet::V4(n) => IpAddr::V4(n.addr()),
+            IpNet::V6(n) => IpAddr::V6(n.addr()),
+        }
+    }
+
+    /// Returns the length of the network prefix, in bits.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn prefix_len(&self) -> u8 {
+        match self {
+            IpNet::V4(n) => n.prefix_len(),
+            IpNet::V6(n) => n.prefix_len(),
+        }
+    }
+
+    /// Returns the length of the address in bits: 32 for IPv4 and 128 for IPv6.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn max_prefix_len(&self) -> u8 {
+        match self {
+            IpNet::V4(n) => n.max_prefix_len(),
+            IpNet::V6(n) => n.max_prefix_len(),
+        }
+    }
+
+    /// Returns the network mask, with the prefix bits set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn netmask(&self) -> IpAddr {
+        match self {
+            IpNet::V4(n) => IpAddr::V4(n.netmask()),
+            IpNet::V6(n) => IpAddr::V6(n.netmask()),
+        }
+    }
+
+    /// Returns the host mask, with the bits after the prefix set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn hostmask(&self) -> IpAddr {
+        match self {
+            IpNet::V4(n) => IpAddr::V4(n.hostmask()),
+            IpNet::V6(n) => IpAddr::V6(n.hostmask()),
+        }
+    }
+
+    /// Returns the first address of the network, with all host bits cleared.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn network(&self) -> IpAddr {
+        match self {
+            IpNet::V4(n) => IpAddr::V4(n.network()),
+            IpNet::V6(n) => IpAddr::V6(n.network()),
+        }
+    }
+
+    /// Returns the last address of the network, with all host bits set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn broadcast(&self) -> IpAddr {
+        match self {
+            IpNet::V4(n) => IpAddr::V4(n.broadcast()),
+            IpNet::V6(n) => IpAddr::V6(n.broadcast()),
+        }
+    }
+
+    /// Returns the same network with the host bits of its address cleared.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn trunc(&self) -> IpNet {
+        match self {
+            IpNet::V4(n) => IpNet::V4(n.trunc()),
+            IpNet::V6(n) => IpNet::V6(n.trunc()),
+        }
+    }
+
+    /// Returns the network with a prefix one bit shorter, which contains this
+    /// network and its sibling, or `None` if the prefix length is already 0.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn supernet(&self) -> Option<IpNet> {
+        match self {
+            IpNet::V4(n) => n.supernet().map(IpNet::V4),
+            IpNet::V6(n) => n.supernet().map(IpNet::V6),
+        }
+    }
+
+    /// Returns [`true`] if `addr` is inside this network.
+    ///
+    /// Addresses of the other family are never contained, so `::ffff:10.0.0.1`
+    /// is not inside `10.0.0.0/8`.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn contains(&self, addr: &IpAddr) -> bool {
+        match (self, addr) {
+            (IpNet::V4(n), IpAddr::V4(a)) => n.contains(a),
+            (IpNet::V6(n), IpAddr::V6(a)) => n.contains(a),
+            _ => false,
+        }
+    }
+
+    /// Returns [`true`] if every address of `other` is inside this network.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn contains_net(&self, other: &IpNet) -> bool {
+        match (self, other) {
+            (IpNet::V4(n), IpNet::V4(o)) => n.contains_net(o),
+            (IpNet::V6(n), IpNet::V6(o)) => n.contains_net(o),
+            _ => false,
+        }
+    }
+
+    /// Returns [`true`] if this is an [`IPv4` network](Ipv4Net), and [`false`] otherwise.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn is_ipv4(&self) -> bool {
+        matches!(self, IpNet::V4(_))
+    }
+
+    /// Returns [`true`] if this is an [`IPv6` network](Ipv6Net), and [`false`] otherwise.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn is_ipv6(&self) -> bool {
+        matches!(self, IpNet::V6(_))
+    }
+}
+
+impl Ipv4Net {
+    /// Creates a new IPv4 network from an address and a prefix length.
+    ///
+    /// Returns an error if `prefix_len` is greater than 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::{Ipv4Addr, Ipv4Net};
+    ///
+    /// assert!(Ipv4Net::new(Ipv4Addr::new(10, 0, 0, 0), 8).is_ok());
+    /// assert!(Ipv4Net::new(Ipv4Addr::new(10, 0, 0, 0), 33).is_err());
+    /// ```
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn new(addr: Ipv4Addr, prefix_len: u8) -> Result<Ipv4Net, PrefixLenError> {
+        if prefix_len > 32 {
+            return Err(PrefixLenError(()));
+        }
+        Ok(Ipv4Net { addr, prefix_len })
+    }
+
+    /// Returns the address of this network, as it was given.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn addr(&self) -> Ipv4Addr {
+        self.addr
+    }
+
+    /// Returns the length of the network prefix, in bits.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the length of an IPv4 address in bits, which is 32.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn max_prefix_len(&self) -> u8 {
+        32
+    }
+
+    fn mask(&self) -> u32 {
+        prefix_mask!(u32, self.prefix_len)
+    }
+
+    /// Returns the network mask, with the prefix bits set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn netmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from(self.mask())
+    }
+
+    /// Returns the host mask, with the bits after the prefix set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn hostmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from(!self.mask())
+    }
+
+    /// Returns the first address of the network, with all host bits cleared.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn network(&self) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.addr) & self.mask())
+    }
+
+    /// Returns the broadcast address of the network, with all host bits set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn broadcast(&self) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.addr) | !self.mask())
+    }
+
+    /// Returns the same network with the host bits of its address cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "192.168.1.7/24".parse().unwrap();
+    /// assert_eq!(net.trunc().to_string(), "192.168.1.0/24");
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn trunc(&self) -> Ipv4Net {
+        Ipv4Net { addr: self.network(), prefix_len: self.prefix_len }
+    }
+
+    /// Returns the network with a prefix one bit shorter, which contains this
+    /// network and its sibling, or `None` if the prefix length is already 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "10.1.0.0/16".parse().unwrap();
+    /// assert_eq!(net.supernet(), Some("10.0.0.0/15".parse().unwrap()));
+    /// assert_eq!("0.0.0.0/0".parse::<Ipv4Net>().unwrap().supernet(), None);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn supernet(&self) -> Option<Ipv4Net> {
+        let prefix_len = self.prefix_len.checked_sub(1)?;
+        Some(Ipv4Net { addr: self.addr, prefix_len }.trunc())
+    }
+
+    /// Returns [`true`] if `addr` is inside this network.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn contains(&self, addr: &Ipv4Addr) -> bool {
+        (u32::from(*addr) ^ u32::from(self.addr)) & self.mask() == 0
+    }
+
+    /// Returns [`true`] if every address of `other` is inside this network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "10.0.0.0/8".parse().unwrap();
+    /// assert!(net.contains_net(&"10.20.0.0/16".parse().unwrap()));
+    /// assert!(!net.contains_net(&"0.0.0.0/0".parse().unwrap()));
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn contains_net(&self, other: &Ipv4Net) -> bool {
+        other.prefix_len >= self.prefix_len && self.contains(&other.addr)
+    }
+
+    /// Returns an iterator over the usable host addresses of the network, in
+    /// ascending order.
+    ///
+    /// The network and broadcast addresses are skipped, except in `/31`
+    /// point-to-point networks ([IETF RFC 3021]) and `/32` single-host
+    /// networks, where every address is a host.
+    ///
+    /// [IETF RFC 3021]: https://tools.ietf.org/html/rfc3021
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::{Ipv4Addr, Ipv4Net};
+    ///
+    /// let net: Ipv4Net = "10.0.0.0/30".parse().unwrap();
+    /// let hosts: Vec<Ipv4Addr> = net.hosts().collect();
+    /// assert_eq!(hosts, [Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 2)]);
+    /// ```
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn hosts(&self) -> Ipv4Hosts {
+        let (first, last) = (u32::from(self.network()), u32::from(self.broadcast()));
+        let range = if self.prefix_len < 31 { first + 1..=last - 1 } else { first..=last };
+        Ipv4Hosts { range }
+    }
+
+    /// Returns an iterator over the subnets of this network with a prefix of
+    /// `new_prefix_len` bits, in ascending order.
+    ///
+    /// Returns an error if `new_prefix_len` is shorter than the prefix of this
+    /// network or longer than 32 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "10.0.0.0/23".parse().unwrap();
+    /// let subnets: Vec<String> = net.subnets(25).unwrap().map(|n| n.to_string()).collect();
+    /// assert_eq!(subnets, ["10.0.0.0/25", "10.0.0.128/25", "10.0.1.0/25", "10.0.1.128/25"]);
+    /// assert!(net.subnets(22).is_err());
+    /// ```
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn subnets(&self, new_prefix_len: u8) -> Result<Ipv4Subnets, PrefixLenError> {
+        if new_prefix_len < self.prefix_len || new_prefix_len > 32 {
+            return Err(PrefixLenError(()));
+        }
+        let count_bits = (new_prefix_len - self.prefix_len) as u32;
+        Ok(Ipv4Subnets {
+            base: u32::from(self.network()),
+            prefix_len: new_prefix_len,
+            indices: 0..=!prefix_mask!(u32, u32::BITS - count_bits),
+        })
+    }
+}
+
+impl Ipv6Net {
+    /// Creates a new IPv6 network from an address and a prefix length.
+    ///
+    /// Returns an error if `prefix_len` is greater than 128.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::{Ipv6Addr, Ipv6Net};
+    ///
+    /// assert!(Ipv6Net::new(Ipv6Addr::LOCALHOST, 128).is_ok());
+    /// assert!(Ipv6Net::new(Ipv6Addr::LOCALHOST, 129).is_err());
+    /// ```
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn new(addr: Ipv6Addr, prefix_len: u8) -> Result<Ipv6Net, PrefixLenError> {
+        if prefix_len > 128 {
+            return Err(PrefixLenError(()));
+        }
+        Ok(Ipv6Net { addr, prefix_len })
+    }
+
+    /// Returns the address of this network, as it was given.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn addr(&self) -> Ipv6Addr {
+        self.addr
+    }
+
+    /// Returns the length of the network prefix, in bits.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the length of an IPv6 address in bits, which is 128.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub const fn max_prefix_len(&self) -> u8 {
+        128
+    }
+
+    fn mask(&self) -> u128 {
+        prefix_mask!(u128, self.prefix_len)
+    }
+
+    /// Returns the network mask, with the prefix bits set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn netmask(&self) -> Ipv6Addr {
+        Ipv6Addr::from(self.mask())
+    }
+
+    /// Returns the host mask, with the bits after the prefix set.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn hostmask(&self) -> Ipv6Addr {
+        Ipv6Addr::from(!self.mask())
+    }
+
+    /// Returns the first address of the network, with all host bits cleared.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn network(&self) -> Ipv6Addr {
+        Ipv6Addr::from(u128::from(self.addr) & self.mask())
+    }
+
+    /// Returns the last address of the network, with all host bits set.
+    ///
+    /// IPv6 has no broadcast addresses; this is named after its IPv4
+    /// counterpart for symmetry.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn broadcast(&self) -> Ipv6Addr {
+        Ipv6Addr::from(u128::from(self.addr) | !self.mask())
+    }
+
+    /// Returns the same network with the host bits of its address cleared.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn trunc(&self) -> Ipv6Net {
+        Ipv6Net { addr: self.network(), prefix_len: self.prefix_len }
+    }
+
+    /// Returns the network with a prefix one bit shorter, which contains this
+    /// network and its sibling, or `None` if the prefix length is already 0.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn supernet(&self) -> Option<Ipv6Net> {
+        let prefix_len = self.prefix_len.checked_sub(1)?;
+        Some(Ipv6Net { addr: self.addr, prefix_len }.trunc())
+    }
+
+    /// Returns [`true`] if `addr` is inside this network.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn contains(&self, addr: &Ipv6Addr) -> bool {
+        (u128::from(*addr) ^ u128::from(self.addr)) & self.mask() == 0
+    }
+
+    /// Returns [`true`] if every address of `other` is inside this network.
+    #[must_use]
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn contains_net(&self, other: &Ipv6Net) -> bool {
+        other.prefix_len >= self.prefix_len && self.contains(&other.addr)
+    }
+
+    /// Returns an iterator over all addresses of the network, in ascending
+    /// order.
+    ///
+    /// Unlike [`Ipv4Net::hosts`], no address is skipped, as IPv6 has no
+    /// broadcast addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::Ipv6Net;
+    ///
+    /// let net: Ipv6Net = "2001:db8::/126".parse().unwrap();
+    /// assert_eq!(net.hosts().count(), 4);
+    /// assert_eq!(net.hosts().last(), Some("2001:db8::3".parse().unwrap()));
+    /// ```
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn hosts(&self) -> Ipv6Hosts {
+        Ipv6Hosts { range: u128::from(self.network())..=u128::from(self.broadcast()) }
+    }
+
+    /// Returns an iterator over the subnets of this network with a prefix of
+    /// `new_prefix_len` bits, in ascending order.
+    ///
+    /// Returns an error if `new_prefix_len` is shorter than the prefix of this
+    /// network or longer than 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_network)]
+    /// use std::net::Ipv6Net;
+    ///
+    /// let net: Ipv6Net = "2001:db8::/32".parse().unwrap();
+    /// let mut subnets = net.subnets(48).unwrap();
+    /// assert_eq!(subnets.next(), Some("2001:db8::/48".parse().unwrap()));
+    /// assert_eq!(subnets.next(), Some("2001:db8:1::/48".parse().unwrap()));
+    /// assert_eq!(subnets.next_back(), Some("2001:db8:ffff::/48".parse().unwrap()));
+    /// ```
+    #[unstable(feature = "ip_network", issue = "none")]
+    pub fn subnets(&self, new_prefix_len: u8) -> Result<Ipv6Subnets, PrefixLenError> {
+        if new_prefix_len < self.prefix_len || new_prefix_len > 128 {
+            return Err(PrefixLenError(()));
+        }
+        let count_bits = (new_prefix_len - self.prefix_len) as u32;
+        Ok(Ipv6Subnets {
+            base: u128::from(self.network()),
+            prefix_len: new_prefix_len,
+            indices: 0..=!prefix_mask!(u128, u128::BITS - count_bits),
+        })
+    }
+}
+
+/// An iterator over the host addresses of an [`Ipv4Net`].
+///
+/// This `struct` is created by [`Ipv4Net::hosts`]. See its documentation for
+/// more.
+#[derive(Clone, Debug)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub struct Ipv4Hosts {
+    range: RangeInclusive<u32>,
+}
+
+/// An iterator over the addresses of an [`Ipv6Net`].
+///
+/// This `struct` is created by [`Ipv6Net::hosts`]. See its documentation for
+/// more.
+#[derive(Clone, Debug)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub struct Ipv6Hosts {
+    range: RangeInclusive<u128>,
+}
+
+/// An iterator over the subnets of an [`Ipv4Net`].
+///
+/// This `struct` is created by [`Ipv4Net::subnets`]. See its documentation
+/// for more.
+#[derive(Clone, Debug)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub struct Ipv4Subnets {
+    base: u32,
+    prefix_len: u8,
+    indices: RangeInclusive<u32>,
+}
+
+/// An iterator over the subnets of an [`Ipv6Net`].
+///
+/// This `struct` is created by [`Ipv6Net::subnets`]. See its documentation
+/// for more.
+#[derive(Clone, Debug)]
+#[unstable(feature = "ip_network", issue = "none")]
+pub struct Ipv6Subnets {
+    base: u128,
+    prefix_len: u8,
+    indices: RangeInclusive<u128>,
+}
+
+impl Ipv4Subnets {
+    fn subnet(&self, index: u32) -> Ipv4Net {
+        let shift = u32::BITS - self.prefix_len as u32;
+        let addr = self.base | index.checked_shl(shift).unwrap_or(0);
+        Ipv4Net { addr: Ipv4Addr::from(addr), prefix_len: self.prefix_len }
+    }
+}
+
+impl Ipv6Subnets {
+    fn subnet(&self, index: u128) -> Ipv6Net {
+        let shift = u128::BITS - self.prefix_len as u32;
+        let addr = self.base | index.checked_shl(shift).unwrap_or(0);
+        Ipv6Net { addr: Ipv6Addr::from(addr), prefix_len: self.prefix_len }
+    }
+}
+
+macro_rules! impl_net_iterator {
+    ($iter:ty, $item:ty, $range:ident, |$this:ident, $x:ident| $map:expr) => {
+        #[unstable(feature = "ip_network", issue = "none")]
+        impl Iterator for $iter {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                let $x = self.$range.next()?;
+                let $this = &*self;
+                Some($map)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.$range.size_hint()
+            }
+        }
+
+        #[unstable(feature = "ip_network", issue = "none")]
+        impl DoubleEndedIterator for $iter {
+            fn next_back(&mut self) -> Option<$item> {
+                let $x = self.$range.next_back()?;
+                let $this = &*self;
+                Some($map)
+            }
+        }
+
+        #[unstable(feature = "ip_network", issue = "none")]
+        impl FusedIterator for $iter {}
+    };
+}
+
+impl_net_iterator!(Ipv4Hosts, Ipv4Addr, range, |_this, x| Ipv4Addr::from(x));
+impl_net_iterator!(Ipv6Hosts, Ipv6Addr, range, |_this, x| Ipv6Addr::from(x));
+impl_net_iterator!(Ipv4Subnets, Ipv4Net, indices, |this, x| this.subnet(x));
+impl_net_iterator!(Ipv6Subnets, Ipv6Net, indices, |this, x| this.subnet(x));
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl fmt::Display for IpNet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpNet::V4(n) => n.fmt(fmt),
+            IpNet::V6(n) => n.fmt(fmt),
+        }
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl fmt::Debug for IpNet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl fmt::Display for Ipv4Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Fast Path: if there's no alignment stuff, write directly to the buffer
+        if fmt.precision().is_none() && fmt.width().is_none() {
+            write!(fmt, "{}/{}", self.addr, self.prefix_len)
+        } else {
+            const IPV4_NET_BUF_LEN: usize = 15 + 3; // Long enough for "255.255.255.255/32"
+            let mut buf = [0u8; IPV4_NET_BUF_LEN];
+            let mut buf_slice = &mut buf[..];
+
+            // Note: The call to write should never fail, hence the unwrap
+            write!(buf_slice, "{}/{}", self.addr, self.prefix_len).unwrap();
+            let len = IPV4_NET_BUF_LEN - buf_slice.len();
+
+            // This unsafe is OK because we know what is being written to the buffer
+            let buf = unsafe { crate::str::from_utf8_unchecked(&buf[..len]) };
+            fmt.pad(buf)
+        }
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl fmt::Debug for Ipv4Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl fmt::Display for Ipv6Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Fast Path: if there's no alignment stuff, write directly to the buffer
+        if fmt.precision().is_none() && fmt.width().is_none() {
+            write!(fmt, "{}/{}", self.addr, self.prefix_len)
+        } else {
+            // Long enough for "ffff:ffff:ffff:ffff:ffff:ffff:255.255.255.255/128"
+            const IPV6_NET_BUF_LEN: usize = 45 + 4;
+            let mut buf = [0u8; IPV6_NET_BUF_LEN];
+            let mut buf_slice = &mut buf[..];
+
+            // Note: The call to write should never fail, hence the unwrap
+            write!(buf_slice, "{}/{}", self.addr, self.prefix_len).unwrap();
+            let len = IPV6_NET_BUF_LEN - buf_slice.len();
+
+            // This unsafe is OK because we know what is being written to the buffer
+            let buf = unsafe { crate::str::from_utf8_unchecked(&buf[..len]) };
+            fmt.pad(buf)
+        }
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl fmt::Debug for Ipv6Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl From<Ipv4Net> for IpNet {
+    /// Copies this network to a new `IpNet::V4`.
+    fn from(net: Ipv4Net) -> IpNet {
+        IpNet::V4(net)
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl From<Ipv6Net> for IpNet {
+    /// Copies this network to a new `IpNet::V6`.
+    fn from(net: Ipv6Net) -> IpNet {
+        IpNet::V6(net)
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl From<Ipv4Addr> for Ipv4Net {
+    /// Creates a `/32` network containing only `addr`.
+    fn from(addr: Ipv4Addr) -> Ipv4Net {
+        Ipv4Net { addr, prefix_len: 32 }
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl From<Ipv6Addr> for Ipv6Net {
+    /// Creates a `/128` network containing only `addr`.
+    fn from(addr: Ipv6Addr) -> Ipv6Net {
+        Ipv6Net { addr, prefix_len: 128 }
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl From<IpAddr> for IpNet {
+    /// Creates a network containing only `addr`, with the longest prefix of
+    /// its family.
+    fn from(addr: IpAddr) -> IpNet {
+        match addr {
+            IpAddr::V4(a) => IpNet::V4(a.into()),
+            IpAddr::V6(a) => IpNet::V6(a.into()),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::net::*;
+
+fn v4(s: &str) -> Ipv4Net {
+    s.parse().unwrap()
+}
+
+fn v6(s: &str) -> Ipv6Net {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_from_str_ip_net() {
+    assert_eq!(Ok(Ipv4Net::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()), "10.0.0.0/8".parse());
+    assert_eq!(Ok(Ipv4Net::new(Ipv4Addr::UNSPECIFIED, 0).unwrap()), "0.0.0.0/0".parse());
+    assert_eq!(Ok(Ipv6Net::new(Ipv6Addr::LOCALHOST, 128).unwrap()), "::1/128".parse());
+    assert_eq!(Ok(IpNet::V4(v4("192.168.0.1/32"))), "192.168.0.1/32".parse());
+    assert_eq!(Ok(IpNet::V6(v6("2001:db8::/32"))), "2001:db8::/32".parse());
+
+    // prefix too long
+    assert!("10.0.0.0/33".parse::<Ipv4Net>().is_err());
+    assert!("::/129".parse::<Ipv6Net>().is_err());
+    assert!("::/1000".parse::<IpNet>().is_err());
+    // leading zero in prefix
+    assert!("10.0.0.0/08".parse::<Ipv4Net>().is_err());
+    // missing prefix
+    assert!("10.0.0.0".parse::<Ipv4Net>().is_err());
+    assert!("10.0.0.0/".parse::<Ipv4Net>().is_err());
+    // wrong family
+    assert!("::1/128".parse::<Ipv4Net>().is_err());
+    assert!("10.0.0.0/8".parse::<Ipv6Net>().is_err());
+    // trailing garbage
+    assert!("10.0.0.0/8/8".parse::<IpNet>().is_err());
+}
+
+#[test]
+fn ipv4_net_properties() {
+    let net = v4("172.16.5.4/12");
+    assert_eq!(net.addr(), Ipv4Addr::new(172, 16, 5, 4));
+    assert_eq!(net.prefix_len(), 12);
+    assert_eq!(net.max_prefix_len(), 32);
+    assert_eq!(net.netmask(), Ipv4Addr::new(255, 240, 0, 0));
+    assert_eq!(net.hostmask(), Ipv4Addr::new(0, 15, 255, 255));
+    assert_eq!(net.network(), Ipv4Addr::new(172, 16, 0, 0));
+    assert_eq!(net.broadcast(), Ipv4Addr::new(172, 31, 255, 255));
+    assert_eq!(net.trunc(), v4("172.16.0.0/12"));
+
+    let all = v4("1.2.3.4/0");
+    assert_eq!(all.netmask(), Ipv4Addr::UNSPECIFIED);
+    assert_eq!(all.broadcast(), Ipv4Addr::BROADCAST);
+    assert!(all.contains(&Ipv4Addr::BROADCAST));
+
+    assert!(net.contains(&Ipv4Addr::new(172, 31, 0, 1)));
+    assert!(!net.contains(&Ipv4Addr::new(172, 32, 0, 1)));
+    assert!(net.contains_net(&v4("172.20.0.0/16")));
+    assert!(net.contains_net(&net));
+    assert!(!net.contains_net(&v4("172.0.0.0/8")));
+    assert!(!net.contains_net(&v4("10.0.0.0/16")));
+}
+
+#[test]
+fn ipv6_net_properties() {
+    let net = v6("2001:db8:1234::1/48");
+    assert_eq!(net.network(), "2001:db8:1234::".parse::<Ipv6Addr>().unwrap());
+    assert_eq!(
+        net.broadcast(),
+        "2001:db8:1234:ffff:ffff:ffff:ffff:ffff".parse::<Ipv6Addr>().unwrap()
+    );
+    assert_eq!(net.netmask(), "ffff:ffff:ffff::".parse::<Ipv6Addr>().unwrap());
+    assert!(net.contains(&"2001:db8:1234:5::9".parse().unwrap()));
+    assert!(!net.contains(&"2001:db8:1235::".parse().unwrap()));
+    assert!(v6("::/0").contains_net(&net));
+}
+
+#[test]
+fn ip_net_mixed_families() {
+    let v4net: IpNet = "10.0.0.0/8".parse().unwrap();
+    let v6net: IpNet = "::ffff:0:0/96".parse().unwrap();
+    let mapped: IpAddr = "::ffff:10.0.0.1".parse().unwrap();
+    assert!(!v4net.contains(&mapped));
+    assert!(v6net.contains(&mapped));
+    assert!(!v4net.contains_net(&v6net));
+    assert!(v4net.is_ipv4() && v6net.is_ipv6());
+    assert_eq!(IpNet::from(IpAddr::V4(Ipv4Addr::LOCALHOST)).prefix_len(), 32);
+    assert_eq!(IpNet::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 129), Err(PrefixLenError(())));
+}
+
+#[test]
+fn hosts() {
+    let hosts: Vec<_> = v4("10.0.0.0/29").hosts().collect();
+    assert_eq!(hosts.len(), 6);
+    assert_eq!(hosts[0], Ipv4Addr::new(10, 0, 0, 1));
+    assert_eq!(hosts[5], Ipv4Addr::new(10, 0, 0, 6));
+
+    assert_eq!(v4("10.0.0.0/31").hosts().count(), 2);
+    assert_eq!(v4("10.0.0.7/32").hosts().collect::<Vec<_>>(), [Ipv4Addr::new(10, 0, 0, 7)]);
+    assert_eq!(v4("0.0.0.0/0").hosts().next_back(), Some(Ipv4Addr::new(255, 255, 255, 254)));
+    assert_eq!(v4("10.0.0.0/24").hosts().size_hint(), (254, Some(254)));
+
+    let mut hosts = v6("::/0").hosts();
+    assert_eq!(hosts.next(), Some(Ipv6Addr::UNSPECIFIED));
+    assert_eq!(hosts.next_back(), Some(Ipv6Addr::from(u128::MAX)));
+    assert_eq!(v6("fe80::/120").hosts().count(), 256);
+}
+
+#[test]
+fn subnets_and_supernets() {
+    let subnets: Vec<_> = v4("10.0.0.0/8").subnets(10).unwrap().collect();
+    assert_eq!(
+        subnets,
+        [v4("10.0.0.0/10"), v4("10.64.0.0/10"), v4("10.128.0.0/10"), v4("10.192.0.0/10")]
+    );
+    assert_eq!(v4("10.1.2.3/8").subnets(8).unwrap().collect::<Vec<_>>(), [v4("10.0.0.0/8")]);
+    assert_eq!(v4("0.0.0.0/0").subnets(32).unwrap().next_back(), Some(v4("255.255.255.255/32")));
+    assert_eq!(v4("0.0.0.0/0").subnets(0).unwrap().count(), 1);
+    assert!(v4("10.0.0.0/8").subnets(7).is_err());
+    assert!(v4("10.0.0.0/8").subnets(33).is_err());
+
+    let mut subnets = v6("::/0").subnets(128).unwrap();
+    assert_eq!(subnets.next(), Some(v6("::/128")));
+    assert_eq!(subnets.next_back(), Some(v6("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128")));
+    assert_eq!(v6("2001:db8::/32").subnets(34).unwrap().nth(3), Some(v6("2001:db8:c000::/34")));
+
+    assert_eq!(v4("10.1.0.0/16").supernet(), Some(v4("10.0.0.0/15")));
+    assert_eq!(v4("0.0.0.0/0").supernet(), None);
+    assert_eq!(v6("2001:db8:8000::/33").supernet(), Some(v6("2001:db8::/32")));
+}
+
+#[test]
+fn ip_net_to_string() {
+    assert_eq!(v4("10.0.0.0/8").to_string(), "10.0.0.0/8");
+    assert_eq!(format!("{:>14}", v4("10.0.0.0/8")), "    10.0.0.0/8");
+    assert_eq!(format!("{:?}", IpNet::V6(v6("2001:db8::/32"))), "2001:db8::/32");
+    assert_eq!(format!("{:<16}|", v6("::1/128")), "::1/128         |");
+}
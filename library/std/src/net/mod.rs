@@ -9,8 +9,12 @@
 //! * [`UdpSocket`] provides functionality for communication over UDP
 //! * [`IpAddr`] represents IP addresses of either IPv4 or IPv6; [`Ipv4Addr`] and
 //!   [`Ipv6Addr`] are respectively IPv4 and IPv6 addresses
+//! * [`IpNet`] represents IP networks (an address and a prefix length) of either IPv4 or
+//!   IPv6; [`Ipv4Net`] and [`Ipv6Net`] are respectively IPv4 and IPv6 networks
 //! * [`SocketAddr`] represents socket addresses of either IPv4 or IPv6; [`SocketAddrV4`]
 //!   and [`SocketAddrV6`] are respectively IPv4 and IPv6 socket addresses
+//! * [`Resolver`] resolves host names with finer control than [`ToSocketAddrs`], and maps
+//!   addresses back to host names
 //! * [`ToSocketAddrs`] is a trait that used for generic address resolution when interacting
 //!   with networking objects like [`TcpListener`], [`TcpStream`] or [`UdpSocket`]
 //! * Other types are return or parameter types for various methods in this module
@@ -27,18 +31,28 @@
 pub use self::addr::{SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::ip::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
+#[unstable(feature = "ip_network", issue = "none")]
+pub use self::ipnet::{
+    IpNet, Ipv4Hosts, Ipv4Net, Ipv4Subnets, Ipv6Hosts, Ipv6Net, Ipv6Subnets, PrefixLenError,
+};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::parser::AddrParseError;
+#[unstable(feature = "net_resolver", issue = "none")]
+pub use self::resolver::{Lookup, Resolver};
 #[unstable(feature = "tcplistener_into_incoming", issue = "88339")]
 pub use self::tcp::IntoIncoming;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::tcp::{Incoming, TcpListener, TcpStream};
+#[unstable(feature = "udp_socket_options", issue = "none")]
+pub use self::udp::RecvMsg;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::udp::UdpSocket;
 
 mod addr;
 mod ip;
+mod ipnet;
 mod parser;
+mod resolver;
 mod tcp;
 #[cfg(test)]
 mod test;
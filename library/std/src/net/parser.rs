@@ -1,4 +1,5 @@
-//! A private parser implementation of IPv4, IPv6, and socket addresses.
+//! A private parser implementation of IPv4, IPv6, and socket addresses, and of
+//! IP networks in CIDR notation.
 //!
 //! This module is "publicly exported" through the `FromStr` implementations
 //! below.
@@ -9,7 +10,9 @@
 use crate::convert::TryInto as _;
 use crate::error::Error;
 use crate::fmt;
-use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    IpAddr, IpNet, Ipv4Addr, Ipv4Net, Ipv6Addr, Ipv6Net, SocketAddr, SocketAddrV4, SocketAddrV6,
+};
 use crate::str::FromStr;
 
 trait ReadNumberHelper: crate::marker::Sized {
@@ -272,6 +275,37 @@ fn read_socket_addr(&mut self) -> Option<SocketAddr> {
             .map(SocketAddr::V4)
             .or_else(|| self.read_socket_addr_v6().map(SocketAddr::V6))
     }
+
+    /// Read a `/` followed by a prefix length in base 10.
+    fn read_prefix_len(&mut self) -> Option<u8> {
+        self.read_atomically(|p| {
+            p.read_given_char('/')?;
+            p.read_number(10, Some(3), false)
+        })
+    }
+
+    /// Read an IPv4 network in CIDR notation.
+    fn read_ipv4_net(&mut self) -> Option<Ipv4Net> {
+        self.read_atomically(|p| {
+            let ip = p.read_ipv4_addr()?;
+            let prefix_len = p.read_prefix_len()?;
+            Ipv4Net::new(ip, prefix_len).ok()
+        })
+    }
+
+    /// Read an IPv6 network in CIDR notation.
+    fn read_ipv6_net(&mut self) -> Option<Ipv6Net> {
+        self.read_atomically(|p| {
+            let ip = p.read_ipv6_addr()?;
+            let prefix_len = p.read_prefix_len()?;
+            Ipv6Net::new(ip, prefix_len).ok()
+        })
+    }
+
+    /// Read an IP network, either IPv4 or IPv6.
+    fn read_ip_net(&mut self) -> Option<IpNet> {
+        self.read_ipv4_net().map(IpNet::V4).or_else(move || self.read_ipv6_net().map(IpNet::V6))
+    }
 }
 
 #[stable(feature = "ip_addr", since = "1.7.0")]
@@ -327,11 +361,36 @@ fn from_str(s: &str) -> Result<SocketAddr, AddrParseError> {
     }
 }
 
+#[unstable(feature = "ip_network", issue = "none")]
+impl FromStr for IpNet {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<IpNet, AddrParseError> {
+        Parser::new(s).parse_with(|p| p.read_ip_net())
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl FromStr for Ipv4Net {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<Ipv4Net, AddrParseError> {
+        Parser::new(s).parse_with(|p| p.read_ipv4_net())
+    }
+}
+
+#[unstable(feature = "ip_network", issue = "none")]
+impl FromStr for Ipv6Net {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<Ipv6Net, AddrParseError> {
+        Parser::new(s).parse_with(|p| p.read_ipv6_net())
+    }
+}
+
 /// An error which can be returned when parsing an IP address or a socket address.
 ///
 /// This error is used as the error type for the [`FromStr`] implementation for
 /// [`IpAddr`], [`Ipv4Addr`], [`Ipv6Addr`], [`SocketAddr`], [`SocketAddrV4`], and
-/// [`SocketAddrV6`].
+/// [`SocketAddrV6`], as well as for the IP network types [`IpNet`], [`Ipv4Net`] and
+/// [`Ipv6Net`].
 ///
 /// # Potential causes
 ///
@@ -0,0 +1,209 @@
+#[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
+mod tests;
+
+use crate::io;
+use crate::net::{IpAddr, SocketAddr};
+use crate::slice;
+use crate::sys_common::net as net_imp;
+use crate::vec;
+
+/// Options and flags which can be used to configure how host names are
+/// resolved.
+///
+/// [`ToSocketAddrs`] resolves names with the system defaults. A `Resolver`
+/// allows restricting the lookup to one address family, asking the system to
+/// skip address families the host has no configured address for, and
+/// requesting the canonical name of the host. It can also perform reverse
+/// lookups, mapping an address back to a host name.
+///
+/// Generally speaking, when using `Resolver`, you'll first call [`new`], then
+/// chain calls to methods to set each option, then call [`lookup`] or
+/// [`lookup_addr`].
+///
+/// [`ToSocketAddrs`]: crate::net::ToSocketAddrs
+/// [`new`]: Resolver::new
+/// [`lookup`]: Resolver::lookup
+/// [`lookup_addr`]: Resolver::lookup_addr
+///
+/// # Examples
+///
+/// Resolve the IPv4 addresses of `localhost`, along with its canonical name:
+///
+/// ```no_run
+/// #![feature(net_resolver)]
+/// use std::net::Resolver;
+///
+/// let lookup = Resolver::new().ipv6(false).canonical_name(true).lookup("localhost", 80)?;
+/// println!("canonical name: {:?}", lookup.canonical_name());
+/// for addr in lookup {
+///     assert!(addr.is_ipv4());
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+#[unstable(feature = "net_resolver", issue = "none")]
+pub struct Resolver {
+    pub(crate) ipv4: bool,
+    pub(crate) ipv6: bool,
+    pub(crate) addr_config: bool,
+    pub(crate) canonical_name: bool,
+}
+
+impl Resolver {
+    /// Creates a blank new set of options ready for configuration.
+    ///
+    /// Both IPv4 and IPv6 addresses are requested, and all flags are unset.
+    #[unstable(feature = "net_resolver", issue = "none")]
+    #[must_use]
+    pub fn new() -> Resolver {
+        Resolver { ipv4: true, ipv6: true, addr_config: false, canonical_name: false }
+    }
+
+    /// Sets whether IPv4 addresses should be returned.
+    ///
+    /// This option defaults to `true`.
+    #[unstable(feature = "net_resolver", issue = "none")]
+    pub fn ipv4(&mut self, ipv4: bool) -> &mut Resolver {
+        self.ipv4 = ipv4;
+        self
+    }
+
+    /// Sets whether IPv6 addresses should be returned.
+    ///
+    /// This option defaults to `true`.
+    #[unstable(feature = "net_resolver", issue = "none")]
+    pub fn ipv6(&mut self, ipv6: bool) -> &mut Resolver {
+        self.ipv6 = ipv6;
+        self
+    }
+
+    /// Sets whether only the address families configured on the local system
+    /// should be returned.
+    ///
+    /// This corresponds to the `AI_ADDRCONFIG` flag of `getaddrinfo`: IPv4
+    /// addresses are only returned if the host has an IPv4 address configured,
+    /// and likewise for IPv6. The loopback address does not count as
+    /// configured.
+    ///
+    /// This option defaults to `false`.
+    #[unstable(feature = "net_resolver", issue = "none")]
+    pub fn addr_config(&mut self, addr_config: bool) -> &mut Resolver {
+        self.addr_config = addr_config;
+        self
+    }
+
+    /// Sets whether the canonical name of the host should be requested.
+    ///
+    /// This corresponds to the `AI_CANONNAME` flag of `getaddrinfo`. The name
+    /// is available through [`Lookup::canonical_name`].
+    ///
+    /// This option defaults to `false`.
+    #[unstable(feature = "net_resolver", issue = "none")]
+    pub fn canonical_name(&mut self, canonical_name: bool) -> &mut Resolver {
+        self.canonical_name = canonical_name;
+        self
+    }
+
+    /// Resolves `host` with the options specified by `self`.
+    ///
+    /// Each returned address has its port set to `port`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`] if both IPv4 and IPv6 were
+    /// disabled, and of kind [`Unsupported`] if a flag was requested that the
+    /// platform doesn't support. Otherwise any error reported by the system
+    /// resolver is returned.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "net_resolver", issue = "none")]
+    pub fn lookup(&self, host: &str, port: u16) -> io::Result<Lookup> {
+        let lh = net_imp::lookup_host_with(host, port, self)?;
+        let canonical_name = lh.canonical_name();
+        let addrs = lh
+            .map(|mut a| {
+                a.set_port(port);
+                a
+            })
+            .collect();
+        Ok(Lookup { canonical_name, addrs })
+    }
+
+    /// Looks up the host name of `addr`.
+    ///
+    /// This performs a reverse lookup through `getnameinfo`, and fails if no
+    /// name is known for the address instead of returning it in numeric form.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_resolver)]
+    /// use std::net::{IpAddr, Ipv4Addr, Resolver};
+    ///
+    /// let name = Resolver::new().lookup_addr(&IpAddr::V4(Ipv4Addr::LOCALHOST))?;
+    /// println!("127.0.0.1 is {name}");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[unstable(feature = "net_resolver", issue = "none")]
+    pub fn lookup_addr(&self, addr: &IpAddr) -> io::Result<String> {
+        net_imp::lookup_addr(addr)
+    }
+}
+
+#[unstable(feature = "net_resolver", issue = "none")]
+impl Default for Resolver {
+    fn default() -> Resolver {
+        Resolver::new()
+    }
+}
+
+/// The result of resolving a host name with a [`Resolver`].
+///
+/// This is created by [`Resolver::lookup`]. It can be iterated over to obtain
+/// the resolved [`SocketAddr`]s.
+#[derive(Clone, Debug)]
+#[unstable(feature = "net_resolver", issue = "none")]
+pub struct Lookup {
+    canonical_name: Option<String>,
+    addrs: Vec<SocketAddr>,
+}
+
+impl Lookup {
+    /// Returns the canonical name of the host.
+    ///
+    /// This is `None` unless [`Resolver::canonical_name`] was enabled and the
+    /// system resolver reported a name.
+    #[unstable(feature = "net_resolver", issue = "none")]
+    #[must_use]
+    pub fn canonical_name(&self) -> Option<&str> {
+        self.canonical_name.as_deref()
+    }
+
+    /// Returns the resolved addresses, in the order the system returned them.
+    #[unstable(feature = "net_resolver", issue = "none")]
+    #[must_use]
+    pub fn addrs(&self) -> &[SocketAddr] {
+        &self.addrs
+    }
+}
+
+#[unstable(feature = "net_resolver", issue = "none")]
+impl IntoIterator for Lookup {
+    type Item = SocketAddr;
+    type IntoIter = vec::IntoIter<SocketAddr>;
+
+    fn into_iter(self) -> vec::IntoIter<SocketAddr> {
+        self.addrs.into_iter()
+    }
+}
+
+#[unstable(feature = "net_resolver", issue = "none")]
+impl<'a> IntoIterator for &'a Lookup {
+    type Item = &'a SocketAddr;
+    type IntoIter = slice::Iter<'a, SocketAddr>;
+
+    fn into_iter(self) -> slice::Iter<'a, SocketAddr> {
+        self.addrs.iter()
+    }
+}
@@ -0,0 +1,47 @@
+use crate::io::ErrorKind;
+use crate::net::{IpAddr, Ipv4Addr, Resolver};
+
+macro_rules! t {
+    ($e:expr) => {
+        match $e {
+            Ok(t) => t,
+            Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+        }
+    };
+}
+
+// These tests rely on `localhost` being listed in the hosts file, which is the
+// case on every system the test suite runs on.
+
+#[test]
+fn lookup_ipv4_only() {
+    let lookup = t!(Resolver::new().ipv6(false).lookup("localhost", 8080));
+    assert!(!lookup.addrs().is_empty());
+    for addr in &lookup {
+        assert!(addr.is_ipv4(), "unexpected address {addr}");
+        assert_eq!(addr.port(), 8080);
+    }
+    assert!(lookup.addrs().iter().any(|a| a.ip() == IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    assert_eq!(lookup.canonical_name(), None);
+}
+
+#[test]
+fn lookup_canonical_name() {
+    let lookup = t!(Resolver::new().ipv6(false).canonical_name(true).lookup("localhost", 0));
+    assert!(lookup.canonical_name().is_some());
+}
+
+#[test]
+fn lookup_no_family() {
+    let err = Resolver::new().ipv4(false).ipv6(false).lookup("localhost", 0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+#[cfg(unix)]
+fn lookup_addr_localhost() {
+    let name = t!(Resolver::new().lookup_addr(&IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    assert!(!name.is_empty());
+    let lookup = t!(Resolver::new().ipv6(false).lookup(&name, 0));
+    assert!(lookup.addrs().iter().any(|a| a.ip() == IpAddr::V4(Ipv4Addr::LOCALHOST)));
+}
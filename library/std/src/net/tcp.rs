@@ -152,6 +152,7 @@ impl TcpStream {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<TcpStream> {
         super::each_addr(addr, net_imp::TcpStream::connect).map(TcpStream)
     }
@@ -168,6 +169,7 @@ pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<TcpStream> {
     /// then uses an OS-specific mechanism to await the completion of the
     /// connection request.
     #[stable(feature = "tcpstream_connect_timeout", since = "1.21.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn connect_timeout(addr: &SocketAddr, timeout: Duration) -> io::Result<TcpStream> {
         net_imp::TcpStream::connect_timeout(addr, timeout).map(TcpStream)
     }
@@ -410,6 +412,7 @@ pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
     /// let len = stream.peek(&mut buf).expect("peek failed");
     /// ```
     #[stable(feature = "peek", since = "1.18.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.peek(buf)
     }
@@ -502,6 +505,108 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.0.nodelay()
     }
 
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// When enabled, the system periodically probes an idle connection, and
+    /// reports it as broken if the peer stops answering. The timing of the
+    /// probes can be tuned with [`set_keepalive_idle`],
+    /// [`set_keepalive_interval`] and [`set_keepalive_retries`].
+    ///
+    /// [`set_keepalive_idle`]: TcpStream::set_keepalive_idle
+    /// [`set_keepalive_interval`]: TcpStream::set_keepalive_interval
+    /// [`set_keepalive_retries`]: TcpStream::set_keepalive_retries
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive)]
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive(true).expect("set_keepalive call failed");
+    /// stream.set_keepalive_idle(Duration::from_secs(60)).expect("set_keepalive_idle call failed");
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive`].
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets how long the connection has to be idle before the first keepalive
+    /// probe is sent.
+    ///
+    /// This sets the `TCP_KEEPIDLE` option (`TCP_KEEPALIVE` on Apple platforms
+    /// and Windows). The duration is rounded down to whole seconds, and an
+    /// error of kind [`InvalidInput`] is returned if that leaves zero.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Returns an error of kind [`Unsupported`] on platforms that don't allow
+    /// tuning keepalive probes.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        self.0.set_keepalive_idle(idle)
+    }
+
+    /// Gets how long the connection has to be idle before the first keepalive
+    /// probe is sent.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive_idle`].
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        self.0.keepalive_idle()
+    }
+
+    /// Sets the time between two keepalive probes.
+    ///
+    /// This sets the `TCP_KEEPINTVL` option. As with
+    /// [`set_keepalive_idle`], the duration is rounded down to whole seconds.
+    ///
+    /// [`set_keepalive_idle`]: TcpStream::set_keepalive_idle
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.0.set_keepalive_interval(interval)
+    }
+
+    /// Gets the time between two keepalive probes.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive_interval`].
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.0.keepalive_interval()
+    }
+
+    /// Sets how many unanswered keepalive probes are sent before the
+    /// connection is considered broken.
+    ///
+    /// This sets the `TCP_KEEPCNT` option.
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        self.0.set_keepalive_retries(retries)
+    }
+
+    /// Gets how many unanswered keepalive probes are sent before the
+    /// connection is considered broken.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive_retries`].
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        self.0.keepalive_retries()
+    }
+
     /// Sets the value for the `IP_TTL` option on this socket.
     ///
     /// This value sets the time-to-live field that is used in every packet sent
@@ -614,10 +719,12 @@ pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for TcpStream {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -629,10 +736,12 @@ fn is_read_vectored(&self) -> bool {
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Write for TcpStream {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.0.write(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
         self.0.write_vectored(bufs)
     }
@@ -648,10 +757,12 @@ fn flush(&mut self) -> io::Result<()> {
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for &TcpStream {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -663,10 +774,12 @@ fn is_read_vectored(&self) -> bool {
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Write for &TcpStream {
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.0.write(buf)
     }
 
+    #[cfg_attr(not(bootstrap), blocking)]
     fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
         self.0.write_vectored(bufs)
     }
@@ -747,6 +860,7 @@ impl TcpListener {
     /// let listener = TcpListener::bind(&addrs[..]).unwrap();
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<TcpListener> {
         super::each_addr(addr, net_imp::TcpListener::bind).map(TcpListener)
     }
@@ -804,6 +918,7 @@ pub fn try_clone(&self) -> io::Result<TcpListener> {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
         // On WASM, `TcpStream` is uninhabited (as it's unsupported) and so
         // the `a` variable here is technically unused.
@@ -796,6 +796,31 @@ fn nodelay() {
     assert_eq!(false, t!(stream.nodelay()));
 }
 
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn keepalive() {
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(TcpStream::connect(&("localhost", addr.port())));
+
+    assert_eq!(false, t!(stream.keepalive()));
+    t!(stream.set_keepalive(true));
+    assert_eq!(true, t!(stream.keepalive()));
+
+    if cfg!(any(target_os = "linux", target_os = "android", target_os = "macos")) {
+        t!(stream.set_keepalive_idle(Duration::from_millis(30_500)));
+        assert_eq!(Duration::from_secs(30), t!(stream.keepalive_idle()));
+        t!(stream.set_keepalive_interval(Duration::from_secs(5)));
+        assert_eq!(Duration::from_secs(5), t!(stream.keepalive_interval()));
+        t!(stream.set_keepalive_retries(3));
+        assert_eq!(3, t!(stream.keepalive_retries()));
+    }
+
+    let err = stream.set_keepalive_idle(Duration::from_millis(500)).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidInput | ErrorKind::Unsupported));
+}
+
 #[test]
 #[cfg_attr(target_env = "sgx", ignore)]
 fn ttl() {
@@ -3,7 +3,7 @@
 
 use crate::fmt;
 use crate::io::{self, ErrorKind};
-use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -91,10 +91,39 @@ impl UdpSocket {
     /// let socket = UdpSocket::bind(&addrs[..]).expect("couldn't bind to address");
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<UdpSocket> {
         super::each_addr(addr, net_imp::UdpSocket::bind).map(UdpSocket)
     }
 
+    /// Creates a UDP socket from the given address, with the `SO_REUSEPORT`
+    /// option set before it is bound.
+    ///
+    /// This allows several sockets, typically in different threads or
+    /// processes, to bind to the same address. The system then spreads the
+    /// incoming datagrams across them. The option has to be set on every
+    /// socket sharing the address, which is why it can't be changed after the
+    /// socket has been bound.
+    ///
+    /// Returns an error of kind [`Unsupported`] on platforms without
+    /// `SO_REUSEPORT`, such as Windows.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_socket_options)]
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket1 = UdpSocket::bind_reuse_port("127.0.0.1:3400").expect("couldn't bind");
+    /// let socket2 = UdpSocket::bind_reuse_port("127.0.0.1:3400").expect("couldn't bind");
+    /// ```
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn bind_reuse_port<A: ToSocketAddrs>(addr: A) -> io::Result<UdpSocket> {
+        super::each_addr(addr, net_imp::UdpSocket::bind_reuse_port).map(UdpSocket)
+    }
+
     /// Receives a single datagram message on the socket. On success, returns the number
     /// of bytes read and the origin.
     ///
@@ -114,6 +143,7 @@ pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<UdpSocket> {
     /// let filled_buf = &mut buf[..number_of_bytes];
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
         self.0.recv_from(buf)
     }
@@ -143,6 +173,7 @@ pub fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
     /// let filled_buf = &mut buf[..number_of_bytes];
     /// ```
     #[stable(feature = "peek", since = "1.18.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
         self.0.peek_from(buf)
     }
@@ -172,6 +203,7 @@ pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
     ///
     /// [Issue #34202]: https://github.com/rust-lang/rust/issues/34202
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn send_to<A: ToSocketAddrs>(&self, buf: &[u8], addr: A) -> io::Result<usize> {
         match addr.to_socket_addrs()?.next() {
             Some(addr) => self.0.send_to(buf, &addr),
@@ -592,6 +624,234 @@ pub fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> io::Re
         self.0.leave_multicast_v6(multiaddr, interface)
     }
 
+    /// Executes an operation of the `IP_ADD_SOURCE_MEMBERSHIP` type.
+    ///
+    /// This joins the multicast `group` like [`join_multicast_v4`], but only
+    /// datagrams sent by `source` are received (source-specific multicast).
+    /// `interface` is the address of the local interface to join on, or
+    /// `INADDR_ANY` to let the system choose.
+    ///
+    /// [`join_multicast_v4`]: UdpSocket::join_multicast_v4
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_socket_options)]
+    /// use std::net::{Ipv4Addr, UdpSocket};
+    ///
+    /// let socket = UdpSocket::bind("0.0.0.0:5000").expect("couldn't bind to address");
+    /// let source = Ipv4Addr::new(192, 0, 2, 1);
+    /// let group = Ipv4Addr::new(232, 1, 1, 1);
+    /// socket.join_ssm_v4(&source, &group, &Ipv4Addr::UNSPECIFIED).expect("join_ssm_v4 failed");
+    /// ```
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn join_ssm_v4(
+        &self,
+        source: &Ipv4Addr,
+        group: &Ipv4Addr,
+        interface: &Ipv4Addr,
+    ) -> io::Result<()> {
+        self.0.join_ssm_v4(source, group, interface)
+    }
+
+    /// Executes an operation of the `IP_DROP_SOURCE_MEMBERSHIP` type.
+    ///
+    /// For more information about this option, see [`UdpSocket::join_ssm_v4`].
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn leave_ssm_v4(
+        &self,
+        source: &Ipv4Addr,
+        group: &Ipv4Addr,
+        interface: &Ipv4Addr,
+    ) -> io::Result<()> {
+        self.0.leave_ssm_v4(source, group, interface)
+    }
+
+    /// Joins an IPv4 multicast group on the interface with the given index.
+    ///
+    /// This is like [`join_multicast_v4`], but names the interface by its
+    /// index (or 0 to indicate any interface) rather than by its address,
+    /// which is needed for interfaces without an IPv4 address of their own.
+    ///
+    /// [`join_multicast_v4`]: UdpSocket::join_multicast_v4
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is currently only supported on Linux and Android.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn join_multicast_v4_by_index(
+        &self,
+        multiaddr: &Ipv4Addr,
+        interface: u32,
+    ) -> io::Result<()> {
+        self.0.join_multicast_v4_by_index(multiaddr, interface)
+    }
+
+    /// Leaves an IPv4 multicast group on the interface with the given index.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocket::join_multicast_v4_by_index`].
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn leave_multicast_v4_by_index(
+        &self,
+        multiaddr: &Ipv4Addr,
+        interface: u32,
+    ) -> io::Result<()> {
+        self.0.leave_multicast_v4_by_index(multiaddr, interface)
+    }
+
+    /// Sets the interface, by index, on which outgoing IPv4 multicast
+    /// datagrams are sent.
+    ///
+    /// This sets the `IP_MULTICAST_IF` option. An index of 0 restores the
+    /// default, letting the routing table pick the interface.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is currently only supported on Linux and Android.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn set_multicast_interface_v4(&self, interface: u32) -> io::Result<()> {
+        self.0.set_multicast_interface_v4(interface)
+    }
+
+    /// Sets the interface, by index, on which outgoing IPv6 multicast
+    /// datagrams are sent.
+    ///
+    /// This sets the `IPV6_MULTICAST_IF` option. An index of 0 restores the
+    /// default, letting the routing table pick the interface.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn set_multicast_interface_v6(&self, interface: u32) -> io::Result<()> {
+        self.0.set_multicast_interface_v6(interface)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// The option can only be enabled when the socket is created, with
+    /// [`UdpSocket::bind_reuse_port`].
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn reuse_port(&self) -> io::Result<bool> {
+        self.0.reuse_port()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// This is a hint for the size of the buffer the system uses to hold
+    /// received datagrams until they are read. The system may adjust the
+    /// value; on Linux, for example, it is doubled to leave room for
+    /// bookkeeping, and [`recv_buffer_size`] returns the adjusted value.
+    ///
+    /// [`recv_buffer_size`]: UdpSocket::recv_buffer_size
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_socket_options)]
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_recv_buffer_size(1 << 20).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_recv_buffer_size`].
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// This is a hint for the size of the buffer the system uses to hold
+    /// outgoing datagrams until they are sent. As with
+    /// [`set_recv_buffer_size`], the system may adjust the value.
+    ///
+    /// [`set_recv_buffer_size`]: UdpSocket::set_recv_buffer_size
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_send_buffer_size`].
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets whether [`recv_msg`] reports the destination address and the
+    /// interface of each received datagram.
+    ///
+    /// This sets the `IP_PKTINFO` option on IPv4 sockets, and the
+    /// `IPV6_RECVPKTINFO` option on IPv6 sockets, so the socket must be bound
+    /// first.
+    ///
+    /// [`recv_msg`]: UdpSocket::recv_msg
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is currently only supported on Linux and Android.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn set_recv_pktinfo(&self, recv_pktinfo: bool) -> io::Result<()> {
+        self.0.set_recv_pktinfo(recv_pktinfo)
+    }
+
+    /// Gets the value of the `IP_PKTINFO` or `IPV6_RECVPKTINFO` option on
+    /// this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_recv_pktinfo`].
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn recv_pktinfo(&self) -> io::Result<bool> {
+        self.0.recv_pktinfo()
+    }
+
+    /// Receives a single datagram message on the socket, along with where it
+    /// was sent from and where it was sent to.
+    ///
+    /// This is like [`recv_from`], but also returns the destination address
+    /// of the datagram and the index of the interface it arrived on, which
+    /// a socket bound to an unspecified address needs in order to reply from
+    /// the right address. These are only available if
+    /// [`set_recv_pktinfo`] was enabled.
+    ///
+    /// If the datagram is longer than `buf`, the excess bytes are discarded
+    /// and [`RecvMsg::truncated`] returns `true`.
+    ///
+    /// [`recv_from`]: UdpSocket::recv_from
+    /// [`set_recv_pktinfo`]: UdpSocket::set_recv_pktinfo
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is currently only supported on Linux and Android.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_socket_options)]
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("0.0.0.0:34254").expect("couldn't bind to address");
+    /// socket.set_recv_pktinfo(true).expect("set_recv_pktinfo call failed");
+    /// let mut buf = [0; 1500];
+    /// let msg = socket.recv_msg(&mut buf).expect("didn't receive data");
+    /// println!(
+    ///     "{} bytes from {} to {:?} on interface {:?}",
+    ///     msg.len(),
+    ///     msg.peer_addr(),
+    ///     msg.local_ip(),
+    ///     msg.interface(),
+    /// );
+    /// ```
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    pub fn recv_msg(&self, buf: &mut [u8]) -> io::Result<RecvMsg> {
+        self.0.recv_msg(buf)
+    }
+
     /// Gets the value of the `SO_ERROR` option on this socket.
     ///
     /// This will retrieve the stored error in the underlying socket, clearing
@@ -644,6 +904,7 @@ pub fn take_error(&self) -> io::Result<Option<io::Error>> {
     /// unable to determine whether something is listening on the remote
     /// address without the application sending data.
     #[stable(feature = "net2_mutators", since = "1.9.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn connect<A: ToSocketAddrs>(&self, addr: A) -> io::Result<()> {
         super::each_addr(addr, |addr| self.0.connect(addr))
     }
@@ -663,6 +924,7 @@ pub fn connect<A: ToSocketAddrs>(&self, addr: A) -> io::Result<()> {
     /// socket.send(&[0, 1, 2]).expect("couldn't send message");
     /// ```
     #[stable(feature = "net2_mutators", since = "1.9.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
         self.0.send(buf)
     }
@@ -691,6 +953,7 @@ pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
     /// }
     /// ```
     #[stable(feature = "net2_mutators", since = "1.9.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.recv(buf)
     }
@@ -731,6 +994,7 @@ pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
     /// }
     /// ```
     #[stable(feature = "peek", since = "1.18.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.peek(buf)
     }
@@ -805,6 +1069,59 @@ fn into_inner(self) -> net_imp::UdpSocket {
     }
 }
 
+/// Information about a datagram received with [`UdpSocket::recv_msg`].
+#[unstable(feature = "udp_socket_options", issue = "none")]
+#[derive(Copy, Clone, Debug)]
+pub struct RecvMsg {
+    pub(crate) len: usize,
+    pub(crate) peer_addr: SocketAddr,
+    pub(crate) local_ip: Option<IpAddr>,
+    pub(crate) interface: Option<u32>,
+    pub(crate) truncated: bool,
+}
+
+impl RecvMsg {
+    /// Returns the number of bytes read into the buffer.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the address the datagram was sent from.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    #[must_use]
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer_addr
+    }
+
+    /// Returns the address the datagram was sent to.
+    ///
+    /// This is `None` unless [`UdpSocket::set_recv_pktinfo`] was enabled.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    #[must_use]
+    pub fn local_ip(&self) -> Option<IpAddr> {
+        self.local_ip
+    }
+
+    /// Returns the index of the interface the datagram arrived on.
+    ///
+    /// This is `None` unless [`UdpSocket::set_recv_pktinfo`] was enabled.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    #[must_use]
+    pub fn interface(&self) -> Option<u32> {
+        self.interface
+    }
+
+    /// Returns `true` if the datagram didn't fit in the buffer and was
+    /// truncated.
+    #[unstable(feature = "udp_socket_options", issue = "none")]
+    #[must_use]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl fmt::Debug for UdpSocket {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -342,6 +342,64 @@ fn ttl() {
     assert_eq!(ttl, t!(stream.ttl()));
 }
 
+#[test]
+fn buffer_sizes() {
+    let socket = t!(UdpSocket::bind(&next_test_ip4()));
+
+    t!(socket.set_recv_buffer_size(64 * 1024));
+    assert!(t!(socket.recv_buffer_size()) >= 64 * 1024);
+    t!(socket.set_send_buffer_size(64 * 1024));
+    assert!(t!(socket.send_buffer_size()) >= 64 * 1024);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn bind_reuse_port() {
+    let addr = next_test_ip4();
+    let socket1 = t!(UdpSocket::bind_reuse_port(&addr));
+    let socket2 = t!(UdpSocket::bind_reuse_port(&addr));
+    assert!(t!(socket1.reuse_port()));
+    assert!(t!(socket2.reuse_port()));
+    assert_eq!(t!(socket1.local_addr()), t!(socket2.local_addr()));
+
+    // A socket without the option can't join them.
+    assert!(UdpSocket::bind(&addr).is_err());
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn recv_msg_pktinfo() {
+    each_ip(&mut |server_ip, client_ip| {
+        let unspecified = match server_ip {
+            SocketAddr::V4(..) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            SocketAddr::V6(..) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        };
+        let server = t!(UdpSocket::bind(SocketAddr::new(unspecified, server_ip.port())));
+        let client = t!(UdpSocket::bind(&client_ip));
+
+        // Without the option, the destination is unknown.
+        t!(client.send_to(b"one", &server_ip));
+        let mut buf = [0; 8];
+        let msg = t!(server.recv_msg(&mut buf));
+        assert_eq!(msg.len(), 3);
+        assert_eq!(&buf[..3], b"one");
+        assert_eq!(msg.peer_addr(), client_ip);
+        assert_eq!(msg.local_ip(), None);
+        assert!(!msg.truncated());
+
+        assert!(!t!(server.recv_pktinfo()));
+        t!(server.set_recv_pktinfo(true));
+        assert!(t!(server.recv_pktinfo()));
+
+        t!(client.send_to(b"two and more", &server_ip));
+        let msg = t!(server.recv_msg(&mut buf));
+        assert_eq!(msg.len(), 8);
+        assert!(msg.truncated());
+        assert_eq!(msg.local_ip(), Some(server_ip.ip()));
+        assert!(msg.interface().is_some());
+    })
+}
+
 #[test]
 fn set_nonblocking() {
     each_ip(&mut |addr, _| {
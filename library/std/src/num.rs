@@ -14,6 +14,8 @@
 
 #[unstable(feature = "saturating_int_impl", issue = "87920")]
 pub use core::num::Saturating;
+#[unstable(feature = "fixed_width_uint", issue = "none")]
+pub use core::num::Uint;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::num::Wrapping;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -80,6 +80,14 @@ mod platform {
 pub mod process;
 pub mod raw;
 pub mod thread;
+#[unstable(feature = "unix_clocks", issue = "none")]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "dragonfly"
+))]
+pub mod time;
 
 #[unstable(feature = "peer_credentials_unix_socket", issue = "42839", reason = "unstable")]
 #[cfg(any(
@@ -3,7 +3,7 @@
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::marker::PhantomData;
 use crate::mem::{size_of, zeroed};
-use crate::os::unix::io::RawFd;
+use crate::os::unix::io::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
 use crate::path::Path;
 use crate::ptr::{eq, read_unaligned};
 use crate::slice::from_raw_parts;
@@ -78,6 +78,78 @@ pub(super) fn send_vectored_with_ancillary_to(
     }
 }
 
+// The most file descriptors Linux accepts in a single `SCM_RIGHTS` message (`SCM_MAX_FD`).
+const MAX_FDS: usize = 253;
+
+// The buffer size needed for a control message carrying `count` file descriptors.
+fn fds_space(count: usize) -> usize {
+    unsafe { libc::CMSG_SPACE((count * size_of::<RawFd>()) as u32) as usize }
+}
+
+pub(super) fn send_fds_to(
+    socket: &Socket,
+    buf: &[u8],
+    fds: &[BorrowedFd<'_>],
+) -> io::Result<usize> {
+    if fds.len() > MAX_FDS {
+        return Err(io::const_io_error!(
+            io::ErrorKind::InvalidInput,
+            "too many file descriptors to send in one message",
+        ));
+    }
+    let fds: Vec<RawFd> = fds.iter().map(|fd| fd.as_raw_fd()).collect();
+    let mut buffer = vec![0; fds_space(fds.len())];
+    let mut ancillary = SocketAncillary::new(&mut buffer);
+    if !fds.is_empty() {
+        let added = ancillary.add_fds(&fds);
+        debug_assert!(added);
+    }
+    send_vectored_with_ancillary_to(socket, None, &[IoSlice::new(buf)], &mut ancillary)
+}
+
+pub(super) fn recv_fds_from(
+    socket: &Socket,
+    buf: &mut [u8],
+    fds: &mut Vec<OwnedFd>,
+) -> io::Result<usize> {
+    recv_fds_with_capacity_from(socket, buf, fds, MAX_FDS)
+}
+
+// Like `recv_fds_from`, with room for only about `max_fds` descriptors, so
+// that truncated descriptors can be tested without sending more than
+// `MAX_FDS` of them.
+pub(super) fn recv_fds_with_capacity_from(
+    socket: &Socket,
+    buf: &mut [u8],
+    fds: &mut Vec<OwnedFd>,
+    max_fds: usize,
+) -> io::Result<usize> {
+    let mut buffer = vec![0; fds_space(max_fds)];
+    let mut ancillary = SocketAncillary::new(&mut buffer);
+    let (count, truncated, _) =
+        recv_vectored_with_ancillary_from(socket, &mut [IoSliceMut::new(buf)], &mut ancillary)?;
+    // Take ownership of every received descriptor, so that none of them leak,
+    // even if the message was truncated.
+    for message in ancillary.messages() {
+        if let Ok(AncillaryData::ScmRights(rights)) = message {
+            fds.extend(rights.map(|fd| unsafe { OwnedFd::from_raw_fd(fd) }));
+        }
+    }
+    if ancillary.truncated() {
+        return Err(io::const_io_error!(
+            io::ErrorKind::InvalidData,
+            "received more file descriptors than fit in one message",
+        ));
+    }
+    if truncated {
+        return Err(io::const_io_error!(
+            io::ErrorKind::InvalidData,
+            "received message is larger than the buffer",
+        ));
+    }
+    Ok(count)
+}
+
 fn add_to_ancillary_data<T>(
     buffer: &mut [u8],
     length: &mut usize,
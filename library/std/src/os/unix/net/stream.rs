@@ -8,7 +8,10 @@
     target_os = "netbsd",
     target_os = "openbsd",
 ))]
-use super::{recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to, SocketAncillary};
+use super::{
+    recv_fds_from, recv_vectored_with_ancillary_from, send_fds_to, send_vectored_with_ancillary_to,
+    SocketAncillary,
+};
 use super::{sockaddr_un, SocketAddr};
 use crate::fmt;
 use crate::io::{self, IoSlice, IoSliceMut};
@@ -608,6 +611,90 @@ pub fn send_vectored_with_ancillary(
     ) -> io::Result<usize> {
         send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
     }
+
+    /// Sends data together with file descriptors.
+    ///
+    /// This is a convenience wrapper around [`send_vectored_with_ancillary`]
+    /// that builds the `SCM_RIGHTS` control message. The descriptors stay open
+    /// in this process; the receiver gets new descriptors referring to the same
+    /// open files.
+    ///
+    /// At least one byte of data should be sent, as some platforms don't
+    /// deliver control messages on stream sockets without accompanying data.
+    ///
+    /// [`send_vectored_with_ancillary`]: UnixStream::send_vectored_with_ancillary
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_fd_passing)]
+    /// use std::fs::File;
+    /// use std::os::unix::io::AsFd;
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixStream::connect("/tmp/sock")?;
+    ///     let file = File::open("/etc/hosts")?;
+    ///     socket.send_fds(b"hosts", &[file.as_fd()])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "emscripten",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    #[unstable(feature = "unix_socket_fd_passing", issue = "none")]
+    pub fn send_fds(&self, buf: &[u8], fds: &[BorrowedFd<'_>]) -> io::Result<usize> {
+        send_fds_to(&self.0, buf, fds)
+    }
+
+    /// Receives data together with any file descriptors sent with it.
+    ///
+    /// The received descriptors are appended to `fds`, with the close-on-exec
+    /// flag set where the platform supports it. On success, returns the number
+    /// of bytes read.
+    ///
+    /// If more descriptors were sent than can be received at once, the rest
+    /// of them are closed and an error of kind [`InvalidData`] is returned.
+    /// The descriptors that were received are still appended to `fds`.
+    ///
+    /// [`InvalidData`]: io::ErrorKind::InvalidData
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_fd_passing)]
+    /// use std::fs::File;
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixStream::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 64];
+    ///     let mut fds = Vec::new();
+    ///     let len = socket.recv_fds(&mut buf, &mut fds)?;
+    ///     let files: Vec<File> = fds.into_iter().map(File::from).collect();
+    ///     println!("received {len} bytes and {} files", files.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "emscripten",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    #[unstable(feature = "unix_socket_fd_passing", issue = "none")]
+    pub fn recv_fds(&self, buf: &mut [u8], fds: &mut Vec<OwnedFd>) -> io::Result<usize> {
+        recv_fds_from(&self.0, buf, fds)
+    }
 }
 
 #[stable(feature = "unix_socket", since = "1.10.0")]
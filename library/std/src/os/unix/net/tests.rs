@@ -785,3 +785,187 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
         unreachable!("must be ScmRights");
     }
 }
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_unix_seqpacket() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let socket = or_panic!(listener.accept()).0;
+        let mut buf = [0; 16];
+        // Message boundaries are preserved.
+        assert_eq!(or_panic!(socket.recv(&mut buf)), 5);
+        assert_eq!(&buf[..5], b"hello");
+        assert_eq!(or_panic!(socket.recv(&mut buf)), 6);
+        assert_eq!(&buf[..6], b"world!");
+        or_panic!(socket.send(b"bye"));
+    });
+
+    let socket = or_panic!(UnixSeqpacket::connect(&socket_path));
+    assert_eq!(Some(&*socket_path), socket.peer_addr().unwrap().as_pathname());
+    or_panic!(socket.send(b"hello"));
+    or_panic!(socket.send(b"world!"));
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(socket.recv(&mut buf)), 3);
+    assert_eq!(&buf[..3], b"bye");
+    thread.join().unwrap();
+    assert_eq!(or_panic!(socket.recv(&mut buf)), 0);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_unix_seqpacket_pair_truncates() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+
+    or_panic!(s1.send(b"0123456789"));
+    or_panic!(s1.send(b"abc"));
+    let mut buf = [0; 4];
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 4);
+    assert_eq!(&buf, b"0123");
+    // The rest of the first message was discarded.
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 3);
+    assert_eq!(&buf[..3], b"abc");
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_send_recv_fds_unix_stream() {
+    use crate::fs::File;
+    use crate::os::unix::io::AsFd;
+
+    let dir = tmpdir();
+    let path = dir.path().join("file");
+    or_panic!(crate::fs::write(&path, b"passed along"));
+    let file = or_panic!(File::open(&path));
+
+    let (s1, s2) = or_panic!(UnixStream::pair());
+    assert_eq!(or_panic!(s1.send_fds(b"x", &[file.as_fd(), s1.as_fd()])), 1);
+
+    let mut buf = [0; 8];
+    let mut fds = Vec::new();
+    assert_eq!(or_panic!(s2.recv_fds(&mut buf, &mut fds)), 1);
+    assert_eq!(buf[0], b'x');
+    assert_eq!(fds.len(), 2);
+    for fd in &fds {
+        assert_ne!(fd.as_raw_fd(), file.as_raw_fd());
+    }
+
+    let mut received = File::from(fds.remove(0));
+    let mut contents = String::new();
+    or_panic!(received.read_to_string(&mut contents));
+    assert_eq!(contents, "passed along");
+
+    // Plain data is received without descriptors.
+    or_panic!(s1.send_fds(b"y", &[]));
+    assert_eq!(or_panic!(s2.recv_fds(&mut buf, &mut fds)), 1);
+    assert_eq!(fds.len(), 1);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_recv_fds_truncated() {
+    use crate::os::unix::io::AsFd;
+
+    let (s1, s2) = or_panic!(UnixStream::pair());
+    let sent = [s1.as_fd(), s1.as_fd(), s1.as_fd(), s1.as_fd(), s1.as_fd(), s1.as_fd()];
+    or_panic!(s1.send_fds(b"x", &sent));
+
+    // Leave room for fewer descriptors than were sent. The ones that fit are
+    // still handed out, so that they can be closed.
+    let mut buf = [0; 8];
+    let mut fds = Vec::new();
+    let err = ancillary::recv_fds_with_capacity_from(&s2.0, &mut buf, &mut fds, 1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(!fds.is_empty() && fds.len() < sent.len(), "received {} fds", fds.len());
+
+    // The next message is received normally.
+    or_panic!(s1.send_fds(b"y", &sent[..1]));
+    fds.clear();
+    assert_eq!(or_panic!(s2.recv_fds(&mut buf, &mut fds)), 1);
+    assert_eq!(fds.len(), 1);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_recv_fds_message_truncated() {
+    let (s1, s2) = or_panic!(UnixDatagram::pair());
+    or_panic!(s1.send_fds(b"too long", &[]));
+
+    let mut buf = [0; 4];
+    let mut fds = Vec::new();
+    let err = s2.recv_fds(&mut buf, &mut fds).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(&buf, b"too ");
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_send_recv_fds_unix_seqpacket() {
+    use crate::os::unix::io::AsFd;
+
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    let (a, mut b) = or_panic!(UnixStream::pair());
+    or_panic!(s1.send_fds(b"stream", &[a.as_fd()]));
+    drop(a);
+
+    let mut buf = [0; 16];
+    let mut fds = Vec::new();
+    assert_eq!(or_panic!(s2.recv_fds(&mut buf, &mut fds)), 6);
+    assert_eq!(&buf[..6], b"stream");
+    assert_eq!(fds.len(), 1);
+
+    // The received descriptor refers to the same socket as the one sent.
+    let mut a = UnixStream::from(fds.pop().unwrap());
+    or_panic!(a.write_all(b"ping"));
+    let mut ping = [0; 4];
+    or_panic!(b.read_exact(&mut ping));
+    assert_eq!(&ping, b"ping");
+}
@@ -0,0 +1,258 @@
+//! Unix-specific clocks, complementing the [`std::time`] module.
+//!
+//! [`Instant`] measures wall-clock time with a clock that stops while the
+//! system is suspended. This module exposes the other clocks commonly needed
+//! by profilers and benchmarks:
+//!
+//! * [`BootInstant`] is a monotonic clock that keeps counting while the
+//!   system is suspended (`CLOCK_BOOTTIME`).
+//! * [`ProcessCpuTime`] measures the CPU time consumed by all threads of the
+//!   current process (`CLOCK_PROCESS_CPUTIME_ID`).
+//! * [`ThreadCpuTime`] measures the CPU time consumed by the current thread
+//!   (`CLOCK_THREAD_CPUTIME_ID`), and [`ThreadCpuTime::of`] reads the CPU
+//!   time of another thread through its [`JoinHandle`].
+//!
+//! CPU time only advances while the process or thread is running on a CPU, so
+//! comparing it with the wall time of an [`Instant`] tells how much of that
+//! time was spent waiting.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(unix_clocks)]
+//! use std::os::unix::time::ThreadCpuTime;
+//! use std::time::Instant;
+//!
+//! let wall = Instant::now();
+//! let cpu = ThreadCpuTime::now();
+//!
+//! let mut x = 0u64;
+//! for i in 0..1_000_000u64 {
+//!     x = x.wrapping_add(i * i);
+//! }
+//! std::thread::sleep(std::time::Duration::from_millis(10));
+//!
+//! println!("{x}: {:?} on CPU out of {:?}", cpu.elapsed(), wall.elapsed());
+//! ```
+//!
+//! [`std::time`]: crate::time
+//! [`Instant`]: crate::time::Instant
+
+#![unstable(feature = "unix_clocks", issue = "none")]
+
+#[cfg(test)]
+mod tests;
+
+use crate::io;
+use crate::marker::PhantomData;
+use crate::ops::Sub;
+use crate::sys::time::clocks;
+use crate::sys_common::AsInner;
+use crate::thread::JoinHandle;
+use crate::time::Duration;
+
+/// A measurement of a monotonically nondecreasing clock that includes the time
+/// the system spent suspended.
+///
+/// This is like [`Instant`], but reads `CLOCK_BOOTTIME` instead of
+/// `CLOCK_MONOTONIC`, so the difference between two `BootInstant`s taken
+/// before and after a suspend includes the time spent asleep.
+///
+/// [`Instant`]: crate::time::Instant
+///
+/// # Examples
+///
+/// ```
+/// #![feature(unix_clocks)]
+/// use std::os::unix::time::BootInstant;
+///
+/// let start = BootInstant::now();
+/// let later = BootInstant::now();
+/// assert!(later >= start);
+/// println!("{:?}", later.duration_since(start));
+/// ```
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct BootInstant(Duration);
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl BootInstant {
+    /// Returns an instant corresponding to "now".
+    #[must_use]
+    pub fn now() -> BootInstant {
+        BootInstant(clocks::boottime())
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
+    #[must_use]
+    pub fn duration_since(&self, earlier: BootInstant) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or None if that instant is later than this one.
+    #[must_use]
+    pub fn checked_duration_since(&self, earlier: BootInstant) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    /// Returns the amount of time elapsed since this instant was created,
+    /// including any time the system spent suspended.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        BootInstant::now() - *self
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be
+    /// represented as `BootInstant`, `None` otherwise.
+    pub fn checked_add(&self, duration: Duration) -> Option<BootInstant> {
+        self.0.checked_add(duration).map(BootInstant)
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self - duration` if `t` can be
+    /// represented as `BootInstant`, `None` otherwise.
+    pub fn checked_sub(&self, duration: Duration) -> Option<BootInstant> {
+        self.0.checked_sub(duration).map(BootInstant)
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Sub<BootInstant> for BootInstant {
+    type Output = Duration;
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
+    fn sub(self, other: BootInstant) -> Duration {
+        self.duration_since(other)
+    }
+}
+
+/// The CPU time consumed by the current process.
+///
+/// This counts the time spent executing on a CPU, in user and kernel mode, by
+/// all threads of the process, including threads that have already exited.
+/// Time spent blocked or waiting to be scheduled is not counted, and on a
+/// multi-core system the CPU time can advance faster than wall-clock time.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(unix_clocks)]
+/// use std::os::unix::time::ProcessCpuTime;
+///
+/// let start = ProcessCpuTime::now();
+/// // ... do some work ...
+/// println!("used {:?} of CPU time", start.elapsed());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ProcessCpuTime(Duration);
+
+impl ProcessCpuTime {
+    /// Returns the CPU time consumed by the process so far.
+    #[must_use]
+    pub fn now() -> ProcessCpuTime {
+        ProcessCpuTime(clocks::process_cputime())
+    }
+
+    /// Returns the CPU time consumed by the process between `earlier` and
+    /// `self`, or zero duration if `earlier` is later than `self`.
+    #[must_use]
+    pub fn duration_since(&self, earlier: ProcessCpuTime) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns the CPU time consumed by the process since this measurement
+    /// was taken.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        ProcessCpuTime::now().duration_since(*self)
+    }
+
+    /// Returns the total CPU time consumed by the process up to this
+    /// measurement.
+    #[must_use]
+    pub fn as_duration(&self) -> Duration {
+        self.0
+    }
+}
+
+/// The CPU time consumed by a single thread.
+///
+/// Like [`ProcessCpuTime`], but only counts the time spent on a CPU by one
+/// thread. A `ThreadCpuTime` obtained from [`now`] describes the current
+/// thread and can't be sent to another thread, so that [`elapsed`] always
+/// reads the clock of the thread that took the measurement.
+///
+/// [`now`]: ThreadCpuTime::now
+/// [`elapsed`]: ThreadCpuTime::elapsed
+///
+/// # Examples
+///
+/// ```
+/// #![feature(unix_clocks)]
+/// use std::os::unix::time::ThreadCpuTime;
+/// use std::thread;
+///
+/// let handle = thread::spawn(|| {
+///     let start = ThreadCpuTime::now();
+///     // ... do some work ...
+///     start.elapsed()
+/// });
+/// // The CPU time of the thread can be read from outside as well, as long as
+/// // it is still running.
+/// if let Ok(total) = ThreadCpuTime::of(&handle) {
+///     println!("used {total:?} so far");
+/// }
+/// println!("measured {:?}", handle.join().unwrap());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ThreadCpuTime {
+    t: Duration,
+    // The measurement is only meaningful on the thread that took it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ThreadCpuTime {
+    /// Returns the CPU time consumed by the current thread so far.
+    #[must_use]
+    pub fn now() -> ThreadCpuTime {
+        ThreadCpuTime { t: clocks::thread_cputime(), _not_send: PhantomData }
+    }
+
+    /// Returns the CPU time consumed by the current thread between `earlier`
+    /// and `self`, or zero duration if `earlier` is later than `self`.
+    #[must_use]
+    pub fn duration_since(&self, earlier: ThreadCpuTime) -> Duration {
+        self.t.saturating_sub(earlier.t)
+    }
+
+    /// Returns the CPU time consumed by the current thread since this
+    /// measurement was taken.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        ThreadCpuTime::now().duration_since(*self)
+    }
+
+    /// Returns the total CPU time consumed by the current thread up to this
+    /// measurement.
+    #[must_use]
+    pub fn as_duration(&self) -> Duration {
+        self.t
+    }
+
+    /// Returns the total CPU time consumed so far by the thread behind `thread`.
+    ///
+    /// A [`ThreadId`] doesn't identify a thread to the operating system, so
+    /// the thread is named by its [`JoinHandle`] instead.
+    ///
+    /// [`ThreadId`]: crate::thread::ThreadId
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the thread has already exited, or if the operating
+    /// system can't provide a CPU-time clock for it.
+    pub fn of<T>(thread: &JoinHandle<T>) -> io::Result<Duration> {
+        clocks::pthread_cputime(thread.as_inner().id())
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+use crate::thread;
+use crate::time::Instant;
+
+fn spin(d: Duration) {
+    let start = Instant::now();
+    while start.elapsed() < d {}
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn boot_instant_monotonic() {
+    let a = BootInstant::now();
+    thread::sleep(Duration::from_millis(10));
+    let b = BootInstant::now();
+    assert!(b >= a);
+    assert!(b - a >= Duration::from_millis(10));
+    assert_eq!(a - b, Duration::ZERO);
+    assert_eq!(a.checked_duration_since(b), None);
+    assert_eq!(a.checked_add(Duration::from_secs(1)).unwrap() - a, Duration::from_secs(1));
+}
+
+#[test]
+fn thread_cpu_time_excludes_sleep() {
+    let cpu = ThreadCpuTime::now();
+    let wall = Instant::now();
+    thread::sleep(Duration::from_millis(100));
+    assert!(wall.elapsed() >= Duration::from_millis(100));
+    assert!(cpu.elapsed() < Duration::from_millis(50));
+}
+
+#[test]
+fn cpu_time_advances_when_busy() {
+    let process = ProcessCpuTime::now();
+    let cpu = ThreadCpuTime::now();
+    spin(Duration::from_millis(20));
+    assert!(cpu.elapsed() > Duration::ZERO);
+    // The process and thread clocks can have a different granularity, so
+    // they aren't compared with each other.
+    assert!(process.elapsed() > Duration::ZERO);
+    assert!(ProcessCpuTime::now().as_duration() >= process.as_duration());
+}
+
+#[test]
+fn cpu_time_of_other_thread() {
+    let (tx, rx) = crate::sync::mpsc::channel();
+    let (done_tx, done_rx) = crate::sync::mpsc::channel::<()>();
+    let handle = thread::spawn(move || {
+        spin(Duration::from_millis(20));
+        tx.send(ThreadCpuTime::now().as_duration()).unwrap();
+        done_rx.recv().unwrap();
+    });
+    let seen = rx.recv().unwrap();
+    let total = ThreadCpuTime::of(&handle).unwrap();
+    assert!(total >= seen);
+    done_tx.send(()).unwrap();
+    handle.join().unwrap();
+}
@@ -13,11 +13,13 @@
 use core::panic::{BoxMeUp, Location, PanicInfo};
 
 use crate::any::Any;
+use crate::backtrace::Backtrace;
 use crate::fmt;
 use crate::intrinsics;
+use crate::lazy::OnceCell;
 use crate::mem::{self, ManuallyDrop};
 use crate::process;
-use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use crate::sys::stdio::panic_output;
 use crate::sys_common::backtrace;
 use crate::sys_common::rwlock::StaticRwLock;
@@ -260,6 +262,237 @@ pub fn update_hook<F>(hook_fn: F)
     }
 }
 
+/// A hook registered with [`push_hook`], along with the hook it wraps.
+type ChainedHook =
+    dyn Fn(&(dyn Fn(&PanicHookInfo<'_>)), &PanicHookInfo<'_>) + 'static + Sync + Send;
+
+// The stack of hooks pushed with `push_hook`, innermost first. The bottom of
+// the stack calls through to `HOOK`. Like `HOOK`, this is protected by
+// `HOOK_LOCK`.
+static mut HOOK_CHAIN: Vec<Box<ChainedHook>> = Vec::new();
+
+// Number of panics that have invoked the panic hook since the process started.
+static TOTAL_PANIC_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Information about a panic, passed to hooks registered with [`push_hook`].
+///
+/// In addition to the [`PanicInfo`] that is given to the hook installed with
+/// [`set_hook`], this carries the panicking thread, a lazily captured
+/// [`Backtrace`] and the number of panics the process has seen so far.
+///
+/// [`set_hook`]: ./fn.set_hook.html
+/// [`push_hook`]: ./fn.push_hook.html
+#[unstable(feature = "panic_hook_chain", issue = "none")]
+pub struct PanicHookInfo<'a> {
+    info: &'a PanicInfo<'a>,
+    thread: Option<thread::Thread>,
+    backtrace: OnceCell<Backtrace>,
+    count: usize,
+}
+
+impl<'a> PanicHookInfo<'a> {
+    fn new(info: &'a PanicInfo<'a>, count: usize) -> Self {
+        PanicHookInfo {
+            info,
+            thread: thread_info::current_thread(),
+            backtrace: OnceCell::new(),
+            count,
+        }
+    }
+
+    /// Returns the [`PanicInfo`] describing the payload and location of the panic.
+    #[must_use]
+    #[unstable(feature = "panic_hook_chain", issue = "none")]
+    pub fn panic_info(&self) -> &PanicInfo<'a> {
+        self.info
+    }
+
+    /// Returns the payload associated with the panic.
+    ///
+    /// This is a shorthand for `self.panic_info().payload()`.
+    #[must_use]
+    #[unstable(feature = "panic_hook_chain", issue = "none")]
+    pub fn payload(&self) -> &(dyn Any + Send) {
+        self.info.payload()
+    }
+
+    /// Returns information about the location from which the panic originated.
+    ///
+    /// This is a shorthand for `self.panic_info().location()`.
+    #[must_use]
+    #[unstable(feature = "panic_hook_chain", issue = "none")]
+    pub fn location(&self) -> Option<&Location<'_>> {
+        self.info.location()
+    }
+
+    /// Returns the name of the panicking thread, if it has one.
+    ///
+    /// Returns `None` for unnamed threads, and for panics that happen while
+    /// the thread's runtime information is unavailable (for example during
+    /// thread-local destruction).
+    #[must_use]
+    #[unstable(feature = "panic_hook_chain", issue = "none")]
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread.as_ref().and_then(|t| t.name())
+    }
+
+    /// Returns a backtrace of the panicking thread.
+    ///
+    /// The backtrace is captured with [`Backtrace::capture`] the first time
+    /// this method is called, so it honors the `RUST_BACKTRACE` and
+    /// `RUST_LIB_BACKTRACE` environment variables, and hooks that never ask
+    /// for it don't pay for it. All hooks in the chain share the same capture.
+    #[must_use]
+    #[unstable(feature = "panic_hook_chain", issue = "none")]
+    pub fn backtrace(&self) -> &Backtrace {
+        self.backtrace.get_or_init(Backtrace::capture)
+    }
+
+    /// Returns the number of panics that have reached the panic hook since the
+    /// process started, including this one.
+    ///
+    /// The first panic observed by the process reports `1`. Panics started
+    /// with [`resume_unwind`] don't invoke the hook and aren't counted.
+    ///
+    /// [`resume_unwind`]: crate::panic::resume_unwind
+    #[must_use]
+    #[unstable(feature = "panic_hook_chain", issue = "none")]
+    pub fn panic_count(&self) -> usize {
+        self.count
+    }
+}
+
+#[unstable(feature = "panic_hook_chain", issue = "none")]
+impl fmt::Debug for PanicHookInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PanicHookInfo")
+            .field("panic_info", &self.info)
+            .field("thread_name", &self.thread_name())
+            .field("panic_count", &self.count)
+            .finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "panic_hook_chain", issue = "none")]
+impl fmt::Display for PanicHookInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.thread_name().unwrap_or("<unnamed>");
+        write!(f, "thread '{name}' {}", self.info)
+    }
+}
+
+/// Pushes a hook onto the panic hook chain.
+///
+/// Unlike [`set_hook`], this doesn't replace the hook that is already
+/// installed. When a thread panics, the most recently pushed hook is called
+/// first, with a function that runs the rest of the chain as its first
+/// argument. The hook decides whether, and when, to call it. The bottom of the
+/// chain is the hook installed with [`set_hook`], which is the default hook
+/// printing to standard error unless it was replaced.
+///
+/// Hooks are given a [`PanicHookInfo`], which additionally exposes the name of
+/// the panicking thread, a backtrace and a process-wide panic counter.
+///
+/// [`set_hook`]: ./fn.set_hook.html
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+///
+/// # Examples
+///
+/// The following will print the custom message, and then the normal output of panic.
+///
+/// ```should_panic
+/// #![feature(panic_hook_chain)]
+/// use std::panic;
+///
+/// panic::push_hook(|prev, info| {
+///     eprintln!("panic #{} on {:?}", info.panic_count(), info.thread_name());
+///     prev(info);
+/// });
+///
+/// panic!("Custom and then normal");
+/// ```
+#[unstable(feature = "panic_hook_chain", issue = "none")]
+pub fn push_hook<F>(hook_fn: F)
+where
+    F: Fn(&(dyn Fn(&PanicHookInfo<'_>)), &PanicHookInfo<'_>) + Sync + Send + 'static,
+{
+    if thread::panicking() {
+        panic!("cannot modify the panic hook from a panicking thread");
+    }
+
+    // SAFETY: `HOOK_CHAIN` can only be modified while holding write access to `HOOK_LOCK`.
+    unsafe {
+        let _guard = HOOK_LOCK.write();
+        HOOK_CHAIN.push(Box::new(hook_fn));
+    }
+}
+
+/// Removes the most recently pushed hook from the panic hook chain, returning it.
+///
+/// Returns `None` if the chain is empty. This never touches the hook installed
+/// with [`set_hook`].
+///
+/// *See also the function [`push_hook`].*
+///
+/// [`set_hook`]: ./fn.set_hook.html
+/// [`push_hook`]: ./fn.push_hook.html
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+///
+/// # Examples
+///
+/// The following will only print "Normal panic":
+///
+/// ```should_panic
+/// #![feature(panic_hook_chain)]
+/// use std::panic;
+///
+/// panic::push_hook(|_, _| println!("Custom panic hook"));
+/// assert!(panic::pop_hook().is_some());
+/// assert!(panic::pop_hook().is_none());
+///
+/// panic!("Normal panic");
+/// ```
+#[must_use]
+#[unstable(feature = "panic_hook_chain", issue = "none")]
+pub fn pop_hook(
+) -> Option<Box<dyn Fn(&(dyn Fn(&PanicHookInfo<'_>)), &PanicHookInfo<'_>) + 'static + Sync + Send>>
+{
+    if thread::panicking() {
+        panic!("cannot modify the panic hook from a panicking thread");
+    }
+
+    // SAFETY: `HOOK_CHAIN` can only be modified while holding write access to `HOOK_LOCK`.
+    unsafe {
+        let guard = HOOK_LOCK.write();
+        let hook = HOOK_CHAIN.pop();
+        drop(guard);
+        hook
+    }
+}
+
+/// Runs `chain` from the top, ending with the hook installed with `set_hook`.
+///
+/// # Safety
+///
+/// Must be called with read access to `HOOK_LOCK` held.
+unsafe fn call_hook_chain(chain: &[Box<ChainedHook>], info: &PanicHookInfo<'_>) {
+    match chain.split_last() {
+        // SAFETY: the caller holds `HOOK_LOCK`, which is all `call_hook_chain` needs.
+        Some((top, rest)) => top(&|info| unsafe { call_hook_chain(rest, info) }, info),
+        // SAFETY: `HOOK` is protected by the same lock as `HOOK_CHAIN`.
+        None => match unsafe { HOOK } {
+            Hook::Default => default_hook(info.panic_info()),
+            Hook::Custom(ptr) => unsafe { (*ptr)(info.panic_info()) },
+        },
+    }
+}
+
 fn default_hook(info: &PanicInfo<'_>) {
     // If this is a double panic, make sure that we print a backtrace
     // for this panic. Otherwise only print it if logging is enabled.
@@ -684,24 +917,30 @@ fn rust_panic_with_hook(
 
     unsafe {
         let mut info = PanicInfo::internal_constructor(message, location, can_unwind);
+        let count = TOTAL_PANIC_COUNT.fetch_add(1, Ordering::Relaxed) + 1;
         let _guard = HOOK_LOCK.read();
-        match HOOK {
-            // Some platforms (like wasm) know that printing to stderr won't ever actually
-            // print anything, and if that's the case we can skip the default
-            // hook. Since string formatting happens lazily when calling `payload`
-            // methods, this means we avoid formatting the string at all!
-            // (The panic runtime might still call `payload.take_box()` though and trigger
-            // formatting.)
-            Hook::Default if panic_output().is_none() => {}
-            Hook::Default => {
-                info.set_payload(payload.get());
-                default_hook(&info);
-            }
-            Hook::Custom(ptr) => {
-                info.set_payload(payload.get());
-                (*ptr)(&info);
-            }
-        };
+        if !HOOK_CHAIN.is_empty() {
+            info.set_payload(payload.get());
+            call_hook_chain(&HOOK_CHAIN, &PanicHookInfo::new(&info, count));
+        } else {
+            match HOOK {
+                // Some platforms (like wasm) know that printing to stderr won't ever actually
+                // print anything, and if that's the case we can skip the default
+                // hook. Since string formatting happens lazily when calling `payload`
+                // methods, this means we avoid formatting the string at all!
+                // (The panic runtime might still call `payload.take_box()` though and trigger
+                // formatting.)
+                Hook::Default if panic_output().is_none() => {}
+                Hook::Default => {
+                    info.set_payload(payload.get());
+                    default_hook(&info);
+                }
+                Hook::Custom(ptr) => {
+                    info.set_payload(payload.get());
+                    (*ptr)(&info);
+                }
+            };
+        }
     }
 
     if panics > 1 || !can_unwind {
@@ -130,12 +130,18 @@
 //!   inter-thread synchronisation mechanism, at the cost of some
 //!   extra memory.
 //!
+//! - [`mpmc`]: Multi-producer, multi-consumer queues, which also allow
+//!   waiting on several channels at once with `Select`.
+//!
 //! - [`Mutex`]: Mutual Exclusion mechanism, which ensures that at
 //!   most one thread at a time is able to access some data.
 //!
 //! - [`Once`]: Used for thread-safe, one-time initialization of a
 //!   global variable.
 //!
+//! - [`ReentrantMutex`]: A mutex that can be locked again by the thread
+//!   already holding it, giving out shared access only.
+//!
 //! - [`RwLock`]: Provides a mutual exclusion mechanism which allows
 //!   multiple readers at the same time, while allowing only one
 //!   writer at a time. In some cases, this can be more efficient than
@@ -145,8 +151,10 @@
 //! [`Barrier`]: crate::sync::Barrier
 //! [`Condvar`]: crate::sync::Condvar
 //! [`mpsc`]: crate::sync::mpsc
+//! [`mpmc`]: crate::sync::mpmc
 //! [`Mutex`]: crate::sync::Mutex
 //! [`Once`]: crate::sync::Once
+//! [`ReentrantMutex`]: crate::sync::ReentrantMutex
 //! [`RwLock`]: crate::sync::RwLock
 
 #![stable(feature = "rust1", since = "1.0.0")]
@@ -167,9 +175,14 @@
 pub use self::once::{Once, OnceState, ONCE_INIT};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+pub use self::remutex::{ReentrantMutex, ReentrantMutexGuard};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+pub use self::rwlock::RwLockUpgradableReadGuard;
 
+pub mod mpmc;
 pub mod mpsc;
 
 mod barrier;
@@ -177,4 +190,5 @@
 mod mutex;
 mod once;
 mod poison;
+mod remutex;
 mod rwlock;
@@ -0,0 +1,313 @@
+use super::*;
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::{Arc, Barrier};
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+#[test]
+fn smoke_all_flavors() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv().unwrap(), 1);
+    assert_eq!(tx.capacity(), None);
+
+    let (tx, rx) = sync_channel::<i32>(1);
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv().unwrap(), 1);
+    assert_eq!(tx.capacity(), Some(1));
+
+    let (tx, rx) = sync_channel::<i32>(0);
+    let t = thread::spawn(move || tx.send(1).unwrap());
+    assert_eq!(rx.recv().unwrap(), 1);
+    t.join().unwrap();
+}
+
+#[test]
+fn len_and_capacity() {
+    let (tx, rx) = sync_channel(2);
+    assert!(tx.is_empty() && !tx.is_full());
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    assert_eq!(rx.len(), 2);
+    assert!(rx.is_full());
+    assert_eq!(tx.try_send(3), Err(TrySendError::Full(3)));
+    assert_eq!(rx.try_recv(), Ok(1));
+    assert_eq!(rx.len(), 1);
+
+    let (tx, rx) = channel();
+    for i in 0..100 {
+        tx.send(i).unwrap();
+    }
+    assert_eq!(rx.len(), 100);
+    assert_eq!(rx.try_iter().count(), 100);
+    assert!(rx.is_empty());
+}
+
+#[test]
+fn multiple_consumers() {
+    const COUNT: usize = 10_000;
+    const THREADS: usize = 4;
+
+    for (tx, rx) in [channel(), sync_channel(3), sync_channel(0)] {
+        let received = Arc::new(AtomicUsize::new(0));
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let rx = rx.clone();
+                let received = received.clone();
+                thread::spawn(move || {
+                    for i in rx.iter() {
+                        received.fetch_add(i, Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+        drop(rx);
+
+        for i in 0..COUNT {
+            tx.send(i).unwrap();
+        }
+        drop(tx);
+
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(received.load(Ordering::Relaxed), COUNT * (COUNT - 1) / 2);
+    }
+}
+
+#[test]
+fn disconnect_wakes_receivers() {
+    for (tx, rx) in [channel::<i32>(), sync_channel(1), sync_channel(0)] {
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let rx = rx.clone();
+                thread::spawn(move || rx.recv())
+            })
+            .collect();
+        thread::sleep(ms(10));
+        drop(tx);
+        for h in handles {
+            assert_eq!(h.join().unwrap(), Err(RecvError));
+        }
+    }
+}
+
+#[test]
+fn disconnect_wakes_senders() {
+    for (tx, rx) in [sync_channel::<i32>(1), sync_channel(0)] {
+        if tx.capacity() == Some(1) {
+            tx.send(0).unwrap();
+        }
+        let handles: Vec<_> = (0..3)
+            .map(|i| {
+                let tx = tx.clone();
+                thread::spawn(move || tx.send(i))
+            })
+            .collect();
+        thread::sleep(ms(10));
+        drop(rx);
+        for (i, h) in handles.into_iter().enumerate() {
+            assert_eq!(h.join().unwrap(), Err(SendError(i as i32)));
+        }
+    }
+}
+
+#[test]
+fn buffered_messages_outlive_senders() {
+    let (tx, rx) = sync_channel(4);
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    drop(tx);
+    assert_eq!(rx.recv(), Ok(1));
+    assert_eq!(rx.recv(), Ok(2));
+    assert_eq!(rx.recv(), Err(RecvError));
+}
+
+#[test]
+fn send_timeout() {
+    let (tx, rx) = sync_channel(1);
+    tx.send_timeout(1, ms(10)).unwrap();
+
+    let start = Instant::now();
+    assert_eq!(tx.send_timeout(2, ms(10)), Err(SendTimeoutError::Timeout(2)));
+    assert!(start.elapsed() >= ms(10));
+
+    let (zx, zr) = sync_channel(0);
+    assert_eq!(zx.send_timeout(3, ms(10)), Err(SendTimeoutError::Timeout(3)));
+
+    drop(rx);
+    drop(zr);
+    assert_eq!(tx.send_timeout(4, ms(10)), Err(SendTimeoutError::Disconnected(4)));
+    assert_eq!(zx.send_timeout(5, ms(10)), Err(SendTimeoutError::Disconnected(5)));
+}
+
+#[test]
+fn recv_timeout() {
+    for (tx, rx) in [channel::<i32>(), sync_channel(1), sync_channel(0)] {
+        let start = Instant::now();
+        assert_eq!(rx.recv_timeout(ms(10)), Err(RecvTimeoutError::Timeout));
+        assert!(start.elapsed() >= ms(10));
+        drop(tx);
+        assert_eq!(rx.recv_timeout(ms(10)), Err(RecvTimeoutError::Disconnected));
+    }
+}
+
+#[test]
+fn drops_unreceived_messages() {
+    struct DropCounter<'a>(&'a AtomicUsize);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    for cap in [None, Some(100)] {
+        let drops = AtomicUsize::new(0);
+        {
+            let (tx, rx) = match cap {
+                None => channel(),
+                Some(cap) => sync_channel(cap),
+            };
+            // Cross a block boundary of the unbounded flavor.
+            for _ in 0..70 {
+                tx.send(DropCounter(&drops)).unwrap();
+            }
+            drop(rx.recv().unwrap());
+            assert_eq!(drops.load(Ordering::SeqCst), 1);
+        }
+        assert_eq!(drops.load(Ordering::SeqCst), 70);
+    }
+}
+
+#[test]
+fn same_channel() {
+    let (tx1, rx1) = channel::<()>();
+    let (tx2, rx2) = channel::<()>();
+    assert!(tx1.same_channel(&tx1.clone()));
+    assert!(!tx1.same_channel(&tx2));
+    assert!(rx1.same_channel(&rx1.clone()));
+    assert!(!rx1.same_channel(&rx2));
+}
+
+#[test]
+fn select_recv() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = sync_channel::<i32>(0);
+
+    let t = thread::spawn(move || {
+        thread::sleep(ms(10));
+        tx2.send(2).unwrap();
+        drop(tx1);
+    });
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    assert_eq!(oper.recv(&rx2), Ok(2));
+
+    let oper = sel.select();
+    match oper.index() {
+        i if i == oper1 => assert_eq!(oper.recv(&rx1), Err(RecvError)),
+        i if i == oper2 => assert_eq!(oper.recv(&rx2), Err(RecvError)),
+        _ => unreachable!(),
+    }
+    t.join().unwrap();
+}
+
+#[test]
+fn select_send() {
+    let (tx1, rx1) = sync_channel::<i32>(1);
+    let (tx2, rx2) = sync_channel::<i32>(0);
+    tx1.send(0).unwrap();
+
+    let t = thread::spawn(move || {
+        thread::sleep(ms(10));
+        assert_eq!(rx2.recv(), Ok(2));
+        drop(rx1);
+    });
+
+    let mut sel = Select::new();
+    let oper1 = sel.send(&tx1);
+    let oper2 = sel.send(&tx2);
+
+    // Only the rendezvous channel gets a receiver.
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    oper.send(&tx2, 2).unwrap();
+    t.join().unwrap();
+
+    sel.remove(oper2);
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.send(&tx1, 1), Err(SendError(1)));
+}
+
+#[test]
+fn select_try_and_timeout() {
+    let (tx, rx) = channel::<i32>();
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx);
+    assert_eq!(sel.try_select().err(), Some(TrySelectError));
+
+    let start = Instant::now();
+    assert_eq!(sel.select_timeout(ms(10)).err(), Some(SelectTimeoutError));
+    assert!(start.elapsed() >= ms(10));
+
+    tx.send(1).unwrap();
+    let oper = sel.try_select().unwrap();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.recv(&rx), Ok(1));
+
+    // An empty `Select` never selects anything.
+    let mut empty = Select::new();
+    assert!(empty.try_select().is_err());
+    assert!(empty.select_timeout(ms(1)).is_err());
+}
+
+#[test]
+fn select_rendezvous_pairs() {
+    const COUNT: usize = 1000;
+
+    let (tx, rx) = sync_channel::<usize>(0);
+    let barrier = Arc::new(Barrier::new(2));
+
+    let b = barrier.clone();
+    let t = thread::spawn(move || {
+        b.wait();
+        for i in 0..COUNT {
+            let mut sel = Select::new();
+            sel.send(&tx);
+            let oper = sel.select();
+            oper.send(&tx, i).unwrap();
+        }
+    });
+
+    barrier.wait();
+    for i in 0..COUNT {
+        let mut sel = Select::new();
+        sel.recv(&rx);
+        let oper = sel.select();
+        assert_eq!(oper.recv(&rx), Ok(i));
+    }
+    t.join().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "dropped `SelectedOperation` without completing the operation")]
+fn select_unfinished_operation() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+
+    let mut sel = Select::new();
+    sel.recv(&rx);
+    let _oper = sel.select();
+}
@@ -6,6 +6,7 @@
 use crate::ops::{Deref, DerefMut};
 use crate::sync::{poison, LockResult, TryLockError, TryLockResult};
 use crate::sys_common::mutex as sys;
+use crate::time::{Duration, Instant};
 
 /// A mutual exclusion primitive useful for protecting shared data
 ///
@@ -259,6 +260,7 @@ impl<T: ?Sized> Mutex<T> {
     /// assert_eq!(*mutex.lock().unwrap(), 10);
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
         unsafe {
             self.inner.raw_lock();
@@ -316,6 +318,101 @@ pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
         }
     }
 
+    /// Attempts to acquire this lock, blocking the current thread for at most
+    /// `timeout`.
+    ///
+    /// If the lock could not be acquired before the timeout expired, then
+    /// [`Err`] is returned. Otherwise, an RAII guard is returned. The lock will
+    /// be unlocked when the guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// If another user of this mutex panicked while holding the mutex, then
+    /// this call will return the [`Poisoned`] error if the mutex would
+    /// otherwise be acquired.
+    ///
+    /// If the mutex could not be acquired because it remained locked for the
+    /// whole `timeout`, then this call will return the [`WouldBlock`] error.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On platforms where the underlying lock can't wait with a timeout, this
+    /// instead repeatedly tries to acquire the lock, sleeping in between, so it
+    /// may take slightly longer than `timeout` to acquire a lock that became
+    /// available in the meantime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lock_timeout)]
+    ///
+    /// use std::sync::{Arc, Mutex, TryLockError};
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// let mutex = Arc::new(Mutex::new(0));
+    /// let c_mutex = Arc::clone(&mutex);
+    ///
+    /// let guard = mutex.lock().unwrap();
+    /// thread::spawn(move || {
+    ///     // The main thread holds the lock until this thread has finished.
+    ///     let lock = c_mutex.try_lock_for(Duration::from_millis(10));
+    ///     assert!(matches!(lock, Err(TryLockError::WouldBlock)));
+    /// }).join().expect("thread::spawn failed");
+    /// drop(guard);
+    ///
+    /// *mutex.try_lock_for(Duration::from_millis(10)).unwrap() = 10;
+    /// assert_eq!(*mutex.lock().unwrap(), 10);
+    /// ```
+    #[unstable(feature = "lock_timeout", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn try_lock_for(&self, timeout: Duration) -> TryLockResult<MutexGuard<'_, T>> {
+        unsafe {
+            if self.inner.raw_lock_timeout(timeout) {
+                Ok(MutexGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
+    /// Attempts to acquire this lock, blocking the current thread until at most
+    /// `deadline`.
+    ///
+    /// This behaves like [`try_lock_for`] with the time left until `deadline`
+    /// as the timeout. If `deadline` has already passed, this behaves like
+    /// [`try_lock`].
+    ///
+    /// [`try_lock_for`]: Mutex::try_lock_for
+    /// [`try_lock`]: Mutex::try_lock
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`try_lock_for`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lock_timeout)]
+    ///
+    /// use std::sync::Mutex;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mutex = Mutex::new(0);
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    ///
+    /// *mutex.try_lock_until(deadline).unwrap() = 10;
+    /// assert_eq!(*mutex.lock().unwrap(), 10);
+    /// ```
+    #[unstable(feature = "lock_timeout", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn try_lock_until(&self, deadline: Instant) -> TryLockResult<MutexGuard<'_, T>> {
+        self.try_lock_for(deadline.saturating_duration_since(Instant::now()))
+    }
+
     /// Immediately drops the guard, and consequently unlocks the mutex.
     ///
     /// This function is equivalent to calling [`drop`] on the guard but is more self-documenting.
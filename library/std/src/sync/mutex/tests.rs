@@ -1,7 +1,8 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, Condvar, Mutex};
+use crate::sync::{Arc, Condvar, Mutex, TryLockError};
 use crate::thread;
+use crate::time::{Duration, Instant};
 
 struct Packet<T>(Arc<(Mutex<T>, Condvar)>);
 
@@ -236,3 +237,54 @@ fn test_mutex_unsized() {
     let comp: &[i32] = &[4, 2, 5];
     assert_eq!(&*mutex.lock().unwrap(), comp);
 }
+
+#[test]
+fn test_try_lock_for() {
+    let m = Arc::new(Mutex::new(0));
+    let m2 = m.clone();
+
+    let guard = m.lock().unwrap();
+    let start = Instant::now();
+    thread::spawn(move || {
+        let r = m2.try_lock_for(Duration::from_millis(20));
+        assert!(matches!(r, Err(TryLockError::WouldBlock)));
+    })
+    .join()
+    .unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    drop(guard);
+
+    *m.try_lock_for(Duration::from_millis(20)).unwrap() = 1;
+    *m.try_lock_until(Instant::now()).unwrap() += 1;
+    assert_eq!(*m.lock().unwrap(), 2);
+}
+
+#[test]
+fn test_try_lock_for_unlocked_in_time() {
+    let m = Arc::new(Mutex::new(0));
+    let m2 = m.clone();
+    let (tx, rx) = channel();
+
+    let h = thread::spawn(move || {
+        let mut lock = m2.lock().unwrap();
+        tx.send(()).unwrap();
+        thread::sleep(Duration::from_millis(10));
+        *lock = 1;
+    });
+    rx.recv().unwrap();
+    assert_eq!(*m.try_lock_for(Duration::from_secs(60)).unwrap(), 1);
+    h.join().unwrap();
+}
+
+#[test]
+fn test_try_lock_for_poison() {
+    let m = Arc::new(Mutex::new(1));
+    let m2 = m.clone();
+    let _ = thread::spawn(move || {
+        let _lock = m2.lock().unwrap();
+        panic!("test panic in inner thread to poison mutex");
+    })
+    .join();
+
+    assert!(matches!(m.try_lock_for(Duration::from_millis(1)), Err(TryLockError::Poisoned(_))));
+}
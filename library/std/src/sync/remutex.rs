@@ -0,0 +1,344 @@
+#[cfg(all(test, not(target_os = "emscripten")))]
+mod tests;
+
+use crate::cell::UnsafeCell;
+use crate::fmt;
+use crate::ops::Deref;
+use crate::panic::{RefUnwindSafe, UnwindSafe};
+use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use crate::sys_common::mutex as sys;
+use crate::sys_common::remutex::current_thread_unique_ptr;
+
+/// A re-entrant mutual exclusion primitive useful for protecting shared data
+///
+/// This mutex will block *other* threads waiting for the lock to become
+/// available. The thread which has already locked the mutex can lock it
+/// multiple times without blocking, preventing a common source of deadlocks.
+///
+/// Because the same thread can hold several guards at once, the guards only
+/// give out shared references to the protected data. Use interior mutability
+/// (usually [`RefCell`] or [`Cell`]) to mutate it.
+///
+/// Unlike [`Mutex`], this mutex does not implement poisoning: a panic while
+/// the lock is held simply unlocks it.
+///
+/// [`RefCell`]: crate::cell::RefCell
+/// [`Cell`]: crate::cell::Cell
+/// [`Mutex`]: crate::sync::Mutex
+///
+/// # Examples
+///
+/// ```
+/// #![feature(reentrant_mutex)]
+///
+/// use std::cell::RefCell;
+/// use std::sync::{Arc, ReentrantMutex};
+/// use std::thread;
+///
+/// let log = Arc::new(ReentrantMutex::new(RefCell::new(Vec::new())));
+///
+/// fn record(log: &ReentrantMutex<RefCell<Vec<u32>>>, n: u32) {
+///     log.lock().borrow_mut().push(n);
+/// }
+///
+/// let log2 = Arc::clone(&log);
+/// thread::spawn(move || {
+///     // Keep the lock for both entries, so they end up next to each other.
+///     let guard = log2.lock();
+///     record(&log2, 1);
+///     record(&log2, 2);
+///     drop(guard);
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert_eq!(*log.lock().borrow(), [1, 2]);
+/// ```
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+pub struct ReentrantMutex<T: ?Sized> {
+    mutex: sys::MovableMutex,
+    // The address of `current_thread_unique_ptr()` of the thread holding the
+    // lock, or zero. Only compared with the current thread's own value, so
+    // relaxed accesses are enough; see `sys_common::remutex` for details.
+    owner: AtomicUsize,
+    // Only accessed by the thread holding the lock.
+    lock_count: UnsafeCell<u32>,
+    data: T,
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+unsafe impl<T: ?Sized + Send> Send for ReentrantMutex<T> {}
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+unsafe impl<T: ?Sized + Send> Sync for ReentrantMutex<T> {}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized + UnwindSafe> UnwindSafe for ReentrantMutex<T> {}
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized + UnwindSafe> RefUnwindSafe for ReentrantMutex<T> {}
+
+/// An RAII implementation of a "scoped lock" of a re-entrant mutex. When this
+/// structure is dropped (falls out of scope), the lock will be unlocked, unless
+/// the same thread still holds other guards for it.
+///
+/// The data protected by the mutex can be accessed through this guard via its
+/// [`Deref`] implementation.
+///
+/// This structure is created by the [`lock`] and [`try_lock`] methods on
+/// [`ReentrantMutex`].
+///
+/// [`lock`]: ReentrantMutex::lock
+/// [`try_lock`]: ReentrantMutex::try_lock
+#[must_use = "if unused the ReentrantMutex will immediately unlock"]
+#[must_not_suspend = "holding a ReentrantMutexGuard across suspend \
+                      points can cause deadlocks, delays, \
+                      and cause Futures to not implement `Send`"]
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+pub struct ReentrantMutexGuard<'a, T: ?Sized + 'a> {
+    lock: &'a ReentrantMutex<T>,
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized> !Send for ReentrantMutexGuard<'_, T> {}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+unsafe impl<T: ?Sized + Sync> Sync for ReentrantMutexGuard<'_, T> {}
+
+impl<T> ReentrantMutex<T> {
+    /// Creates a new re-entrant mutex in an unlocked state ready for use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(reentrant_mutex)]
+    ///
+    /// use std::sync::ReentrantMutex;
+    ///
+    /// let mutex = ReentrantMutex::new(0);
+    /// ```
+    #[unstable(feature = "reentrant_mutex", issue = "none")]
+    pub fn new(t: T) -> ReentrantMutex<T> {
+        ReentrantMutex {
+            mutex: sys::MovableMutex::new(),
+            owner: AtomicUsize::new(0),
+            lock_count: UnsafeCell::new(0),
+            data: t,
+        }
+    }
+
+    /// Consumes this mutex, returning the underlying data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(reentrant_mutex)]
+    ///
+    /// use std::sync::ReentrantMutex;
+    ///
+    /// let mutex = ReentrantMutex::new(0);
+    /// assert_eq!(mutex.into_inner(), 0);
+    /// ```
+    #[unstable(feature = "reentrant_mutex", issue = "none")]
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T: ?Sized> ReentrantMutex<T> {
+    /// Acquires the mutex, blocking the current thread until it is able to do
+    /// so.
+    ///
+    /// If the current thread already holds the lock, this returns another
+    /// guard right away instead of blocking. The mutex is unlocked once all
+    /// guards of the thread have been dropped.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the current thread already holds the lock
+    /// `u32::MAX` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(reentrant_mutex)]
+    ///
+    /// use std::sync::ReentrantMutex;
+    ///
+    /// let mutex = ReentrantMutex::new(1);
+    ///
+    /// let a = mutex.lock();
+    /// let b = mutex.lock();
+    /// assert_eq!(*a + *b, 2);
+    /// ```
+    #[unstable(feature = "reentrant_mutex", issue = "none")]
+    pub fn lock(&self) -> ReentrantMutexGuard<'_, T> {
+        let this_thread = current_thread_unique_ptr();
+        // Safety: We only touch lock_count when we own the lock.
+        unsafe {
+            if self.owner.load(Relaxed) == this_thread {
+                self.increment_lock_count();
+            } else {
+                self.mutex.raw_lock();
+                self.owner.store(this_thread, Relaxed);
+                debug_assert_eq!(*self.lock_count.get(), 0);
+                *self.lock_count.get() = 1;
+            }
+        }
+        ReentrantMutexGuard { lock: self }
+    }
+
+    /// Attempts to acquire the mutex.
+    ///
+    /// If the mutex is held by another thread, then [`None`] is returned.
+    /// Otherwise, an RAII guard is returned. Like [`lock`], this succeeds
+    /// when the current thread already holds the lock.
+    ///
+    /// This function does not block.
+    ///
+    /// [`lock`]: ReentrantMutex::lock
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the current thread already holds the lock
+    /// `u32::MAX` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(reentrant_mutex)]
+    ///
+    /// use std::sync::{Arc, ReentrantMutex};
+    /// use std::thread;
+    ///
+    /// let mutex = Arc::new(ReentrantMutex::new(0));
+    /// let guard = mutex.lock();
+    ///
+    /// assert!(mutex.try_lock().is_some());
+    ///
+    /// let mutex2 = Arc::clone(&mutex);
+    /// thread::spawn(move || assert!(mutex2.try_lock().is_none())).join().unwrap();
+    /// drop(guard);
+    /// ```
+    #[unstable(feature = "reentrant_mutex", issue = "none")]
+    pub fn try_lock(&self) -> Option<ReentrantMutexGuard<'_, T>> {
+        let this_thread = current_thread_unique_ptr();
+        // Safety: We only touch lock_count when we own the lock.
+        unsafe {
+            if self.owner.load(Relaxed) == this_thread {
+                self.increment_lock_count();
+                Some(ReentrantMutexGuard { lock: self })
+            } else if self.mutex.try_lock() {
+                self.owner.store(this_thread, Relaxed);
+                debug_assert_eq!(*self.lock_count.get(), 0);
+                *self.lock_count.get() = 1;
+                Some(ReentrantMutexGuard { lock: self })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call borrows the `ReentrantMutex` mutably, no actual locking
+    /// needs to take place -- the mutable borrow statically guarantees no
+    /// locks exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(reentrant_mutex)]
+    ///
+    /// use std::sync::ReentrantMutex;
+    ///
+    /// let mut mutex = ReentrantMutex::new(0);
+    /// *mutex.get_mut() = 10;
+    /// assert_eq!(*mutex.lock(), 10);
+    /// ```
+    #[unstable(feature = "reentrant_mutex", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    unsafe fn increment_lock_count(&self) {
+        *self.lock_count.get() = (*self.lock_count.get())
+            .checked_add(1)
+            .expect("lock count overflow in reentrant mutex");
+    }
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for ReentrantMutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("ReentrantMutex");
+        match self.try_lock() {
+            Some(guard) => {
+                d.field("data", &&*guard);
+            }
+            None => {
+                struct LockedPlaceholder;
+                impl fmt::Debug for LockedPlaceholder {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str("<locked>")
+                    }
+                }
+                d.field("data", &LockedPlaceholder);
+            }
+        }
+        d.finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: Default> Default for ReentrantMutex<T> {
+    /// Creates a `ReentrantMutex<T>`, with the `Default` value for T.
+    fn default() -> ReentrantMutex<T> {
+        ReentrantMutex::new(Default::default())
+    }
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T> From<T> for ReentrantMutex<T> {
+    /// Creates a new mutex in an unlocked state ready for use.
+    /// This is equivalent to [`ReentrantMutex::new`].
+    fn from(t: T) -> Self {
+        ReentrantMutex::new(t)
+    }
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized> Deref for ReentrantMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.lock.data
+    }
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for ReentrantMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized + fmt::Display> fmt::Display for ReentrantMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[unstable(feature = "reentrant_mutex", issue = "none")]
+impl<T: ?Sized> Drop for ReentrantMutexGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        // Safety: We own the lock.
+        unsafe {
+            *self.lock.lock_count.get() -= 1;
+            if *self.lock.lock_count.get() == 0 {
+                self.lock.owner.store(0, Relaxed);
+                self.lock.mutex.raw_unlock();
+            }
+        }
+    }
+}
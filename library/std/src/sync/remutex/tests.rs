@@ -0,0 +1,101 @@
+use crate::cell::RefCell;
+use crate::sync::mpsc::channel;
+use crate::sync::{Arc, ReentrantMutex};
+use crate::thread;
+
+#[test]
+fn smoke() {
+    let m = ReentrantMutex::new(());
+    {
+        let a = m.lock();
+        {
+            let b = m.lock();
+            {
+                let c = m.lock();
+                assert_eq!(*c, ());
+            }
+            assert_eq!(*b, ());
+        }
+        assert_eq!(*a, ());
+    }
+}
+
+#[test]
+fn is_mutex() {
+    let m = Arc::new(ReentrantMutex::new(RefCell::new(0)));
+    let m2 = m.clone();
+    let lock = m.lock();
+    let child = thread::spawn(move || {
+        let lock = m2.lock();
+        assert_eq!(*lock.borrow(), 4950);
+    });
+    for i in 0..100 {
+        let lock = m.lock();
+        *lock.borrow_mut() += i;
+    }
+    drop(lock);
+    child.join().unwrap();
+}
+
+#[test]
+fn trylock_works() {
+    let m = Arc::new(ReentrantMutex::new(()));
+    let m2 = m.clone();
+    let _lock = m.try_lock();
+    let _lock2 = m.try_lock();
+    thread::spawn(move || {
+        let lock = m2.try_lock();
+        assert!(lock.is_none());
+    })
+    .join()
+    .unwrap();
+    let _lock3 = m.try_lock();
+}
+
+#[test]
+fn unlocks_after_last_guard() {
+    let m = Arc::new(ReentrantMutex::new(()));
+    let a = m.lock();
+    let b = m.lock();
+    drop(a);
+
+    let m2 = m.clone();
+    let (tx, rx) = channel();
+    let child = thread::spawn(move || {
+        let _lock = m2.lock();
+        tx.send(()).unwrap();
+    });
+    assert!(rx.try_recv().is_err());
+    drop(b);
+    rx.recv().unwrap();
+    child.join().unwrap();
+}
+
+#[test]
+fn lock_after_panic() {
+    let m = Arc::new(ReentrantMutex::new(1));
+    let m2 = m.clone();
+    let _ = thread::spawn(move || {
+        let _lock = m2.lock();
+        panic!("test panic in inner thread to release the lock");
+    })
+    .join();
+    // No poisoning: the lock is simply released.
+    assert_eq!(*m.lock(), 1);
+}
+
+#[test]
+fn get_mut_and_into_inner() {
+    let mut m = ReentrantMutex::new(RefCell::new(vec![1]));
+    m.get_mut().get_mut().push(2);
+    assert_eq!(m.into_inner().into_inner(), [1, 2]);
+}
+
+#[test]
+fn unsized_data() {
+    let m: &ReentrantMutex<[i32]> = &ReentrantMutex::new([1, 2, 3]);
+    let a = m.lock();
+    let b = m.lock();
+    assert_eq!(&*a, &*b);
+    assert_eq!(format!("{m:?}"), "ReentrantMutex { data: [1, 2, 3], .. }");
+}
@@ -3,9 +3,11 @@
 
 use crate::cell::UnsafeCell;
 use crate::fmt;
+use crate::mem::ManuallyDrop;
 use crate::ops::{Deref, DerefMut};
-use crate::sync::{poison, LockResult, TryLockError, TryLockResult};
+use crate::sync::{poison, LockResult, PoisonError, TryLockError, TryLockResult};
 use crate::sys_common::rwlock as sys;
+use crate::time::{Duration, Instant};
 
 /// A reader-writer lock
 ///
@@ -133,6 +135,35 @@ impl<T: ?Sized> !Send for RwLockWriteGuard<'_, T> {}
 #[stable(feature = "rwlock_guard_sync", since = "1.23.0")]
 unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
 
+/// RAII structure used to release the upgradable read access of a lock when
+/// dropped.
+///
+/// This gives shared access like [`RwLockReadGuard`], but can be turned into
+/// a [`RwLockWriteGuard`] with [`upgrade`] without letting any writer in
+/// between. Only one upgradable guard for a lock can exist at a time, but it
+/// can coexist with any number of [`RwLockReadGuard`]s.
+///
+/// This structure is created by the [`upgradable_read`] and
+/// [`try_upgradable_read`] methods on [`RwLock`].
+///
+/// [`upgrade`]: RwLockUpgradableReadGuard::upgrade
+/// [`upgradable_read`]: RwLock::upgradable_read
+/// [`try_upgradable_read`]: RwLock::try_upgradable_read
+#[must_use = "if unused the RwLock will immediately unlock"]
+#[must_not_suspend = "holding a RwLockUpgradableReadGuard across suspend \
+                      points can cause deadlocks, delays, \
+                      and cause Futures to not implement `Send`"]
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+pub struct RwLockUpgradableReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> !Send for RwLockUpgradableReadGuard<'_, T> {}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+unsafe impl<T: ?Sized + Sync> Sync for RwLockUpgradableReadGuard<'_, T> {}
+
 impl<T> RwLock<T> {
     /// Creates a new instance of an `RwLock<T>` which is unlocked.
     ///
@@ -195,6 +226,7 @@ impl<T: ?Sized> RwLock<T> {
     /// ```
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
         unsafe {
             self.inner.read();
@@ -250,6 +282,93 @@ pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
         }
     }
 
+    /// Attempts to acquire this rwlock with shared access, blocking the
+    /// current thread for at most `timeout`.
+    ///
+    /// If the access could not be granted before the timeout expired, then
+    /// [`Err`] is returned. Otherwise, an RAII guard is returned which will
+    /// release the shared access when it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the [`Poisoned`] error if the RwLock is
+    /// poisoned. An RwLock is poisoned whenever a writer panics while holding
+    /// an exclusive lock. `Poisoned` will only be returned if the lock would
+    /// have otherwise been acquired.
+    ///
+    /// This function will return the [`WouldBlock`] error if the RwLock could
+    /// not be acquired because it remained write locked for the whole `timeout`.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On platforms where the underlying lock can't wait with a timeout, this
+    /// instead repeatedly tries to acquire the lock, sleeping in between, so it
+    /// may take slightly longer than `timeout` to acquire a lock that became
+    /// available in the meantime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lock_timeout)]
+    ///
+    /// use std::sync::RwLock;
+    /// use std::time::Duration;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// match lock.try_read_for(Duration::from_millis(10)) {
+    ///     Ok(n) => assert_eq!(*n, 1),
+    ///     Err(_) => unreachable!(),
+    /// };
+    /// ```
+    #[unstable(feature = "lock_timeout", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn try_read_for(&self, timeout: Duration) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        unsafe {
+            if self.inner.read_timeout(timeout) {
+                Ok(RwLockReadGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
+    /// Attempts to acquire this rwlock with shared access, blocking the
+    /// current thread until at most `deadline`.
+    ///
+    /// This behaves like [`try_read_for`] with the time left until
+    /// `deadline` as the timeout. If `deadline` has already passed, this
+    /// behaves like [`try_read`].
+    ///
+    /// [`try_read_for`]: RwLock::try_read_for
+    /// [`try_read`]: RwLock::try_read
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`try_read_for`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lock_timeout)]
+    ///
+    /// use std::sync::RwLock;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let lock = RwLock::new(1);
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    ///
+    /// assert_eq!(*lock.try_read_until(deadline).unwrap(), 1);
+    /// ```
+    #[unstable(feature = "lock_timeout", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn try_read_until(&self, deadline: Instant) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        self.try_read_for(deadline.saturating_duration_since(Instant::now()))
+    }
+
     /// Locks this rwlock with exclusive write access, blocking the current
     /// thread until it can be acquired.
     ///
@@ -283,6 +402,7 @@ pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
     /// ```
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
         unsafe {
             self.inner.write();
@@ -339,6 +459,197 @@ pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
         }
     }
 
+    /// Attempts to acquire this rwlock with exclusive access, blocking the
+    /// current thread for at most `timeout`.
+    ///
+    /// If the access could not be granted before the timeout expired, then
+    /// [`Err`] is returned. Otherwise, an RAII guard is returned which will
+    /// release the exclusive access when it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the [`Poisoned`] error if the RwLock is
+    /// poisoned. An RwLock is poisoned whenever a writer panics while holding
+    /// an exclusive lock. `Poisoned` will only be returned if the lock would
+    /// have otherwise been acquired.
+    ///
+    /// This function will return the [`WouldBlock`] error if the RwLock could
+    /// not be acquired because it remained locked for the whole `timeout`.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On platforms where the underlying lock can't wait with a timeout, this
+    /// instead repeatedly tries to acquire the lock, sleeping in between, so it
+    /// may take slightly longer than `timeout` to acquire a lock that became
+    /// available in the meantime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lock_timeout)]
+    ///
+    /// use std::sync::RwLock;
+    /// use std::time::Duration;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// match lock.try_write_for(Duration::from_millis(10)) {
+    ///     Ok(n) => assert_eq!(*n, 1),
+    ///     Err(_) => unreachable!(),
+    /// };
+    /// ```
+    #[unstable(feature = "lock_timeout", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn try_write_for(&self, timeout: Duration) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        unsafe {
+            if self.inner.write_timeout(timeout) {
+                Ok(RwLockWriteGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
+    /// Attempts to acquire this rwlock with exclusive access, blocking the
+    /// current thread until at most `deadline`.
+    ///
+    /// This behaves like [`try_write_for`] with the time left until
+    /// `deadline` as the timeout. If `deadline` has already passed, this
+    /// behaves like [`try_write`].
+    ///
+    /// [`try_write_for`]: RwLock::try_write_for
+    /// [`try_write`]: RwLock::try_write
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`try_write_for`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lock_timeout)]
+    ///
+    /// use std::sync::RwLock;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let lock = RwLock::new(1);
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    ///
+    /// assert_eq!(*lock.try_write_until(deadline).unwrap(), 1);
+    /// ```
+    #[unstable(feature = "lock_timeout", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn try_write_until(&self, deadline: Instant) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        self.try_write_for(deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Locks this rwlock with upgradable shared read access, blocking the
+    /// current thread until it can be acquired.
+    ///
+    /// Upgradable read access can be held together with ordinary read access,
+    /// but not with write access or other upgradable read access. The returned
+    /// guard can later be turned into a write guard with
+    /// [`RwLockUpgradableReadGuard::upgrade`], which guarantees that no writer
+    /// modifies the data in between, unlike dropping a read guard and then
+    /// calling [`write`].
+    ///
+    /// [`write`]: RwLock::write
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock.
+    /// The failure will occur immediately after the lock has been acquired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when called if the lock is already held by
+    /// the current thread.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Currently, only the futex-based lock used on Linux and Android has a
+    /// native upgradable mode. On other platforms, upgradable read access
+    /// excludes ordinary readers as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(vec![1, 2]);
+    ///
+    /// let list = lock.upgradable_read().unwrap();
+    /// if !list.contains(&3) {
+    ///     let mut list = RwLockUpgradableReadGuard::upgrade(list);
+    ///     list.push(3);
+    /// }
+    ///
+    /// assert_eq!(*lock.read().unwrap(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn upgradable_read(&self) -> LockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            self.inner.upgradable_read();
+            RwLockUpgradableReadGuard::new(self)
+        }
+    }
+
+    /// Attempts to acquire this rwlock with upgradable shared read access.
+    ///
+    /// If the access could not be granted at this time, then `Err` is returned.
+    /// Otherwise, an RAII guard is returned which will release the upgradable
+    /// access when it is dropped.
+    ///
+    /// This function does not block.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the [`Poisoned`] error if the RwLock is
+    /// poisoned. An RwLock is poisoned whenever a writer panics while holding
+    /// an exclusive lock. `Poisoned` will only be returned if the lock would
+    /// have otherwise been acquired.
+    ///
+    /// This function will return the [`WouldBlock`] error if the RwLock could
+    /// not be acquired because it was already locked exclusively, or another
+    /// thread holds upgradable read access.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let n = lock.try_upgradable_read().unwrap();
+    /// assert_eq!(*n, 1);
+    ///
+    /// assert!(lock.try_upgradable_read().is_err());
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn try_upgradable_read(&self) -> TryLockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_upgradable_read() {
+                Ok(RwLockUpgradableReadGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Determines whether the lock is poisoned.
     ///
     /// If another thread is active, the lock can still become poisoned at any
@@ -479,6 +790,85 @@ unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockWriteGuard<'rwlock,
     }
 }
 
+impl<'rwlock, T: ?Sized> RwLockUpgradableReadGuard<'rwlock, T> {
+    unsafe fn new(
+        lock: &'rwlock RwLock<T>,
+    ) -> LockResult<RwLockUpgradableReadGuard<'rwlock, T>> {
+        poison::map_result(lock.poison.borrow(), |_| RwLockUpgradableReadGuard { lock })
+    }
+
+    /// Turns upgradable read access into write access, blocking the current
+    /// thread until all other readers have released the lock.
+    ///
+    /// No other thread can acquire write access between the creation of the
+    /// upgradable guard and the return of this function, so any data read
+    /// through it is still up to date.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockUpgradableReadGuard::upgrade(guard)`, since a method would
+    /// interfere with methods of the same name on the contents of the lock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let n = lock.upgradable_read().unwrap();
+    /// let mut n = RwLockUpgradableReadGuard::upgrade(n);
+    /// *n += 1;
+    /// drop(n);
+    ///
+    /// assert_eq!(*lock.read().unwrap(), 2);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    #[cfg_attr(not(bootstrap), blocking)]
+    pub fn upgrade(guard: Self) -> RwLockWriteGuard<'rwlock, T> {
+        let lock = ManuallyDrop::new(guard).lock;
+        unsafe {
+            lock.inner.upgrade();
+            // Poisoning can only happen while write locked, so the poison
+            // state hasn't changed since the upgradable guard was created.
+            RwLockWriteGuard::new(lock).unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    /// Attempts to turn upgradable read access into write access.
+    ///
+    /// If other threads still have read access, the upgradable guard is
+    /// returned back in `Err`.
+    ///
+    /// This function does not block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let n = lock.upgradable_read().unwrap();
+    /// let m = RwLockUpgradableReadGuard::try_upgrade(n).unwrap();
+    /// assert_eq!(*m, 1);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn try_upgrade(guard: Self) -> Result<RwLockWriteGuard<'rwlock, T>, Self> {
+        unsafe {
+            if guard.lock.inner.try_upgrade() {
+                let lock = ManuallyDrop::new(guard).lock;
+                Ok(RwLockWriteGuard::new(lock).unwrap_or_else(PoisonError::into_inner))
+            } else {
+                Err(guard)
+            }
+        }
+    }
+}
+
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl<T: fmt::Debug> fmt::Debug for RwLockReadGuard<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -493,6 +883,20 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized + fmt::Display> fmt::Display for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl<T: fmt::Debug> fmt::Debug for RwLockWriteGuard<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -516,6 +920,15 @@ fn deref(&self) -> &T {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> Deref for RwLockUpgradableReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
     type Target = T;
@@ -541,6 +954,15 @@ fn drop(&mut self) {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> Drop for RwLockUpgradableReadGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            self.lock.inner.upgradable_unlock();
+        }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
     fn drop(&mut self) {
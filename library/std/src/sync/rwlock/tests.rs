@@ -1,7 +1,8 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, RwLock, TryLockError};
+use crate::sync::{Arc, RwLock, RwLockUpgradableReadGuard, TryLockError};
 use crate::thread;
+use crate::time::{Duration, Instant};
 use rand::{self, Rng};
 
 #[derive(Eq, PartialEq, Debug)]
@@ -245,3 +246,166 @@ fn test_get_mut_poison() {
         Ok(x) => panic!("get_mut of poisoned RwLock is Ok: {x:?}"),
     }
 }
+
+#[test]
+fn test_rwlock_try_write_for() {
+    let lock = Arc::new(RwLock::new(0isize));
+    let lock2 = lock.clone();
+
+    let read_guard = lock.read().unwrap();
+    let start = Instant::now();
+    thread::spawn(move || {
+        let r = lock2.try_write_for(Duration::from_millis(20));
+        assert!(matches!(r, Err(TryLockError::WouldBlock)));
+        // Readers must not be blocked by the writer that gave up.
+        drop(lock2.try_read_for(Duration::from_millis(20)).unwrap());
+    })
+    .join()
+    .unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    drop(read_guard);
+
+    *lock.try_write_for(Duration::from_millis(20)).unwrap() = 1;
+    *lock.try_write_until(Instant::now()).unwrap() += 1;
+    assert_eq!(*lock.try_read_until(Instant::now()).unwrap(), 2);
+}
+
+#[test]
+fn test_rwlock_try_write_for_wakes_readers() {
+    let lock = Arc::new(RwLock::new(()));
+    let read_guard = lock.read().unwrap();
+
+    let lock2 = lock.clone();
+    let writer = thread::spawn(move || {
+        assert!(lock2.try_write_for(Duration::from_millis(50)).is_err());
+    });
+    // Give the writer some time to start waiting, which blocks new readers.
+    thread::sleep(Duration::from_millis(10));
+    let lock2 = lock.clone();
+    let reader = thread::spawn(move || drop(lock2.read().unwrap()));
+
+    // Once the writer gave up, the reader must get the lock, even though
+    // it is still read locked.
+    writer.join().unwrap();
+    reader.join().unwrap();
+    drop(read_guard);
+}
+
+#[test]
+fn test_rwlock_try_read_for() {
+    let lock = Arc::new(RwLock::new(0isize));
+    let lock2 = lock.clone();
+
+    let write_guard = lock.write().unwrap();
+    thread::spawn(move || {
+        let r = lock2.try_read_for(Duration::from_millis(10));
+        assert!(matches!(r, Err(TryLockError::WouldBlock)));
+    })
+    .join()
+    .unwrap();
+    drop(write_guard);
+
+    let (tx, rx) = channel();
+    let lock2 = lock.clone();
+    let h = thread::spawn(move || {
+        let mut lock = lock2.write().unwrap();
+        tx.send(()).unwrap();
+        thread::sleep(Duration::from_millis(10));
+        *lock = 1;
+    });
+    rx.recv().unwrap();
+    assert_eq!(*lock.try_read_for(Duration::from_secs(60)).unwrap(), 1);
+    h.join().unwrap();
+}
+
+#[test]
+fn test_rwlock_upgradable_read() {
+    let lock = RwLock::new(1);
+    let guard = lock.upgradable_read().unwrap();
+    assert_eq!(*guard, 1);
+    assert!(matches!(lock.try_upgradable_read(), Err(TryLockError::WouldBlock)));
+    assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+
+    let mut guard = RwLockUpgradableReadGuard::try_upgrade(guard).unwrap();
+    *guard = 2;
+    assert!(matches!(lock.try_read(), Err(TryLockError::WouldBlock)));
+    drop(guard);
+
+    assert_eq!(*lock.try_upgradable_read().unwrap(), 2);
+    assert_eq!(*lock.read().unwrap(), 2);
+}
+
+// Other platforms implement upgradable read locks as exclusive locks.
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_rwlock_upgradable_read_allows_readers() {
+    let lock = Arc::new(RwLock::new(()));
+    let guard = lock.upgradable_read().unwrap();
+
+    let (tx, rx) = channel();
+    let (tx2, rx2) = channel::<()>();
+    let lock2 = lock.clone();
+    let h = thread::spawn(move || {
+        let _guard = lock2.upgradable_read().unwrap();
+        tx.send(()).unwrap();
+        rx2.recv().unwrap();
+    });
+    // Give the other thread some time to start waiting for our upgradable read lock.
+    thread::sleep(Duration::from_millis(10));
+    drop(guard);
+    rx.recv().unwrap();
+
+    // Having waited for it doesn't make the upgradable read lock block readers.
+    drop(lock.try_read().unwrap());
+    tx2.send(()).unwrap();
+    h.join().unwrap();
+}
+
+#[test]
+fn test_rwlock_upgrade_is_atomic() {
+    const N: usize = 8;
+    const M: usize = 1000;
+
+    let lock = Arc::new(RwLock::new(0));
+    let threads: Vec<_> = (0..N)
+        .map(|i| {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                for _ in 0..M {
+                    if i % 2 == 0 {
+                        let n = lock.upgradable_read().unwrap();
+                        let old = *n;
+                        let mut n = RwLockUpgradableReadGuard::upgrade(n);
+                        // No writer got in between the read and the upgrade.
+                        assert_eq!(*n, old);
+                        *n += 1;
+                    } else {
+                        drop(lock.read().unwrap());
+                        *lock.write().unwrap() += 1;
+                    }
+                }
+            })
+        })
+        .collect();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), N * M);
+}
+
+#[test]
+fn test_rwlock_upgradable_read_poison() {
+    let arc = Arc::new(RwLock::new(1));
+    let arc2 = arc.clone();
+    let _: Result<(), _> = thread::spawn(move || {
+        let _lock = arc2.write().unwrap();
+        panic!();
+    })
+    .join();
+    let guard = arc.upgradable_read().unwrap_err().into_inner();
+    let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+    *guard = 2;
+    drop(guard);
+    assert!(arc.is_poisoned());
+    assert_eq!(*arc.read().unwrap_err().into_inner(), 2);
+}
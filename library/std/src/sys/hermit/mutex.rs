@@ -5,6 +5,8 @@
 use crate::ptr;
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sys::hermit::abi;
+use crate::sys_common::lock_timeout::poll_lock;
+use crate::time::Duration;
 
 /// This type provides a lock based on busy waiting to realize mutual exclusion
 ///
@@ -216,6 +218,13 @@ pub unsafe fn try_lock(&self) -> bool {
         guard.locked
     }
 
+    #[inline]
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        // Blocked tasks are only woken up by `unlock`, so they can't give up
+        // after a timeout. Poll instead.
+        poll_lock(timeout, || self.try_lock())
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
@@ -1,5 +1,6 @@
 use crate::cell::UnsafeCell;
 use crate::sys::locks::{Condvar, Mutex};
+use crate::time::{Duration, Instant};
 
 pub struct RwLock {
     lock: Mutex,
@@ -65,6 +66,45 @@ pub unsafe fn try_write(&self) -> bool {
         return ok;
     }
 
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        self.lock.lock();
+        let ok = self.wait_until(Instant::now().checked_add(timeout), |state| state.inc_readers());
+        self.lock.unlock();
+        ok
+    }
+
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        self.lock.lock();
+        let ok = self.wait_until(Instant::now().checked_add(timeout), |state| state.inc_writers());
+        self.lock.unlock();
+        ok
+    }
+
+    /// Waits until `acquire` succeeds or the deadline passes, with `self.lock`
+    /// held. Every wake up only waits for the time remaining until the
+    /// deadline, so spurious wake ups and contention don't extend the wait.
+    /// An overflowing deadline (None) waits forever.
+    unsafe fn wait_until(
+        &self,
+        deadline: Option<Instant>,
+        mut acquire: impl FnMut(&mut State) -> bool,
+    ) -> bool {
+        loop {
+            if acquire(&mut *self.state.get()) {
+                return true;
+            }
+            match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => {
+                        self.cond.wait_timeout(&self.lock, remaining);
+                    }
+                    _ => return false,
+                },
+                None => self.cond.wait(&self.lock),
+            }
+        }
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         self.lock.lock();
@@ -4,7 +4,9 @@
     abi,
     error::{expect_success, expect_success_aborting, fail, ItronError},
     spin::SpinIdOnceCell,
+    time::with_tmos,
 };
+use crate::time::Duration;
 
 pub struct Mutex {
     /// The ID of the underlying mutex object
@@ -65,6 +67,17 @@ pub unsafe fn try_lock(&self) -> bool {
         }
     }
 
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        let mtx = self.raw();
+        match with_tmos(timeout, |tmo| unsafe { abi::tloc_mtx(mtx, tmo) }) {
+            abi::E_TMOUT => false,
+            er => {
+                expect_success(er, &"tloc_mtx");
+                true
+            }
+        }
+    }
+
     pub unsafe fn destroy(&self) {
         if let Some(mtx) = self.mtx.get().map(|x| x.0) {
             expect_success_aborting(unsafe { abi::del_mtx(mtx) }, &"del_mtx");
@@ -1,4 +1,6 @@
 use super::waitqueue::{try_lock_or_false, SpinMutex, WaitQueue, WaitVariable};
+use crate::sys_common::lock_timeout::poll_lock;
+use crate::time::Duration;
 
 pub struct Mutex {
     inner: SpinMutex<WaitVariable<bool>>,
@@ -53,6 +55,13 @@ pub unsafe fn try_lock(&self) -> bool {
         }
     }
 
+    #[inline]
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        // Waiters are handed the lock directly by `unlock`, so they can't
+        // leave the wait queue on their own after a timeout. Poll instead.
+        poll_lock(timeout, || unsafe { self.try_lock() })
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
@@ -2,7 +2,9 @@
 use crate::error;
 use crate::fmt;
 use crate::io::{self, IoSlice, IoSliceMut};
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{
+    IpAddr, Ipv4Addr, Ipv6Addr, RecvMsg, Resolver, Shutdown, SocketAddr, ToSocketAddrs,
+};
 use crate::sync::Arc;
 use crate::sys::fd::FileDesc;
 use crate::sys::{sgx_ineffective, unsupported, AsInner, FromInner, IntoInner, TryIntoInner};
@@ -199,6 +201,38 @@ pub fn nodelay(&self) -> io::Result<bool> {
         sgx_ineffective(false)
     }
 
+    pub fn set_keepalive(&self, _: bool) -> io::Result<()> {
+        sgx_ineffective(())
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        sgx_ineffective(false)
+    }
+
+    pub fn set_keepalive_idle(&self, _: Duration) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        unsupported()
+    }
+
     pub fn set_ttl(&self, _: u32) -> io::Result<()> {
         sgx_ineffective(())
     }
@@ -325,6 +359,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         unsupported()
     }
 
+    pub fn bind_reuse_port(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+        unsupported()
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         self.0
     }
@@ -413,6 +451,62 @@ pub fn leave_multicast_v6(&self, _: &Ipv6Addr, _: u32) -> io::Result<()> {
         self.0
     }
 
+    pub fn join_ssm_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn leave_ssm_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn join_multicast_v4_by_index(&self, _: &Ipv4Addr, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn leave_multicast_v4_by_index(&self, _: &Ipv4Addr, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn set_multicast_interface_v4(&self, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn set_multicast_interface_v6(&self, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn reuse_port(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
+    pub fn set_recv_pktinfo(&self, _: bool) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn recv_pktinfo(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn recv_msg(&self, _: &mut [u8]) -> io::Result<RecvMsg> {
+        self.0
+    }
+
     pub fn set_ttl(&self, _: u32) -> io::Result<()> {
         self.0
     }
@@ -480,6 +574,10 @@ fn new(host: String) -> io::Result<LookupHost> {
     pub fn port(&self) -> u16 {
         self.0
     }
+
+    pub fn canonical_name(&self) -> Option<String> {
+        self.0
+    }
 }
 
 impl Iterator for LookupHost {
@@ -505,6 +603,14 @@ fn try_from((host, port): (&'a str, u16)) -> io::Result<LookupHost> {
     }
 }
 
+pub fn lookup_host_with(host: &str, port: u16, _resolver: &Resolver) -> io::Result<LookupHost> {
+    LookupHost::new(format!("{host}:{port}"))
+}
+
+pub fn lookup_addr(_addr: &IpAddr) -> io::Result<String> {
+    unsupported()
+}
+
 #[allow(bad_style)]
 pub mod netc {
     pub const AF_INET: u8 = 0;
@@ -7,6 +7,8 @@
     try_lock_or_false, NotifiedTcs, SpinMutex, SpinMutexGuard, WaitQueue, WaitVariable,
 };
 use crate::mem;
+use crate::sys_common::lock_timeout::poll_lock;
+use crate::time::Duration;
 
 pub struct RwLock {
     readers: SpinMutex<WaitVariable<Option<NonZeroUsize>>>,
@@ -92,6 +94,18 @@ pub unsafe fn try_write(&self) -> bool {
         }
     }
 
+    // Waiters are handed the lock directly when it is unlocked, so they can't
+    // leave the wait queue on their own after a timeout. Poll instead.
+    #[inline]
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        poll_lock(timeout, || unsafe { self.try_read() })
+    }
+
+    #[inline]
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        poll_lock(timeout, || unsafe { self.try_write() })
+    }
+
     #[inline]
     unsafe fn __read_unlock(
         &self,
@@ -6,6 +6,8 @@
         spin::SpinIdOnceCell,
     },
 };
+use crate::sys_common::lock_timeout::poll_lock;
+use crate::time::Duration;
 
 pub struct RwLock {
     /// The ID of the underlying mutex object
@@ -71,6 +73,18 @@ pub unsafe fn try_write(&self) -> bool {
         }
     }
 
+    // The SOLID kernel extension has no timed variants of `rwl_loc_rdl` and
+    // `rwl_loc_wrl`.
+    #[inline]
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        poll_lock(timeout, || unsafe { self.try_read() })
+    }
+
+    #[inline]
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        poll_lock(timeout, || unsafe { self.try_write() })
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         let rwl = self.raw();
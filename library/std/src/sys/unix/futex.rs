@@ -5,7 +5,7 @@
 ))]
 
 use crate::sync::atomic::AtomicU32;
-use crate::time::Duration;
+use crate::time::{Duration, Instant};
 
 /// Wait for a futex_wake operation to wake us.
 ///
@@ -111,3 +111,18 @@ pub fn futex_wake(futex: &AtomicU32) -> bool {
 pub fn futex_wake_all(futex: &AtomicU32) {
     unsafe { emscripten_futex_wake(futex, i32::MAX) };
 }
+
+/// Wait for a futex_wake operation to wake us, or until `deadline` has passed.
+///
+/// A deadline of `None` means waiting without a timeout.
+///
+/// Returns false on timeout, and true in all other cases.
+pub fn futex_wait_until(futex: &AtomicU32, expected: u32, deadline: Option<Instant>) -> bool {
+    match deadline {
+        None => futex_wait(futex, expected, None),
+        Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+            Some(timeout) if !timeout.is_zero() => futex_wait(futex, expected, Some(timeout)),
+            _ => false,
+        },
+    }
+}
@@ -12,7 +12,7 @@ pub mod net {
     use crate::convert::TryFrom;
     use crate::fmt;
     use crate::io::{self, IoSlice, IoSliceMut};
-    use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+    use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, RecvMsg, Resolver, Shutdown, SocketAddr};
     use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
     use crate::sys::fd::FileDesc;
     use crate::sys_common::{AsInner, FromInner, IntoInner};
@@ -115,6 +115,38 @@ pub fn nodelay(&self) -> io::Result<bool> {
             unimpl!();
         }
 
+        pub fn set_keepalive(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_idle(&self, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_idle(&self) -> io::Result<Duration> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_interval(&self) -> io::Result<Duration> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_retries(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
         pub fn set_nonblocking(&self, _: bool) -> io::Result<()> {
             unimpl!();
         }
@@ -372,6 +404,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
             unimpl!();
         }
 
+        pub fn bind_reuse_port(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+            unimpl!();
+        }
+
         pub fn socket(&self) -> &Socket {
             &self.inner
         }
@@ -468,6 +504,62 @@ pub fn leave_multicast_v6(&self, _: &Ipv6Addr, _: u32) -> io::Result<()> {
             unimpl!();
         }
 
+        pub fn join_ssm_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn leave_ssm_v4(&self, _: &Ipv4Addr, _: &Ipv4Addr, _: &Ipv4Addr) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn join_multicast_v4_by_index(&self, _: &Ipv4Addr, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn leave_multicast_v4_by_index(&self, _: &Ipv4Addr, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn set_multicast_interface_v4(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn set_multicast_interface_v6(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuse_port(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_recv_pktinfo(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_pktinfo(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn recv_msg(&self, _: &mut [u8]) -> io::Result<RecvMsg> {
+            unimpl!();
+        }
+
         pub fn set_ttl(&self, _: u32) -> io::Result<()> {
             unimpl!();
         }
@@ -529,6 +621,10 @@ impl LookupHost {
         pub fn port(&self) -> u16 {
             0 // unimplemented
         }
+
+        pub fn canonical_name(&self) -> Option<String> {
+            None // unimplemented
+        }
     }
 
     unsafe impl Sync for LookupHost {}
@@ -549,4 +645,12 @@ fn try_from(_v: (&'a str, u16)) -> io::Result<LookupHost> {
             unimpl!();
         }
     }
+
+    pub fn lookup_host_with(_: &str, _: u16, _: &Resolver) -> io::Result<LookupHost> {
+        unimpl!();
+    }
+
+    pub fn lookup_addr(_: &IpAddr) -> io::Result<String> {
+        unimpl!();
+    }
 }
@@ -2,8 +2,8 @@
     AtomicU32,
     Ordering::{Acquire, Relaxed, Release},
 };
-use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
-use crate::time::Duration;
+use crate::sys::futex::{futex_wait, futex_wait_until, futex_wake, futex_wake_all};
+use crate::time::{Duration, Instant};
 
 pub type MovableMutex = Mutex;
 pub type MovableCondvar = Condvar;
@@ -35,12 +35,20 @@ pub unsafe fn try_lock(&self) -> bool {
     #[inline]
     pub unsafe fn lock(&self) {
         if self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_err() {
-            self.lock_contended();
+            self.lock_contended(None);
         }
     }
 
+    #[inline]
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        // Overflows are rounded up to an infinite timeout (None).
+        self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_ok()
+            || self.lock_contended(Instant::now().checked_add(timeout))
+    }
+
+    /// Returns false if the deadline passed before the lock could be acquired.
     #[cold]
-    fn lock_contended(&self) {
+    fn lock_contended(&self, deadline: Option<Instant>) -> bool {
         // Spin first to speed things up if the lock is released quickly.
         let mut state = self.spin();
 
@@ -48,7 +56,7 @@ fn lock_contended(&self) {
         // without marking it as contended.
         if state == 0 {
             match self.futex.compare_exchange(0, 1, Acquire, Relaxed) {
-                Ok(_) => return, // Locked!
+                Ok(_) => return true, // Locked!
                 Err(s) => state = s,
             }
         }
@@ -59,11 +67,15 @@ fn lock_contended(&self) {
             // to be friendlier for the caches.
             if state != 2 && self.futex.swap(2, Acquire) == 0 {
                 // We changed it from 0 to 2, so we just succesfully locked it.
-                return;
+                return true;
             }
 
             // Wait for the futex to change state, assuming it is still 2.
-            futex_wait(&self.futex, 2, None);
+            // Giving up leaves the lock marked as contended, which only
+            // costs the thread unlocking it a spurious wake up.
+            if !futex_wait_until(&self.futex, 2, deadline) {
+                return false;
+            }
 
             // Spin again after waking up.
             state = self.spin();
@@ -2,28 +2,45 @@
     AtomicU32,
     Ordering::{Acquire, Relaxed, Release},
 };
-use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
+use crate::sys::futex::{futex_wait_until, futex_wake, futex_wake_all};
+use crate::time::{Duration, Instant};
 
 pub type MovableRwLock = RwLock;
 
 pub struct RwLock {
-    // The state consists of a 30-bit reader counter, a 'readers waiting' flag, and a 'writers waiting' flag.
-    // Bits 0..30:
+    // The state consists of a 28-bit reader counter, an 'upgradable readers
+    // waiting' flag, an 'upgradable' flag, a 'readers waiting' flag, and a
+    // 'writers waiting' flag.
+    // Bits 0..28:
     //   0: Unlocked
-    //   1..=0x3FFF_FFFE: Locked by N readers
-    //   0x3FFF_FFFF: Write locked
+    //   1..=0x0FFF_FFFE: Locked by N readers
+    //   0x0FFF_FFFF: Write locked
+    // Bit 28: Upgradable readers are waiting on the writer_notify futex.
+    //         Unlike the writers waiting flag, this doesn't stop new readers.
+    // Bit 29: One of the readers holds an upgradable read lock.
     // Bit 30: Readers are waiting on this futex.
-    // Bit 31: Writers are waiting on the writer_notify futex.
+    // Bit 31: Writers, or a reader that is upgrading its lock, are waiting on
+    //         the writer_notify futex.
     state: AtomicU32,
     // The 'condition variable' to notify writers through.
     // Incremented on every signal.
     writer_notify: AtomicU32,
+    // The number of writers and upgrading readers that might be waiting on
+    // the writer_notify futex, which is used to clear the writers waiting bit
+    // when the last of them gives up because of a timeout.
+    writers_waiting: AtomicU32,
+    // The number of readers that might be waiting on the state futex, which
+    // is used to clear the readers waiting bit when the last of them gives up
+    // because of a timeout.
+    readers_waiting: AtomicU32,
 }
 
 const READ_LOCKED: u32 = 1;
-const MASK: u32 = (1 << 30) - 1;
+const MASK: u32 = (1 << 28) - 1;
 const WRITE_LOCKED: u32 = MASK;
 const MAX_READERS: u32 = MASK - 1;
+const UPGRADABLE_WAITING: u32 = 1 << 28;
+const UPGRADABLE: u32 = 1 << 29;
 const READERS_WAITING: u32 = 1 << 30;
 const WRITERS_WAITING: u32 = 1 << 31;
 
@@ -57,10 +74,34 @@ fn has_reached_max_readers(state: u32) -> bool {
     state & MASK == MAX_READERS
 }
 
+fn has_upgradable_waiting(state: u32) -> bool {
+    state & UPGRADABLE_WAITING != 0
+}
+
+fn has_upgradable(state: u32) -> bool {
+    state & UPGRADABLE != 0
+}
+
+fn is_upgradable_lockable(state: u32) -> bool {
+    // Only one upgradable read lock can exist at a time, since two of them
+    // could never both be upgraded.
+    state & MASK < MAX_READERS && !has_upgradable(state)
+}
+
+fn is_upgradable_uncontended(state: u32) -> bool {
+    // The upgradable reader itself is the only reader left.
+    state & MASK == READ_LOCKED
+}
+
 impl RwLock {
     #[inline]
     pub const fn new() -> Self {
-        Self { state: AtomicU32::new(0), writer_notify: AtomicU32::new(0) }
+        Self {
+            state: AtomicU32::new(0),
+            writer_notify: AtomicU32::new(0),
+            writers_waiting: AtomicU32::new(0),
+            readers_waiting: AtomicU32::new(0),
+        }
     }
 
     #[inline]
@@ -82,10 +123,16 @@ pub unsafe fn read(&self) {
                 .compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed)
                 .is_err()
         {
-            self.read_contended();
+            self.read_contended(None);
         }
     }
 
+    #[inline]
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        // Overflows are rounded up to an infinite timeout (None).
+        self.try_read() || self.read_contended(Instant::now().checked_add(timeout))
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         let state = self.state.fetch_sub(READ_LOCKED, Release) - READ_LOCKED;
@@ -95,21 +142,34 @@ pub unsafe fn read_unlock(&self) {
         debug_assert!(!has_readers_waiting(state) || has_writers_waiting(state));
 
         // Wake up a writer if we were the last reader and there's a writer waiting.
-        if is_unlocked(state) && has_writers_waiting(state) {
+        if is_unlocked(state) && (has_writers_waiting(state) || has_upgradable_waiting(state)) {
             self.wake_writer_or_readers(state);
+        } else if has_upgradable(state)
+            && is_upgradable_uncontended(state)
+            && has_writers_waiting(state)
+        {
+            // The upgradable reader might be waiting for us to leave.
+            self.wake_all_writers();
         }
     }
 
+    /// Returns false if the deadline passed before the lock could be acquired.
     #[cold]
-    fn read_contended(&self) {
+    fn read_contended(&self, deadline: Option<Instant>) -> bool {
         let mut state = self.spin_read();
 
+        self.readers_waiting.fetch_add(1, Relaxed);
+
         loop {
             // If we can lock it, lock it.
             if is_read_lockable(state) {
                 match self.state.compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed)
                 {
-                    Ok(_) => return, // Locked!
+                    Ok(_) => {
+                        // Locked!
+                        self.readers_waiting.fetch_sub(1, Relaxed);
+                        return true;
+                    }
                     Err(s) => {
                         state = s;
                         continue;
@@ -119,6 +179,7 @@ fn read_contended(&self) {
 
             // Check for overflow.
             if has_reached_max_readers(state) {
+                self.readers_waiting.fetch_sub(1, Relaxed);
                 panic!("too many active read locks on RwLock");
             }
 
@@ -133,7 +194,10 @@ fn read_contended(&self) {
             }
 
             // Wait for the state to change.
-            futex_wait(&self.state, state | READERS_WAITING, None);
+            if !futex_wait_until(&self.state, state | READERS_WAITING, deadline) {
+                self.read_timed_out();
+                return false;
+            }
 
             // Spin again after waking up.
             state = self.spin_read();
@@ -150,27 +214,37 @@ pub unsafe fn try_write(&self) -> bool {
     #[inline]
     pub unsafe fn write(&self) {
         if self.state.compare_exchange_weak(0, WRITE_LOCKED, Acquire, Relaxed).is_err() {
-            self.write_contended();
+            self.write_contended(None);
         }
     }
 
+    #[inline]
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        // Overflows are rounded up to an infinite timeout (None).
+        self.try_write() || self.write_contended(Instant::now().checked_add(timeout))
+    }
+
     #[inline]
     pub unsafe fn write_unlock(&self) {
         let state = self.state.fetch_sub(WRITE_LOCKED, Release) - WRITE_LOCKED;
 
         debug_assert!(is_unlocked(state));
 
-        if has_writers_waiting(state) || has_readers_waiting(state) {
+        if has_writers_waiting(state) || has_readers_waiting(state) || has_upgradable_waiting(state)
+        {
             self.wake_writer_or_readers(state);
         }
     }
 
+    /// Returns false if the deadline passed before the lock could be acquired.
     #[cold]
-    fn write_contended(&self) {
+    fn write_contended(&self, deadline: Option<Instant>) -> bool {
         let mut state = self.spin_write();
 
         let mut other_writers_waiting = 0;
 
+        self.writers_waiting.fetch_add(1, Relaxed);
+
         loop {
             // If it's unlocked, we try to lock it.
             if is_unlocked(state) {
@@ -180,7 +254,11 @@ fn write_contended(&self) {
                     Acquire,
                     Relaxed,
                 ) {
-                    Ok(_) => return, // Locked!
+                    Ok(_) => {
+                        // Locked!
+                        self.writers_waiting.fetch_sub(1, Relaxed);
+                        return true;
+                    }
                     Err(s) => {
                         state = s;
                         continue;
@@ -215,13 +293,179 @@ fn write_contended(&self) {
             }
 
             // Wait for the state to change.
-            futex_wait(&self.writer_notify, seq, None);
+            if !futex_wait_until(&self.writer_notify, seq, deadline) {
+                self.write_timed_out();
+                return false;
+            }
 
             // Spin again after waking up.
             state = self.spin_write();
         }
     }
 
+    #[inline]
+    pub unsafe fn try_upgradable_read(&self) -> bool {
+        self.state
+            .fetch_update(Acquire, Relaxed, |s| {
+                (is_read_lockable(s) && !has_upgradable(s)).then(|| s + READ_LOCKED + UPGRADABLE)
+            })
+            .is_ok()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read(&self) {
+        if !self.try_upgradable_read() {
+            self.upgradable_read_contended();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_unlock(&self) {
+        let state =
+            self.state.fetch_sub(READ_LOCKED + UPGRADABLE, Release) - (READ_LOCKED + UPGRADABLE);
+
+        if is_unlocked(state) {
+            if has_writers_waiting(state)
+                || has_readers_waiting(state)
+                || has_upgradable_waiting(state)
+            {
+                self.wake_writer_or_readers(state);
+            }
+        } else if has_upgradable_waiting(state) {
+            // Other upgradable readers are waiting for us.
+            self.wake_upgradable_readers();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        self.state
+            .fetch_update(Acquire, Relaxed, |s| {
+                is_upgradable_uncontended(s).then(|| (s & !(MASK | UPGRADABLE)) | WRITE_LOCKED)
+            })
+            .is_ok()
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {
+        if self
+            .state
+            .compare_exchange_weak(READ_LOCKED | UPGRADABLE, WRITE_LOCKED, Acquire, Relaxed)
+            .is_err()
+        {
+            self.upgrade_contended();
+        }
+    }
+
+    /// Upgradable readers wait on `writer_notify` like writers do, since they
+    /// have to be woken up when the other upgradable reader leaves, even when
+    /// the lock stays read locked. They use their own waiting bit though, so
+    /// that they don't stop new readers like waiting writers do.
+    #[cold]
+    fn upgradable_read_contended(&self) {
+        let mut state = self.state.load(Relaxed);
+
+        let mut other_upgradable_waiting = 0;
+
+        loop {
+            // We ignore the readers and writers waiting bits, since an
+            // upgradable read lock can be held along with other read locks.
+            if is_upgradable_lockable(state) {
+                match self.state.compare_exchange_weak(
+                    state,
+                    (state + READ_LOCKED + UPGRADABLE) | other_upgradable_waiting,
+                    Acquire,
+                    Relaxed,
+                ) {
+                    Ok(_) => return, // Locked!
+                    Err(s) => {
+                        state = s;
+                        continue;
+                    }
+                }
+            }
+
+            // Set the waiting bit indicating that we're waiting on it.
+            if !has_upgradable_waiting(state) {
+                if let Err(s) =
+                    self.state.compare_exchange(state, state | UPGRADABLE_WAITING, Relaxed, Relaxed)
+                {
+                    state = s;
+                    continue;
+                }
+            }
+
+            // Other upgradable readers might be waiting now too, so we should
+            // make sure we keep that bit on once we manage to lock it.
+            other_upgradable_waiting = UPGRADABLE_WAITING;
+
+            let seq = self.writer_notify.load(Acquire);
+
+            let s = self.state.load(Relaxed);
+            if is_upgradable_lockable(s) || !has_upgradable_waiting(s) {
+                state = s;
+                continue;
+            }
+
+            futex_wait_until(&self.writer_notify, seq, None);
+
+            state = self.state.load(Relaxed);
+        }
+    }
+
+    #[cold]
+    fn upgrade_contended(&self) {
+        let mut state = self.state.load(Relaxed);
+
+        self.writers_waiting.fetch_add(1, Relaxed);
+
+        loop {
+            // Once all other readers are gone, turn our read lock into a write lock.
+            // The waiting bits are kept, since others might still be waiting.
+            if is_upgradable_uncontended(state) {
+                match self.state.compare_exchange_weak(
+                    state,
+                    (state & !(MASK | UPGRADABLE)) | WRITE_LOCKED,
+                    Acquire,
+                    Relaxed,
+                ) {
+                    Ok(_) => {
+                        // Upgraded!
+                        self.writers_waiting.fetch_sub(1, Relaxed);
+                        return;
+                    }
+                    Err(s) => {
+                        state = s;
+                        continue;
+                    }
+                }
+            }
+
+            // Setting the writers waiting bit stops new readers from coming in,
+            // and makes the last reader to leave wake us up.
+            if !has_writers_waiting(state) {
+                if let Err(s) =
+                    self.state.compare_exchange(state, state | WRITERS_WAITING, Relaxed, Relaxed)
+                {
+                    state = s;
+                    continue;
+                }
+            }
+
+            let seq = self.writer_notify.load(Acquire);
+
+            let s = self.state.load(Relaxed);
+            if is_upgradable_uncontended(s) || !has_writers_waiting(s) {
+                state = s;
+                continue;
+            }
+
+            futex_wait_until(&self.writer_notify, seq, None);
+
+            state = self.state.load(Relaxed);
+        }
+    }
+
     /// Wake up waiting threads after unlocking.
     ///
     /// If both are waiting, this will wake up only one writer, but will fall
@@ -230,6 +474,12 @@ fn write_contended(&self) {
     fn wake_writer_or_readers(&self, mut state: u32) {
         assert!(is_unlocked(state));
 
+        // Upgradable readers don't conflict with readers, nor with the
+        // writer we might wake up below, so they are all woken up as well.
+        if has_upgradable_waiting(state) {
+            state = self.wake_upgradable_readers();
+        }
+
         // The readers waiting bit might be turned on at any point now,
         // since readers will block when there's anything waiting.
         // Writers will just lock the lock though, regardless of the waiting bits,
@@ -276,6 +526,61 @@ fn wake_writer_or_readers(&self, mut state: u32) {
         }
     }
 
+    /// Clean up after a reader gave up on acquiring the lock because of a timeout.
+    #[cold]
+    fn read_timed_out(&self) {
+        if self.readers_waiting.fetch_sub(1, Relaxed) == 1 {
+            // We were the last waiting reader, so nobody needs the readers
+            // waiting bit anymore. A reader that started waiting in the
+            // meantime is woken up, and sets the bit again.
+            if has_readers_waiting(self.state.fetch_and(!READERS_WAITING, Relaxed)) {
+                futex_wake_all(&self.state);
+            }
+        }
+        self.timed_out();
+    }
+
+    /// Clean up after a writer gave up on acquiring the lock because of a timeout.
+    #[cold]
+    fn write_timed_out(&self) {
+        if self.writers_waiting.fetch_sub(1, Relaxed) == 1 {
+            // We were the last thread waiting on writer_notify, so nobody
+            // needs the writers waiting bit anymore. Leaving it set would
+            // block new readers while the lock is read locked, even though
+            // no writer is waiting for the readers to leave.
+            let state = self.state.fetch_and(!WRITERS_WAITING, Relaxed) & !WRITERS_WAITING;
+
+            // A thread that started waiting on writer_notify in the meantime
+            // is woken up, and sets the bit again.
+            self.wake_all_writers();
+
+            // Readers only wait on a lock that is not write locked because of
+            // waiting writers, so they can continue now.
+            if !is_write_locked(state) && has_readers_waiting(state) {
+                if has_readers_waiting(self.state.fetch_and(!READERS_WAITING, Relaxed)) {
+                    futex_wake_all(&self.state);
+                }
+            }
+        }
+        self.timed_out();
+    }
+
+    /// Clean up after giving up on acquiring the lock because of a timeout.
+    ///
+    /// If other threads are still waiting and the lock was unlocked in the
+    /// meantime, nobody is going to wake them up anymore, so we do what an
+    /// unlocking thread would do.
+    fn timed_out(&self) {
+        let state = self.state.load(Relaxed);
+        if is_unlocked(state)
+            && (has_writers_waiting(state)
+                || has_readers_waiting(state)
+                || has_upgradable_waiting(state))
+        {
+            self.wake_writer_or_readers(state);
+        }
+    }
+
     /// This wakes one writer and returns true if we woke up a writer that was
     /// blocked on futex_wait.
     ///
@@ -286,6 +591,24 @@ fn wake_writer(&self) -> bool {
         futex_wake(&self.writer_notify)
     }
 
+    /// Clears the upgradable readers waiting bit and wakes up everything
+    /// waiting on `writer_notify`, returning the new state.
+    ///
+    /// Writers that are woken up as well simply go back to sleep if they
+    /// can't get the lock.
+    fn wake_upgradable_readers(&self) -> u32 {
+        let state = self.state.fetch_and(!UPGRADABLE_WAITING, Relaxed) & !UPGRADABLE_WAITING;
+        self.wake_all_writers();
+        state
+    }
+
+    /// Wake up everything waiting on `writer_notify`, for when it's not known
+    /// which of the waiters can make progress.
+    fn wake_all_writers(&self) {
+        self.writer_notify.fetch_add(1, Release);
+        futex_wake_all(&self.writer_notify);
+    }
+
     /// Spin for a while, but stop directly at the given condition.
     fn spin_until(&self, f: impl Fn(u32) -> bool) -> u32 {
         let mut spin = 100; // Chosen by fair dice roll.
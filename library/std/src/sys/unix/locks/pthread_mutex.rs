@@ -1,6 +1,7 @@
 use crate::cell::UnsafeCell;
 use crate::mem::MaybeUninit;
 use crate::sys::cvt_nz;
+use crate::time::Duration;
 
 pub struct Mutex {
     inner: UnsafeCell<libc::pthread_mutex_t>,
@@ -72,6 +73,49 @@ pub unsafe fn unlock(&self) {
     pub unsafe fn try_lock(&self) -> bool {
         libc::pthread_mutex_trylock(self.inner.get()) == 0
     }
+    #[cfg(any(
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "fuchsia",
+    ))]
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        extern "C" {
+            fn pthread_mutex_timedlock(
+                mutex: *mut libc::pthread_mutex_t,
+                abstime: *const libc::timespec,
+            ) -> libc::c_int;
+        }
+
+        match realtime_deadline(timeout) {
+            Some(deadline) => {
+                let r = pthread_mutex_timedlock(self.inner.get(), &deadline);
+                debug_assert!(r == 0 || r == libc::ETIMEDOUT);
+                r == 0
+            }
+            // Overflows are rounded up to an infinite timeout.
+            None => {
+                self.lock();
+                true
+            }
+        }
+    }
+    #[cfg(not(any(
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "fuchsia",
+    )))]
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        // `pthread_mutex_timedlock` is not available on this platform.
+        crate::sys_common::lock_timeout::poll_lock(timeout, || self.try_lock())
+    }
     #[inline]
     #[cfg(not(target_os = "dragonfly"))]
     pub unsafe fn destroy(&self) {
@@ -90,6 +134,26 @@ pub unsafe fn destroy(&self) {
     }
 }
 
+/// Converts a relative timeout into the absolute `CLOCK_REALTIME` deadline
+/// expected by `pthread_mutex_timedlock` and friends, or `None` on overflow.
+///
+/// As the deadline is measured with the system clock, changes to the system
+/// time affect how long these functions actually wait.
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "fuchsia",
+))]
+pub(super) fn realtime_deadline(timeout: Duration) -> Option<libc::timespec> {
+    use crate::sys::time::Timespec;
+
+    Some(Timespec::now(libc::CLOCK_REALTIME).checked_add_duration(&timeout)?.t)
+}
+
 pub(super) struct PthreadMutexAttr<'a>(pub &'a mut MaybeUninit<libc::pthread_mutexattr_t>);
 
 impl Drop for PthreadMutexAttr<'_> {
@@ -1,5 +1,6 @@
 use crate::cell::UnsafeCell;
 use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::time::Duration;
 
 pub struct RwLock {
     inner: UnsafeCell<libc::pthread_rwlock_t>,
@@ -57,9 +58,15 @@ pub unsafe fn read(&self) {
     #[inline]
     pub unsafe fn try_read(&self) -> bool {
         let r = libc::pthread_rwlock_tryrdlock(self.inner.get());
+        self.finish_try_read(r)
+    }
+    /// Checks the result `r` of a non-blocking or timed read lock attempt,
+    /// returning whether the lock was acquired.
+    #[inline]
+    unsafe fn finish_try_read(&self, r: libc::c_int) -> bool {
         if r == 0 {
             if *self.write_locked.get() {
-                // `pthread_rwlock_tryrdlock` succeeded when it should not have.
+                // The lock attempt succeeded when it should not have.
                 self.raw_unlock();
                 false
             } else {
@@ -96,9 +103,15 @@ pub unsafe fn write(&self) {
     #[inline]
     pub unsafe fn try_write(&self) -> bool {
         let r = libc::pthread_rwlock_trywrlock(self.inner.get());
+        self.finish_try_write(r)
+    }
+    /// Checks the result `r` of a non-blocking or timed write lock attempt,
+    /// returning whether the lock was acquired.
+    #[inline]
+    unsafe fn finish_try_write(&self, r: libc::c_int) -> bool {
         if r == 0 {
             if *self.write_locked.get() || self.num_readers.load(Ordering::Relaxed) != 0 {
-                // `pthread_rwlock_trywrlock` succeeded when it should not have.
+                // The lock attempt succeeded when it should not have.
                 self.raw_unlock();
                 false
             } else {
@@ -109,6 +122,90 @@ pub unsafe fn try_write(&self) -> bool {
             false
         }
     }
+    #[cfg(any(
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "fuchsia",
+    ))]
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        extern "C" {
+            fn pthread_rwlock_timedrdlock(
+                rwlock: *mut libc::pthread_rwlock_t,
+                abstime: *const libc::timespec,
+            ) -> libc::c_int;
+        }
+
+        match super::pthread_mutex::realtime_deadline(timeout) {
+            // Like `try_read`, this treats a lock that would deadlock as
+            // unavailable, which is what it would look like after the timeout.
+            Some(deadline) => {
+                self.finish_try_read(pthread_rwlock_timedrdlock(self.inner.get(), &deadline))
+            }
+            // Overflows are rounded up to an infinite timeout.
+            None => {
+                self.read();
+                true
+            }
+        }
+    }
+    #[cfg(not(any(
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "fuchsia",
+    )))]
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        // `pthread_rwlock_timedrdlock` is not available on this platform.
+        crate::sys_common::lock_timeout::poll_lock(timeout, || self.try_read())
+    }
+    #[cfg(any(
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "fuchsia",
+    ))]
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        extern "C" {
+            fn pthread_rwlock_timedwrlock(
+                rwlock: *mut libc::pthread_rwlock_t,
+                abstime: *const libc::timespec,
+            ) -> libc::c_int;
+        }
+
+        match super::pthread_mutex::realtime_deadline(timeout) {
+            Some(deadline) => {
+                self.finish_try_write(pthread_rwlock_timedwrlock(self.inner.get(), &deadline))
+            }
+            // Overflows are rounded up to an infinite timeout.
+            None => {
+                self.write();
+                true
+            }
+        }
+    }
+    #[cfg(not(any(
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "fuchsia",
+    )))]
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        // `pthread_rwlock_timedwrlock` is not available on this platform.
+        crate::sys_common::lock_timeout::poll_lock(timeout, || self.try_write())
+    }
     #[inline]
     unsafe fn raw_unlock(&self) {
         let r = libc::pthread_rwlock_unlock(self.inner.get());
@@ -357,3 +357,47 @@ pub fn now(clock: clock_t) -> Timespec {
         }
     }
 }
+
+/// Readings of the clocks exposed through `std::os::unix::time`.
+///
+/// Each reading is the raw value of the clock, which is only meaningful when
+/// compared to another reading of the same clock.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "dragonfly"
+))]
+pub mod clocks {
+    use crate::io;
+    use crate::mem::MaybeUninit;
+    use crate::sys::{cvt, cvt_nz};
+    use crate::time::Duration;
+
+    fn now(clock: libc::clockid_t) -> io::Result<Duration> {
+        let mut t = MaybeUninit::uninit();
+        cvt(unsafe { libc::clock_gettime(clock, t.as_mut_ptr()) })?;
+        let t: libc::timespec = unsafe { t.assume_init() };
+        // None of these clocks can be negative.
+        Ok(Duration::new(t.tv_sec as u64, t.tv_nsec as u32))
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn boottime() -> Duration {
+        now(libc::CLOCK_BOOTTIME).unwrap()
+    }
+
+    pub fn process_cputime() -> Duration {
+        now(libc::CLOCK_PROCESS_CPUTIME_ID).unwrap()
+    }
+
+    pub fn thread_cputime() -> Duration {
+        now(libc::CLOCK_THREAD_CPUTIME_ID).unwrap()
+    }
+
+    pub fn pthread_cputime(thread: libc::pthread_t) -> io::Result<Duration> {
+        let mut clock = MaybeUninit::uninit();
+        cvt_nz(unsafe { libc::pthread_getcpuclockid(thread, clock.as_mut_ptr()) })?;
+        now(unsafe { clock.assume_init() })
+    }
+}
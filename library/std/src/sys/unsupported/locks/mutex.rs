@@ -1,4 +1,5 @@
 use crate::cell::Cell;
+use crate::time::Duration;
 
 pub struct Mutex {
     // This platform has no threads, so we can use a Cell here.
@@ -33,6 +34,12 @@ pub unsafe fn try_lock(&self) -> bool {
         self.locked.replace(true) == false
     }
 
+    #[inline]
+    pub unsafe fn lock_timeout(&self, _timeout: Duration) -> bool {
+        // Without other threads, nobody could unlock it while we wait.
+        unsafe { self.try_lock() }
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
@@ -1,4 +1,5 @@
 use crate::cell::Cell;
+use crate::time::Duration;
 
 pub struct RwLock {
     // This platform has no threads, so we can use a Cell here.
@@ -53,6 +54,17 @@ pub unsafe fn try_write(&self) -> bool {
         }
     }
 
+    // Without other threads, nobody could unlock it while we wait.
+    #[inline]
+    pub unsafe fn read_timeout(&self, _timeout: Duration) -> bool {
+        unsafe { self.try_read() }
+    }
+
+    #[inline]
+    pub unsafe fn write_timeout(&self, _timeout: Duration) -> bool {
+        unsafe { self.try_write() }
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         self.mode.set(self.mode.get() - 1);
@@ -1,6 +1,8 @@
 use crate::arch::wasm32;
+use crate::cmp;
 use crate::mem;
 use crate::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+use crate::time::Duration;
 
 pub struct Mutex {
     locked: AtomicUsize,
@@ -40,6 +42,23 @@ pub unsafe fn lock(&self) {
         }
     }
 
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        let nanos = cmp::min(i64::MAX as u128, timeout.as_nanos()) as i64;
+        while !self.try_lock() {
+            // There might not be a clock to measure the remaining time with,
+            // so the timeout starts over after every wake up. Since every wake
+            // up is caused by another thread unlocking the mutex, this only
+            // waits longer than `timeout` under contention.
+            // SAFETY: the caller must uphold the safety contract for `memory_atomic_wait32`.
+            let val = unsafe { wasm32::memory_atomic_wait32(self.ptr(), 1, nanos) };
+            // 0 == woken, 1 == not equal to 1 (unlocked), 2 == timeout
+            if val == 2 {
+                return self.try_lock();
+            }
+        }
+        true
+    }
+
     pub unsafe fn unlock(&self) {
         let prev = self.locked.swap(0, SeqCst);
         debug_assert_eq!(prev, 1);
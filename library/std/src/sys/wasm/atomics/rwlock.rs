@@ -1,5 +1,6 @@
 use crate::cell::UnsafeCell;
 use crate::sys::locks::{Condvar, Mutex};
+use crate::time::Duration;
 
 pub struct RwLock {
     lock: Mutex,
@@ -65,6 +66,30 @@ pub unsafe fn try_write(&self) -> bool {
         return ok;
     }
 
+    // There might not be a clock to measure the remaining time with, so the
+    // timeout starts over after every wake up. Since every wake up either lets
+    // us take the lock or is caused by another thread unlocking it, this only
+    // waits longer than `timeout` under contention.
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        self.lock.lock();
+        let mut ok = (*self.state.get()).inc_readers();
+        while !ok && self.cond.wait_timeout(&self.lock, timeout) {
+            ok = (*self.state.get()).inc_readers();
+        }
+        self.lock.unlock();
+        ok
+    }
+
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        self.lock.lock();
+        let mut ok = (*self.state.get()).inc_writers();
+        while !ok && self.cond.wait_timeout(&self.lock, timeout) {
+            ok = (*self.state.get()).inc_writers();
+        }
+        self.lock.unlock();
+        ok
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         self.lock.lock();
@@ -198,8 +198,12 @@ fn clone(&self) -> Self {
 pub const CREATE_UNICODE_ENVIRONMENT: DWORD = 0x00000400;
 pub const STARTF_USESTDHANDLES: DWORD = 0x00000100;
 
+pub const AF_UNSPEC: c_int = 0;
 pub const AF_INET: c_int = 2;
 pub const AF_INET6: c_int = 23;
+pub const AI_CANONNAME: c_int = 0x00000002;
+pub const AI_ADDRCONFIG: c_int = 0x00000400;
+pub const NI_NAMEREQD: c_int = 0x04;
 pub const SD_BOTH: c_int = 2;
 pub const SD_RECEIVE: c_int = 0;
 pub const SD_SEND: c_int = 1;
@@ -210,19 +214,29 @@ fn clone(&self) -> Self {
 pub const SO_LINGER: c_int = 0x0080;
 pub const SO_RCVTIMEO: c_int = 0x1006;
 pub const SO_SNDTIMEO: c_int = 0x1005;
+pub const SO_RCVBUF: c_int = 0x1002;
+pub const SO_SNDBUF: c_int = 0x1001;
+pub const SO_KEEPALIVE: c_int = 0x0008;
 pub const IPPROTO_IP: c_int = 0;
 pub const IPPROTO_TCP: c_int = 6;
 pub const IPPROTO_IPV6: c_int = 41;
 pub const TCP_NODELAY: c_int = 0x0001;
+pub const TCP_KEEPALIVE: c_int = 3;
+pub const TCP_KEEPCNT: c_int = 16;
+pub const TCP_KEEPINTVL: c_int = 17;
 pub const IP_TTL: c_int = 4;
 pub const IPV6_V6ONLY: c_int = 27;
 pub const SO_ERROR: c_int = 0x1007;
 pub const SO_BROADCAST: c_int = 0x0020;
 pub const IP_MULTICAST_LOOP: c_int = 11;
+pub const IP_MULTICAST_IF: c_int = 9;
+pub const IPV6_MULTICAST_IF: c_int = 9;
 pub const IPV6_MULTICAST_LOOP: c_int = 11;
 pub const IP_MULTICAST_TTL: c_int = 10;
 pub const IP_ADD_MEMBERSHIP: c_int = 12;
 pub const IP_DROP_MEMBERSHIP: c_int = 13;
+pub const IP_ADD_SOURCE_MEMBERSHIP: c_int = 15;
+pub const IP_DROP_SOURCE_MEMBERSHIP: c_int = 16;
 pub const IPV6_ADD_MEMBERSHIP: c_int = 12;
 pub const IPV6_DROP_MEMBERSHIP: c_int = 13;
 pub const MSG_PEEK: c_int = 0x2;
@@ -240,6 +254,13 @@ pub struct ip_mreq {
     pub imr_interface: in_addr,
 }
 
+#[repr(C)]
+pub struct ip_mreq_source {
+    pub imr_multiaddr: in_addr,
+    pub imr_sourceaddr: in_addr,
+    pub imr_interface: in_addr,
+}
+
 #[repr(C)]
 pub struct ipv6_mreq {
     pub ipv6mr_multiaddr: in6_addr,
@@ -1179,6 +1200,15 @@ pub fn getaddrinfo(
         res: *mut *mut ADDRINFOA,
     ) -> c_int;
     pub fn freeaddrinfo(res: *mut ADDRINFOA);
+    pub fn getnameinfo(
+        sa: *const SOCKADDR,
+        salen: socklen_t,
+        host: *mut c_char,
+        hostlen: DWORD,
+        serv: *mut c_char,
+        servlen: DWORD,
+        flags: c_int,
+    ) -> c_int;
     pub fn select(
         nfds: c_int,
         readfds: *mut fd_set,
@@ -16,6 +16,8 @@
 
 use crate::cell::UnsafeCell;
 use crate::sys::c;
+use crate::sys_common::lock_timeout::poll_lock;
+use crate::time::Duration;
 
 pub struct Mutex {
     srwlock: UnsafeCell<c::SRWLOCK>,
@@ -49,6 +51,11 @@ pub unsafe fn try_lock(&self) -> bool {
         c::TryAcquireSRWLockExclusive(raw(self)) != 0
     }
 
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        // SRWLock can't be acquired with a timeout.
+        poll_lock(timeout, || self.try_lock())
+    }
+
     #[inline]
     pub unsafe fn unlock(&self) {
         c::ReleaseSRWLockExclusive(raw(self));
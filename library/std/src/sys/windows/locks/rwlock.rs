@@ -1,5 +1,7 @@
 use crate::cell::UnsafeCell;
 use crate::sys::c;
+use crate::sys_common::lock_timeout::poll_lock;
+use crate::time::Duration;
 
 pub struct RwLock {
     inner: UnsafeCell<c::SRWLOCK>,
@@ -30,6 +32,13 @@ pub unsafe fn write(&self) {
     pub unsafe fn try_write(&self) -> bool {
         c::TryAcquireSRWLockExclusive(self.inner.get()) != 0
     }
+    // SRWLock can't be acquired with a timeout.
+    pub unsafe fn read_timeout(&self, timeout: Duration) -> bool {
+        poll_lock(timeout, || self.try_read())
+    }
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        poll_lock(timeout, || self.try_write())
+    }
     #[inline]
     pub unsafe fn read_unlock(&self) {
         c::ReleaseSRWLockShared(self.inner.get())
@@ -38,7 +47,6 @@ pub unsafe fn read_unlock(&self) {
     pub unsafe fn write_unlock(&self) {
         c::ReleaseSRWLockExclusive(self.inner.get())
     }
-
     #[inline]
     pub unsafe fn destroy(&self) {
         // ...
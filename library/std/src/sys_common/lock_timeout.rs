@@ -0,0 +1,47 @@
+//! Timed lock acquisition for platforms whose locks can't wait with a timeout.
+
+#![allow(dead_code)] // not used on all platforms
+
+use crate::cmp;
+use crate::hint;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+/// Repeatedly calls `try_lock` until it succeeds or `timeout` has elapsed,
+/// returning whether the lock was acquired.
+///
+/// This backs off from spinning to yielding to sleeping for increasingly long
+/// intervals, capped at about a millisecond, so that it neither keeps a core
+/// busy nor oversleeps the deadline by much.
+pub fn poll_lock(timeout: Duration, mut try_lock: impl FnMut() -> bool) -> bool {
+    if try_lock() {
+        return true;
+    }
+
+    // Overflows are rounded up to an infinite timeout (None).
+    let deadline = Instant::now().checked_add(timeout);
+    let mut attempt = 0u32;
+    loop {
+        if try_lock() {
+            return true;
+        }
+
+        let remaining = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => return false,
+            },
+            None => Duration::MAX,
+        };
+
+        if attempt < 16 {
+            hint::spin_loop();
+        } else if attempt < 32 {
+            thread::yield_now();
+        } else {
+            let backoff = Duration::from_micros(1 << cmp::min(attempt - 32, 10));
+            thread::sleep(cmp::min(backoff, remaining));
+        }
+        attempt = attempt.saturating_add(1);
+    }
+}
@@ -24,6 +24,7 @@
 pub mod condvar;
 pub mod fs;
 pub mod io;
+pub mod lock_timeout;
 pub mod memchr;
 pub mod mutex;
 pub mod process;
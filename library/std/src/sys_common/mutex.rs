@@ -1,4 +1,5 @@
 use crate::sys::locks as imp;
+use crate::time::Duration;
 
 /// An OS-based mutual exclusion lock, meant for use in static variables.
 ///
@@ -83,6 +84,13 @@ pub fn try_lock(&self) -> bool {
         unsafe { self.0.try_lock() }
     }
 
+    /// Locks the mutex, blocking the current thread for at most `timeout`,
+    /// returning whether it was successfully acquired or not.
+    #[inline]
+    pub fn raw_lock_timeout(&self, timeout: Duration) -> bool {
+        unsafe { self.0.lock_timeout(timeout) }
+    }
+
     /// Unlocks the mutex.
     ///
     /// Behavior is undefined if the current thread does not actually hold the
@@ -3,11 +3,11 @@
 
 use crate::cmp;
 use crate::convert::{TryFrom, TryInto};
-use crate::ffi::CString;
+use crate::ffi::{CStr, CString};
 use crate::fmt;
 use crate::io::{self, ErrorKind, IoSlice, IoSliceMut};
 use crate::mem;
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, RecvMsg, Resolver, Shutdown, SocketAddr};
 use crate::ptr;
 use crate::sys::net::netc as c;
 use crate::sys::net::{cvt, cvt_gai, cvt_r, init, wrlen_t, Socket};
@@ -54,6 +54,40 @@
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux", target_os = "android",
+        target_os = "emscripten", target_os = "fuchsia",
+        target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd",
+        target_os = "illumos", target_os = "solaris"))] {
+        const TCP_KEEPIDLE: Option<c_int> = Some(libc::TCP_KEEPIDLE);
+        const TCP_KEEPINTVL: Option<c_int> = Some(libc::TCP_KEEPINTVL);
+        const TCP_KEEPCNT: Option<c_int> = Some(libc::TCP_KEEPCNT);
+    } else if #[cfg(any(target_os = "ios", target_os = "macos", windows))] {
+        const TCP_KEEPIDLE: Option<c_int> = Some(c::TCP_KEEPALIVE);
+        const TCP_KEEPINTVL: Option<c_int> = Some(c::TCP_KEEPINTVL);
+        const TCP_KEEPCNT: Option<c_int> = Some(c::TCP_KEEPCNT);
+    } else {
+        const TCP_KEEPIDLE: Option<c_int> = None;
+        const TCP_KEEPINTVL: Option<c_int> = None;
+        const TCP_KEEPCNT: Option<c_int> = None;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux", target_os = "android",
+        target_os = "emscripten", target_os = "fuchsia",
+        target_os = "dragonfly", target_os = "freebsd",
+        target_os = "openbsd", target_os = "netbsd",
+        target_os = "ios", target_os = "macos",
+        target_os = "haiku", target_os = "redox"))] {
+        const SO_REUSEPORT: Option<c_int> = Some(libc::SO_REUSEPORT);
+    } else {
+        const SO_REUSEPORT: Option<c_int> = None;
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // sockaddr and misc bindings
 ////////////////////////////////////////////////////////////////////////////////
@@ -91,6 +125,26 @@ pub fn getsockopt<T: Copy>(sock: &Socket, level: c_int, option_name: c_int) -> i
     }
 }
 
+fn keepalive_option(option: Option<c_int>) -> io::Result<c_int> {
+    option.ok_or(io::const_io_error!(
+        ErrorKind::Unsupported,
+        "TCP keepalive tuning is not supported on this platform",
+    ))
+}
+
+fn keepalive_secs(dur: Duration) -> io::Result<c_int> {
+    match c_int::try_from(dur.as_secs()) {
+        Ok(0) => Err(io::const_io_error!(
+            ErrorKind::InvalidInput,
+            "cannot set a keepalive duration shorter than one second",
+        )),
+        Ok(secs) => Ok(secs),
+        Err(_) => {
+            Err(io::const_io_error!(ErrorKind::InvalidInput, "keepalive duration is too large"))
+        }
+    }
+}
+
 fn sockname<F>(f: F) -> io::Result<SocketAddr>
 where
     F: FnOnce(*mut c::sockaddr, *mut c::socklen_t) -> c_int,
@@ -196,15 +250,112 @@ impl<'a> TryFrom<(&'a str, u16)> for LookupHost {
     type Error = io::Error;
 
     fn try_from((host, port): (&'a str, u16)) -> io::Result<LookupHost> {
-        init();
+        getaddrinfo(host, port, c::AF_UNSPEC, 0)
+    }
+}
+
+fn getaddrinfo(host: &str, port: u16, family: c_int, flags: c_int) -> io::Result<LookupHost> {
+    init();
 
-        let c_host = CString::new(host)?;
-        let mut hints: c::addrinfo = unsafe { mem::zeroed() };
-        hints.ai_socktype = c::SOCK_STREAM;
-        let mut res = ptr::null_mut();
+    let c_host = CString::new(host)?;
+    let mut hints: c::addrinfo = unsafe { mem::zeroed() };
+    hints.ai_family = family;
+    hints.ai_flags = flags;
+    hints.ai_socktype = c::SOCK_STREAM;
+    let mut res = ptr::null_mut();
+    unsafe {
+        cvt_gai(c::getaddrinfo(c_host.as_ptr(), ptr::null(), &hints, &mut res))
+            .map(|_| LookupHost { original: res, cur: res, port })
+    }
+}
+
+impl LookupHost {
+    /// Returns the canonical name of the host, if it was requested with
+    /// `AI_CANONNAME` and the resolver provided one.
+    pub fn canonical_name(&self) -> Option<String> {
         unsafe {
-            cvt_gai(c::getaddrinfo(c_host.as_ptr(), ptr::null(), &hints, &mut res))
-                .map(|_| LookupHost { original: res, cur: res, port })
+            let name = self.original.as_ref()?.ai_canonname;
+            if name.is_null() {
+                return None;
+            }
+            Some(CStr::from_ptr(name).to_string_lossy().into_owned())
+        }
+    }
+}
+
+/// Resolves `host` with the options of a `Resolver`.
+pub fn lookup_host_with(host: &str, port: u16, resolver: &Resolver) -> io::Result<LookupHost> {
+    let family = match (resolver.ipv4, resolver.ipv6) {
+        (true, true) => c::AF_UNSPEC,
+        (true, false) => c::AF_INET,
+        (false, true) => c::AF_INET6,
+        (false, false) => {
+            return Err(io::const_io_error!(
+                ErrorKind::InvalidInput,
+                "neither IPv4 nor IPv6 addresses were requested",
+            ));
+        }
+    };
+    getaddrinfo(host, port, family, resolver_flags(resolver)?)
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        windows,
+        target_os = "linux", target_os = "android",
+        target_os = "emscripten", target_os = "fuchsia",
+        target_os = "freebsd", target_os = "netbsd", target_os = "openbsd",
+        target_os = "ios", target_os = "macos", target_os = "redox"))] {
+        /// Looks up the host name of `addr`, failing if it doesn't have one.
+        pub fn lookup_addr(addr: &IpAddr) -> io::Result<String> {
+            init();
+
+            // NI_MAXHOST
+            let mut host = [0 as libc::c_char; 1025];
+            let addr = SocketAddr::new(*addr, 0);
+            let (addrp, len) = (&addr).into_inner();
+            unsafe {
+                cvt_gai(c::getnameinfo(
+                    addrp,
+                    len,
+                    host.as_mut_ptr(),
+                    host.len() as _,
+                    ptr::null_mut(),
+                    0,
+                    c::NI_NAMEREQD,
+                ))?;
+                Ok(CStr::from_ptr(host.as_ptr()).to_string_lossy().into_owned())
+            }
+        }
+
+        fn resolver_flags(resolver: &Resolver) -> io::Result<c_int> {
+            let mut flags = 0;
+            if resolver.addr_config {
+                flags |= c::AI_ADDRCONFIG;
+            }
+            if resolver.canonical_name {
+                flags |= c::AI_CANONNAME;
+            }
+            Ok(flags)
+        }
+    } else {
+        // `libc` doesn't expose `getnameinfo` or the resolver flags on the
+        // remaining targets, so only plain lookups are available there.
+        pub fn lookup_addr(_addr: &IpAddr) -> io::Result<String> {
+            Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "reverse lookups are not supported on this platform",
+            ))
+        }
+
+        fn resolver_flags(resolver: &Resolver) -> io::Result<c_int> {
+            if resolver.addr_config || resolver.canonical_name {
+                return Err(io::const_io_error!(
+                    ErrorKind::Unsupported,
+                    "resolver flags are not supported on this platform",
+                ));
+            }
+            Ok(0)
         }
     }
 }
@@ -328,6 +479,48 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.inner.nodelay()
     }
 
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_KEEPALIVE, keepalive as c_int)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        let name = keepalive_option(TCP_KEEPIDLE)?;
+        setsockopt(&self.inner, c::IPPROTO_TCP, name, keepalive_secs(idle)?)
+    }
+
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_TCP, keepalive_option(TCP_KEEPIDLE)?)?;
+        Ok(Duration::from_secs(raw as u64))
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        let name = keepalive_option(TCP_KEEPINTVL)?;
+        setsockopt(&self.inner, c::IPPROTO_TCP, name, keepalive_secs(interval)?)
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_TCP, keepalive_option(TCP_KEEPINTVL)?)?;
+        Ok(Duration::from_secs(raw as u64))
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        let name = keepalive_option(TCP_KEEPCNT)?;
+        let retries = c_int::try_from(retries).map_err(|_| {
+            io::const_io_error!(ErrorKind::InvalidInput, "too many keepalive retries")
+        })?;
+        setsockopt(&self.inner, c::IPPROTO_TCP, name, retries)
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_TCP, keepalive_option(TCP_KEEPCNT)?)?;
+        Ok(raw as u32)
+    }
+
     pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
         setsockopt(&self.inner, c::IPPROTO_IP, c::IP_TTL, ttl as c_int)
     }
@@ -494,6 +687,23 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         Ok(UdpSocket { inner: sock })
     }
 
+    pub fn bind_reuse_port(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+        let addr = addr?;
+
+        init();
+
+        let sock = Socket::new(addr, c::SOCK_DGRAM)?;
+        setsockopt(&sock, c::SOL_SOCKET, reuse_port_option()?, 1 as c_int)?;
+        let (addrp, len) = addr.into_inner();
+        cvt(unsafe { c::bind(sock.as_raw(), addrp, len as _) })?;
+        Ok(UdpSocket { inner: sock })
+    }
+
+    pub fn reuse_port(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, reuse_port_option()?)?;
+        Ok(raw != 0)
+    }
+
     pub fn socket(&self) -> &Socket {
         &self.inner
     }
@@ -632,6 +842,86 @@ pub fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> io::Re
         setsockopt(&self.inner, c::IPPROTO_IPV6, IPV6_DROP_MEMBERSHIP, mreq)
     }
 
+    pub fn join_ssm_v4(
+        &self,
+        source: &Ipv4Addr,
+        group: &Ipv4Addr,
+        interface: &Ipv4Addr,
+    ) -> io::Result<()> {
+        set_ssm_v4(&self.inner, source, group, interface, true)
+    }
+
+    pub fn leave_ssm_v4(
+        &self,
+        source: &Ipv4Addr,
+        group: &Ipv4Addr,
+        interface: &Ipv4Addr,
+    ) -> io::Result<()> {
+        set_ssm_v4(&self.inner, source, group, interface, false)
+    }
+
+    pub fn join_multicast_v4_by_index(
+        &self,
+        multiaddr: &Ipv4Addr,
+        interface: u32,
+    ) -> io::Result<()> {
+        set_multicast_v4_by_index(&self.inner, c::IP_ADD_MEMBERSHIP, multiaddr, interface)
+    }
+
+    pub fn leave_multicast_v4_by_index(
+        &self,
+        multiaddr: &Ipv4Addr,
+        interface: u32,
+    ) -> io::Result<()> {
+        set_multicast_v4_by_index(&self.inner, c::IP_DROP_MEMBERSHIP, multiaddr, interface)
+    }
+
+    pub fn set_multicast_interface_v4(&self, interface: u32) -> io::Result<()> {
+        set_multicast_v4_by_index(
+            &self.inner,
+            c::IP_MULTICAST_IF,
+            &Ipv4Addr::UNSPECIFIED,
+            interface,
+        )
+    }
+
+    pub fn set_multicast_interface_v6(&self, interface: u32) -> io::Result<()> {
+        setsockopt(&self.inner, c::IPPROTO_IPV6, c::IPV6_MULTICAST_IF, interface as c_int)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_RCVBUF, buffer_size(size)?)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_RCVBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_SNDBUF, buffer_size(size)?)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_SNDBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_recv_pktinfo(&self, recv_pktinfo: bool) -> io::Result<()> {
+        let (level, name) = pktinfo_option(&self.socket_addr()?)?;
+        setsockopt(&self.inner, level, name, recv_pktinfo as c_int)
+    }
+
+    pub fn recv_pktinfo(&self) -> io::Result<bool> {
+        let (level, name) = pktinfo_option(&self.socket_addr()?)?;
+        let raw: c_int = getsockopt(&self.inner, level, name)?;
+        Ok(raw != 0)
+    }
+
+    pub fn recv_msg(&self, buf: &mut [u8]) -> io::Result<RecvMsg> {
+        recv_msg(&self.inner, buf)
+    }
+
     pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
         setsockopt(&self.inner, c::IPPROTO_IP, c::IP_TTL, ttl as c_int)
     }
@@ -689,3 +979,154 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         res.field(name, &self.inner.as_raw()).finish()
     }
 }
+
+fn buffer_size(size: usize) -> io::Result<c_int> {
+    c_int::try_from(size).map_err(|_| {
+        io::const_io_error!(ErrorKind::InvalidInput, "socket buffer size is too large")
+    })
+}
+
+fn reuse_port_option() -> io::Result<c_int> {
+    SO_REUSEPORT.ok_or(io::const_io_error!(
+        ErrorKind::Unsupported,
+        "SO_REUSEPORT is not supported on this platform",
+    ))
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android", target_os = "emscripten", windows))] {
+        fn set_ssm_v4(
+            sock: &Socket,
+            source: &Ipv4Addr,
+            group: &Ipv4Addr,
+            interface: &Ipv4Addr,
+            join: bool,
+        ) -> io::Result<()> {
+            let mreq = c::ip_mreq_source {
+                imr_multiaddr: group.into_inner(),
+                imr_interface: interface.into_inner(),
+                imr_sourceaddr: source.into_inner(),
+            };
+            let name =
+                if join { c::IP_ADD_SOURCE_MEMBERSHIP } else { c::IP_DROP_SOURCE_MEMBERSHIP };
+            setsockopt(sock, c::IPPROTO_IP, name, mreq)
+        }
+    } else {
+        fn set_ssm_v4(
+            _sock: &Socket,
+            _source: &Ipv4Addr,
+            _group: &Ipv4Addr,
+            _interface: &Ipv4Addr,
+            _join: bool,
+        ) -> io::Result<()> {
+            Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "source-specific multicast is not supported on this platform",
+            ))
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android", target_os = "emscripten"))] {
+        // `ip_mreqn` names the interface by index, and is accepted both for
+        // (un)subscribing and for `IP_MULTICAST_IF`.
+        fn set_multicast_v4_by_index(
+            sock: &Socket,
+            name: c_int,
+            multiaddr: &Ipv4Addr,
+            interface: u32,
+        ) -> io::Result<()> {
+            let interface = c_int::try_from(interface).map_err(|_| {
+                io::const_io_error!(ErrorKind::InvalidInput, "invalid interface index")
+            })?;
+            let mreq = c::ip_mreqn {
+                imr_multiaddr: multiaddr.into_inner(),
+                imr_address: Ipv4Addr::UNSPECIFIED.into_inner(),
+                imr_ifindex: interface,
+            };
+            setsockopt(sock, c::IPPROTO_IP, name, mreq)
+        }
+    } else {
+        fn set_multicast_v4_by_index(
+            _sock: &Socket,
+            _name: c_int,
+            _multiaddr: &Ipv4Addr,
+            _interface: u32,
+        ) -> io::Result<()> {
+            Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "selecting IPv4 multicast interfaces by index is not supported on this platform",
+            ))
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        fn pktinfo_option(addr: &SocketAddr) -> io::Result<(c_int, c_int)> {
+            match addr {
+                SocketAddr::V4(..) => Ok((c::IPPROTO_IP, c::IP_PKTINFO)),
+                SocketAddr::V6(..) => Ok((c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO)),
+            }
+        }
+
+        fn recv_msg(sock: &Socket, buf: &mut [u8]) -> io::Result<RecvMsg> {
+            // Room for one `in6_pktinfo` control message, aligned for `cmsghdr`.
+            let mut control = [0u64; 8];
+            let mut storage: c::sockaddr_storage = unsafe { mem::zeroed() };
+            let mut iov = c::iovec { iov_base: buf.as_mut_ptr().cast(), iov_len: buf.len() };
+            let mut msg: c::msghdr = unsafe { mem::zeroed() };
+            msg.msg_name = &mut storage as *mut _ as *mut c_void;
+            msg.msg_namelen = mem::size_of_val(&storage) as c::socklen_t;
+            msg.msg_iov = &mut iov;
+            msg.msg_iovlen = 1;
+            msg.msg_control = control.as_mut_ptr().cast();
+            msg.msg_controllen = mem::size_of_val(&control) as _;
+
+            let len = sock.recv_msg(&mut msg)?;
+            let peer_addr = sockaddr_to_addr(&storage, msg.msg_namelen as usize)?;
+
+            let mut local_ip = None;
+            let mut interface = None;
+            unsafe {
+                let mut cmsg = c::CMSG_FIRSTHDR(&msg);
+                while !cmsg.is_null() {
+                    let data = c::CMSG_DATA(cmsg);
+                    match ((*cmsg).cmsg_level, (*cmsg).cmsg_type) {
+                        (c::IPPROTO_IP, c::IP_PKTINFO) => {
+                            let info: c::in_pktinfo = ptr::read_unaligned(data.cast());
+                            let octets = info.ipi_addr.s_addr.to_ne_bytes();
+                            local_ip = Some(IpAddr::V4(Ipv4Addr::from(octets)));
+                            interface = Some(info.ipi_ifindex as u32);
+                        }
+                        (c::IPPROTO_IPV6, c::IPV6_PKTINFO) => {
+                            let info: c::in6_pktinfo = ptr::read_unaligned(data.cast());
+                            local_ip = Some(IpAddr::V6(Ipv6Addr::from_inner(info.ipi6_addr)));
+                            interface = Some(info.ipi6_ifindex as u32);
+                        }
+                        _ => {}
+                    }
+                    cmsg = c::CMSG_NXTHDR(&msg, cmsg);
+                }
+            }
+
+            let truncated = msg.msg_flags & c::MSG_TRUNC != 0;
+            Ok(RecvMsg { len, peer_addr, local_ip, interface, truncated })
+        }
+    } else {
+        fn pktinfo_option(_addr: &SocketAddr) -> io::Result<(c_int, c_int)> {
+            Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "packet information is not supported on this platform",
+            ))
+        }
+
+        fn recv_msg(_sock: &Socket, _buf: &mut [u8]) -> io::Result<RecvMsg> {
+            Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "recv_msg is not supported on this platform",
+            ))
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::sys::locks as imp;
+use crate::time::Duration;
 
 /// An OS-based reader-writer lock, meant for use in static variables.
 ///
@@ -93,6 +94,13 @@ pub fn try_read(&self) -> bool {
         unsafe { self.0.try_read() }
     }
 
+    /// Acquires shared access to this lock, blocking the current thread for at
+    /// most `timeout`, returning whether it succeeded or not.
+    #[inline]
+    pub fn read_timeout(&self, timeout: Duration) -> bool {
+        unsafe { self.0.read_timeout(timeout) }
+    }
+
     /// Acquires write access to the underlying lock, blocking the current thread
     /// to do so.
     #[inline]
@@ -109,6 +117,13 @@ pub fn try_write(&self) -> bool {
         unsafe { self.0.try_write() }
     }
 
+    /// Acquires exclusive access to this lock, blocking the current thread for
+    /// at most `timeout`, returning whether it succeeded or not.
+    #[inline]
+    pub fn write_timeout(&self, timeout: Duration) -> bool {
+        unsafe { self.0.write_timeout(timeout) }
+    }
+
     /// Unlocks previously acquired shared access to this lock.
     ///
     /// Behavior is undefined if the current thread does not have shared access.
@@ -125,6 +140,57 @@ pub unsafe fn read_unlock(&self) {
     pub unsafe fn write_unlock(&self) {
         self.0.write_unlock()
     }
+
+    /// Acquires shared access that can later be upgraded to exclusive access,
+    /// blocking the current thread to do so.
+    ///
+    /// At most one thread holds such access at a time. On platforms without a
+    /// native upgradable mode, this acquires exclusive access instead.
+    #[inline]
+    pub fn upgradable_read(&self) {
+        unsafe { upgradable::read(&self.0) }
+    }
+
+    /// Attempts to acquire upgradable shared access to this lock, returning
+    /// whether it succeeded or not.
+    ///
+    /// This function does not block the current thread.
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        unsafe { upgradable::try_read(&self.0) }
+    }
+
+    /// Unlocks previously acquired upgradable shared access to this lock.
+    ///
+    /// Behavior is undefined if the current thread does not have upgradable
+    /// shared access.
+    #[inline]
+    pub unsafe fn upgradable_unlock(&self) {
+        upgradable::unlock(&self.0)
+    }
+
+    /// Turns upgradable shared access into exclusive access, blocking the
+    /// current thread until all other readers are gone.
+    ///
+    /// Behavior is undefined if the current thread does not have upgradable
+    /// shared access. Afterwards, the lock must be unlocked with
+    /// `write_unlock`.
+    #[inline]
+    pub unsafe fn upgrade(&self) {
+        upgradable::upgrade(&self.0)
+    }
+
+    /// Attempts to turn upgradable shared access into exclusive access,
+    /// returning whether it succeeded or not.
+    ///
+    /// This function does not block the current thread.
+    ///
+    /// Behavior is undefined if the current thread does not have upgradable
+    /// shared access.
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        upgradable::try_upgrade(&self.0)
+    }
 }
 
 impl Drop for MovableRwLock {
@@ -132,3 +198,70 @@ fn drop(&mut self) {
         unsafe { self.0.destroy() };
     }
 }
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        all(target_os = "emscripten", target_feature = "atomics"),
+    ))] {
+        // The futex-based rwlock has a native upgradable read mode.
+        mod upgradable {
+            use crate::sys::locks::RwLock;
+
+            #[inline]
+            pub unsafe fn read(lock: &RwLock) {
+                lock.upgradable_read()
+            }
+
+            #[inline]
+            pub unsafe fn try_read(lock: &RwLock) -> bool {
+                lock.try_upgradable_read()
+            }
+
+            #[inline]
+            pub unsafe fn unlock(lock: &RwLock) {
+                lock.upgradable_unlock()
+            }
+
+            #[inline]
+            pub unsafe fn upgrade(lock: &RwLock) {
+                lock.upgrade()
+            }
+
+            #[inline]
+            pub unsafe fn try_upgrade(lock: &RwLock) -> bool {
+                lock.try_upgrade()
+            }
+        }
+    } else {
+        // There is no native upgradable read mode, so an upgradable read lock
+        // is simply an exclusive lock, which makes upgrading it a no-op.
+        mod upgradable {
+            use crate::sys::locks::RwLock;
+
+            #[inline]
+            pub unsafe fn read(lock: &RwLock) {
+                lock.write()
+            }
+
+            #[inline]
+            pub unsafe fn try_read(lock: &RwLock) -> bool {
+                lock.try_write()
+            }
+
+            #[inline]
+            pub unsafe fn unlock(lock: &RwLock) {
+                lock.write_unlock()
+            }
+
+            #[inline]
+            pub unsafe fn upgrade(_lock: &RwLock) {}
+
+            #[inline]
+            pub unsafe fn try_upgrade(_lock: &RwLock) -> bool {
+                true
+            }
+        }
+    }
+}
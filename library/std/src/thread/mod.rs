@@ -186,6 +186,12 @@
 #[unstable(feature = "scoped_threads", issue = "93203")]
 pub use scoped::{scope, Scope, ScopedJoinHandle};
 
+#[unstable(feature = "thread_pool", issue = "none")]
+mod pool;
+
+#[unstable(feature = "thread_pool", issue = "none")]
+pub use pool::{PoolJoinHandle, ThreadPool};
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::local::{AccessError, LocalKey};
 
@@ -829,6 +835,7 @@ pub fn sleep_ms(ms: u32) {
 /// assert!(now.elapsed() >= ten_millis);
 /// ```
 #[stable(feature = "thread_sleep", since = "1.4.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn sleep(dur: Duration) {
     imp::Thread::sleep(dur)
 }
@@ -920,6 +927,7 @@ pub fn sleep(dur: Duration) {
 /// [`unpark`]: Thread::unpark
 /// [`thread::park_timeout`]: park_timeout
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn park() {
     // SAFETY: park_timeout is called on the parker owned by this thread.
     unsafe {
@@ -984,6 +992,7 @@ pub fn park_timeout_ms(ms: u32) {
 /// }
 /// ```
 #[stable(feature = "park_timeout", since = "1.4.0")]
+#[cfg_attr(not(bootstrap), blocking)]
 pub fn park_timeout(dur: Duration) {
     // SAFETY: park_timeout is called on the parker owned by this thread.
     unsafe {
@@ -1458,6 +1467,7 @@ pub fn thread(&self) -> &Thread {
     /// join_handle.join().expect("Couldn't join on the associated thread");
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn join(self) -> Result<T> {
         self.0.join()
     }
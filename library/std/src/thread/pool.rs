@@ -0,0 +1,392 @@
+use super::scoped::ScopeData;
+use super::{available_parallelism, Builder, Result, Scope};
+use crate::cell::Cell;
+use crate::collections::VecDeque;
+use crate::fmt;
+use crate::io;
+use crate::num::NonZeroUsize;
+use crate::panic::{catch_unwind, AssertUnwindSafe};
+use crate::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use crate::sync::{Arc, Condvar, Mutex};
+
+type Job<'scope> = Box<dyn FnOnce() + Send + 'scope>;
+
+/// A pool of worker threads within a [`scope`], running tasks that may borrow
+/// non-`'static` data.
+///
+/// Each worker has its own queue of tasks. [`spawn`] spreads new tasks over
+/// these queues, and a worker that runs out of tasks steals them from the
+/// others.
+///
+/// The workers are [scoped threads][`Scope::spawn`]: dropping the pool lets
+/// them finish the remaining tasks and exit, and the end of the scope joins
+/// them. Like for scoped threads, if a task panicked and its
+/// [`PoolJoinHandle`] was dropped without being joined, the scope will panic
+/// after all threads are joined.
+///
+/// A pool that is leaked (e.g. with [`mem::forget`]) never shuts down, so its
+/// scope never ends.
+///
+/// [`scope`]: super::scope
+/// [`spawn`]: ThreadPool::spawn
+/// [`mem::forget`]: crate::mem::forget
+///
+/// # Examples
+///
+/// ```
+/// #![feature(scoped_threads, thread_pool)]
+///
+/// use std::thread::{self, ThreadPool};
+///
+/// let data: Vec<u64> = (1..=1000).collect();
+///
+/// let sum: u64 = thread::scope(|s| {
+///     let pool = ThreadPool::new(s);
+///     let handles: Vec<_> =
+///         data.chunks(100).map(|chunk| pool.spawn(move || chunk.iter().sum::<u64>())).collect();
+///     handles.into_iter().map(|h| h.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(sum, 500500);
+/// ```
+#[unstable(feature = "thread_pool", issue = "none")]
+pub struct ThreadPool<'scope> {
+    shared: Arc<Shared<'scope>>,
+}
+
+/// An owned permission to join on a task of a [`ThreadPool`] (block on its
+/// completion).
+///
+/// See [`ThreadPool::spawn`] for details.
+#[unstable(feature = "thread_pool", issue = "none")]
+pub struct PoolJoinHandle<'scope, T> {
+    shared: Arc<Shared<'scope>>,
+    packet: Arc<Packet<'scope, T>>,
+}
+
+struct Shared<'scope> {
+    queues: Box<[Mutex<VecDeque<Job<'scope>>>]>,
+    /// Used to spread tasks spawned from outside the pool over the queues.
+    next_queue: AtomicUsize,
+    /// Number of tasks in all queues together.
+    queued: AtomicUsize,
+    /// Number of workers that are (about to be) waiting on `wake`.
+    /// Only modified while `sleep` is locked.
+    sleepers: AtomicUsize,
+    sleep: Mutex<()>,
+    wake: Condvar,
+    shutdown: AtomicBool,
+    scope_data: &'scope ScopeData,
+}
+
+struct Packet<'scope, T> {
+    state: Mutex<PacketState<T>>,
+    done: Condvar,
+    scope_data: &'scope ScopeData,
+}
+
+struct PacketState<T> {
+    result: Option<Result<T>>,
+    /// Set when the join handle is dropped without being joined.
+    detached: bool,
+}
+
+thread_local! {
+    /// The pool and queue index of the worker running on this thread, if any.
+    static WORKER: Cell<(usize, usize)> = const { Cell::new((0, 0)) }
+}
+
+impl<'scope> ThreadPool<'scope> {
+    /// Creates a pool with one worker per unit of [`available_parallelism`]
+    /// in the given scope, using default parameters of [`Builder`].
+    ///
+    /// If you want to specify the number of workers, their stack size or
+    /// their names, use [`Builder::spawn_pool`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread; use [`Builder::spawn_pool`]
+    /// to recover from such errors.
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn new<'env>(scope: &'scope Scope<'scope, 'env>) -> ThreadPool<'scope> {
+        let threads = available_parallelism().map_or(1, NonZeroUsize::get);
+        Builder::new().spawn_pool(scope, threads).expect("failed to spawn thread")
+    }
+
+    /// Returns the number of worker threads in this pool.
+    #[unstable(feature = "thread_pool", issue = "none")]
+    #[must_use]
+    pub fn num_threads(&self) -> usize {
+        self.shared.queues.len()
+    }
+
+    /// Spawns a task on the pool, returning a [`PoolJoinHandle`] for it.
+    ///
+    /// Like scoped threads, tasks may borrow non-`'static` data from outside
+    /// the scope. The join handle provides a [`join`] method that can be used
+    /// to wait for the task, which returns an [`Err`] containing the panic
+    /// payload if the task panicked.
+    ///
+    /// If the join handle is dropped, the task still runs. In that case, if
+    /// the task panics, the scope will panic after all threads are joined.
+    ///
+    /// [`join`]: PoolJoinHandle::join
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn spawn<F, T>(&self, f: F) -> PoolJoinHandle<'scope, T>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        let packet = Arc::new(Packet {
+            state: Mutex::new(PacketState { result: None, detached: false }),
+            done: Condvar::new(),
+            scope_data: self.shared.scope_data,
+        });
+        let their_packet = packet.clone();
+        self.shared.push(Box::new(move || {
+            let result = catch_unwind(AssertUnwindSafe(f));
+            their_packet.complete(result);
+        }));
+        PoolJoinHandle { shared: self.shared.clone(), packet }
+    }
+}
+
+#[unstable(feature = "thread_pool", issue = "none")]
+impl Drop for ThreadPool<'_> {
+    fn drop(&mut self) {
+        self.shared.shutdown.store(true, Ordering::SeqCst);
+        let _guard = self.shared.sleep.lock().unwrap();
+        self.shared.wake.notify_all();
+    }
+}
+
+impl Builder {
+    /// Spawns a [`ThreadPool`] with `threads` workers in the given scope,
+    /// using the settings set through this `Builder`.
+    ///
+    /// If a name was set, the workers are named after it, followed by a dash
+    /// and their index.
+    ///
+    /// Unlike [`ThreadPool::new`], this method yields an [`io::Result`] to
+    /// capture any failure to create the threads at the OS level. Workers
+    /// that were already spawned when a failure occurs exit right away.
+    ///
+    /// [`io::Result`]: crate::io::Result
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero, or if a thread name was set and it
+    /// contained null bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(scoped_threads, thread_pool)]
+    ///
+    /// use std::thread;
+    ///
+    /// thread::scope(|s| {
+    ///     let pool = thread::Builder::new()
+    ///         .name("worker".to_string())
+    ///         .spawn_pool(s, 2)
+    ///         .unwrap();
+    ///     let name = pool.spawn(|| thread::current().name().unwrap().to_string());
+    ///     assert!(name.join().unwrap().starts_with("worker-"));
+    /// });
+    /// ```
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn spawn_pool<'scope, 'env>(
+        self,
+        scope: &'scope Scope<'scope, 'env>,
+        threads: usize,
+    ) -> io::Result<ThreadPool<'scope>> {
+        assert!(threads > 0, "a thread pool needs at least one thread");
+
+        let pool = ThreadPool {
+            shared: Arc::new(Shared {
+                queues: (0..threads).map(|_| Mutex::new(VecDeque::new())).collect(),
+                next_queue: AtomicUsize::new(0),
+                queued: AtomicUsize::new(0),
+                sleepers: AtomicUsize::new(0),
+                sleep: Mutex::new(()),
+                wake: Condvar::new(),
+                shutdown: AtomicBool::new(false),
+                scope_data: &scope.data,
+            }),
+        };
+        for index in 0..threads {
+            let builder = Builder {
+                name: self.name.as_ref().map(|name| format!("{name}-{index}")),
+                stack_size: self.stack_size,
+            };
+            let shared = pool.shared.clone();
+            // On failure, dropping `pool` shuts down the workers spawned so far.
+            builder.spawn_scoped(scope, move || shared.run_worker(index))?;
+        }
+        Ok(pool)
+    }
+}
+
+impl<'scope> Shared<'scope> {
+    fn id(&self) -> usize {
+        self as *const Self as usize
+    }
+
+    /// Returns the queue index of the current thread if it's a worker of this pool.
+    fn current_worker(&self) -> Option<usize> {
+        let (pool, index) = WORKER.with(Cell::get);
+        (pool == self.id()).then(|| index)
+    }
+
+    fn push(&self, job: Job<'scope>) {
+        // Workers keep tasks they spawn themselves in their own queue.
+        let index = self
+            .current_worker()
+            .unwrap_or_else(|| self.next_queue.fetch_add(1, Ordering::Relaxed) % self.queues.len());
+        // Pairs with the SeqCst operations in `run_worker`: either the worker
+        // sees the new task, or we see that it's going to sleep.
+        self.queued.fetch_add(1, Ordering::SeqCst);
+        self.queues[index].lock().unwrap().push_back(job);
+        if self.sleepers.load(Ordering::SeqCst) > 0 {
+            let _guard = self.sleep.lock().unwrap();
+            self.wake.notify_one();
+        }
+    }
+
+    /// Takes a task from the given queue, or steals one from another queue.
+    fn pop(&self, index: usize) -> Option<Job<'scope>> {
+        let n = self.queues.len();
+        let job = self.queues[index].lock().unwrap().pop_front().or_else(|| {
+            // Steal from the back, away from where the owner takes tasks.
+            (1..n).find_map(|i| self.queues[(index + i) % n].lock().unwrap().pop_back())
+        })?;
+        self.queued.fetch_sub(1, Ordering::Relaxed);
+        Some(job)
+    }
+
+    fn run_worker(&self, index: usize) {
+        WORKER.with(|w| w.set((self.id(), index)));
+        loop {
+            if let Some(job) = self.pop(index) {
+                job();
+                continue;
+            }
+            let guard = self.sleep.lock().unwrap();
+            self.sleepers.fetch_add(1, Ordering::SeqCst);
+            let idle = self.queued.load(Ordering::SeqCst) == 0;
+            if idle && self.shutdown.load(Ordering::SeqCst) {
+                self.sleepers.fetch_sub(1, Ordering::SeqCst);
+                break;
+            }
+            let guard = if idle { self.wake.wait(guard).unwrap() } else { guard };
+            self.sleepers.fetch_sub(1, Ordering::SeqCst);
+            drop(guard);
+        }
+        WORKER.with(|w| w.set((0, 0)));
+    }
+}
+
+impl<'scope, T> Packet<'scope, T> {
+    fn complete(&self, result: Result<T>) {
+        let mut state = self.state.lock().unwrap();
+        if state.detached {
+            drop(state);
+            if result.is_err() {
+                self.scope_data.mark_panicked();
+            }
+        } else {
+            state.result = Some(result);
+            drop(state);
+            self.done.notify_all();
+        }
+    }
+}
+
+impl<'scope, T> PoolJoinHandle<'scope, T> {
+    /// Waits for the associated task to finish.
+    ///
+    /// This function will return immediately if the task has already
+    /// finished. When called from a worker of the same pool, the worker runs
+    /// other tasks while it waits, so tasks may wait for each other without
+    /// deadlocking the pool.
+    ///
+    /// If the associated task panics, [`Err`] is returned with the panic
+    /// payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(scoped_threads, thread_pool)]
+    ///
+    /// use std::thread::{self, ThreadPool};
+    ///
+    /// thread::scope(|s| {
+    ///     let pool = ThreadPool::new(s);
+    ///     let t = pool.spawn(|| {
+    ///         panic!("oh no");
+    ///     });
+    ///     assert!(t.join().is_err());
+    /// });
+    /// ```
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn join(self) -> Result<T> {
+        if let Some(index) = self.shared.current_worker() {
+            while !self.is_finished() {
+                match self.shared.pop(index) {
+                    Some(job) => job(),
+                    // Our task is running on another worker.
+                    None => break,
+                }
+            }
+        }
+        let mut state = self.packet.state.lock().unwrap();
+        loop {
+            if let Some(result) = state.result.take() {
+                return result;
+            }
+            state = self.packet.done.wait(state).unwrap();
+        }
+    }
+
+    /// Checks if the associated task has finished running.
+    ///
+    /// This function does not block. To block while waiting on the task to
+    /// finish, use [`join`][Self::join].
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn is_finished(&self) -> bool {
+        self.packet.state.lock().unwrap().result.is_some()
+    }
+}
+
+#[unstable(feature = "thread_pool", issue = "none")]
+impl<T> Drop for PoolJoinHandle<'_, T> {
+    fn drop(&mut self) {
+        let mut state = self.packet.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => {
+                drop(state);
+                if result.is_err() {
+                    self.packet.scope_data.mark_panicked();
+                }
+            }
+            None => state.detached = true,
+        }
+    }
+}
+
+#[unstable(feature = "thread_pool", issue = "none")]
+impl fmt::Debug for ThreadPool<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("num_threads", &self.num_threads())
+            .field("queued", &self.shared.queued.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "thread_pool", issue = "none")]
+impl<T> fmt::Debug for PoolJoinHandle<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolJoinHandle").finish_non_exhaustive()
+    }
+}
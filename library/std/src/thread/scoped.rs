@@ -10,7 +10,7 @@
 ///
 /// See [`scope`] for details.
 pub struct Scope<'scope, 'env: 'scope> {
-    data: ScopeData,
+    pub(super) data: ScopeData,
     /// Invariance over 'scope, to make sure 'scope cannot shrink,
     /// which is necessary for soundness.
     ///
@@ -53,12 +53,17 @@ pub(super) fn increment_num_running_threads(&self) {
     }
     pub(super) fn decrement_num_running_threads(&self, panic: bool) {
         if panic {
-            self.a_thread_panicked.store(true, Ordering::Relaxed);
+            self.mark_panicked();
         }
         if self.num_running_threads.fetch_sub(1, Ordering::Release) == 1 {
             self.main_thread.unpark();
         }
     }
+    /// Makes the scope panic once everything is joined, like for a scoped
+    /// thread that panicked without being joined.
+    pub(super) fn mark_panicked(&self) {
+        self.a_thread_panicked.store(true, Ordering::Relaxed);
+    }
 }
 
 /// Create a scope for spawning scoped threads.
@@ -303,6 +308,7 @@ pub fn thread(&self) -> &Thread {
     ///     assert!(t.join().is_err());
     /// });
     /// ```
+    #[cfg_attr(not(bootstrap), blocking)]
     pub fn join(self) -> Result<T> {
         self.0.join()
     }
@@ -4,11 +4,11 @@
 use crate::panic::panic_any;
 use crate::result;
 use crate::sync::{
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     mpsc::{channel, Sender},
     Arc, Barrier,
 };
-use crate::thread::{self, Scope, ThreadId};
+use crate::thread::{self, Scope, ThreadId, ThreadPool};
 use crate::time::Duration;
 use crate::time::Instant;
 
@@ -316,3 +316,70 @@ fn drop(&mut self) {
     });
     assert!(actually_finished.load(Ordering::Relaxed));
 }
+
+#[test]
+fn test_thread_pool_borrows_and_joins() {
+    let data: Vec<usize> = (0..1000).collect();
+    let counter = AtomicUsize::new(0);
+    let sum: usize = thread::scope(|s| {
+        let pool = ThreadPool::new(s);
+        let handles: Vec<_> = data
+            .chunks(10)
+            .map(|chunk| {
+                let counter = &counter;
+                pool.spawn(move || {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                    chunk.iter().sum::<usize>()
+                })
+            })
+            .collect();
+        // Detached tasks still run before the scope ends.
+        pool.spawn(|| counter.fetch_add(1, Ordering::Relaxed));
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+    assert_eq!(sum, 999 * 1000 / 2);
+    assert_eq!(counter.load(Ordering::Relaxed), 101);
+}
+
+#[test]
+fn test_thread_pool_names_workers() {
+    thread::scope(|s| {
+        let pool = Builder::new().name("pool".to_string()).spawn_pool(s, 3).unwrap();
+        assert_eq!(pool.num_threads(), 3);
+        let name = pool.spawn(|| thread::current().name().map(str::to_string)).join().unwrap();
+        assert!(["pool-0", "pool-1", "pool-2"].contains(&name.as_deref().unwrap()));
+    });
+}
+
+#[test]
+fn test_thread_pool_join_in_task() {
+    // With a single worker, joining from a task only completes if the worker
+    // runs the other task itself.
+    let (tx, rx) = channel();
+    thread::scope(|s| {
+        let pool = Builder::new().spawn_pool(s, 1).unwrap();
+        let inner = pool.spawn(|| 5);
+        pool.spawn(move || tx.send(inner.join().unwrap()).unwrap());
+    });
+    assert_eq!(rx.recv().unwrap(), 5);
+}
+
+#[test]
+fn test_thread_pool_join_panic() {
+    thread::scope(|s| {
+        let pool = Builder::new().spawn_pool(s, 2).unwrap();
+        let result = pool.spawn(|| panic_any(42_u32)).join();
+        assert_eq!(*result.unwrap_err().downcast::<u32>().unwrap(), 42);
+    });
+}
+
+#[test]
+fn test_thread_pool_detached_panic() {
+    let result = crate::panic::catch_unwind(|| {
+        thread::scope(|s| {
+            let pool = Builder::new().spawn_pool(s, 2).unwrap();
+            drop(pool.spawn(|| panic!("test panic in a detached task")));
+        });
+    });
+    assert!(result.is_err());
+}
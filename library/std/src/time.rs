@@ -570,6 +570,35 @@ pub fn checked_add(&self, duration: Duration) -> Option<SystemTime> {
     pub fn checked_sub(&self, duration: Duration) -> Option<SystemTime> {
         self.0.checked_sub_duration(&duration).map(SystemTime)
     }
+
+    /// Returns the UTC calendar date and time of this system time.
+    ///
+    /// See [`UtcDateTime`] for details, including how to format the result as
+    /// an RFC 3339 timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(system_time_civil)]
+    /// use std::time::SystemTime;
+    ///
+    /// println!("the time is {}", SystemTime::now().to_utc());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn to_utc(&self) -> UtcDateTime {
+        let (secs, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(d) => (d.as_secs() as i128, d.subsec_nanos()),
+            Err(e) => {
+                let d = e.duration();
+                match d.subsec_nanos() {
+                    0 => (-(d.as_secs() as i128), 0),
+                    n => (-(d.as_secs() as i128) - 1, NANOS_PER_SEC - n),
+                }
+            }
+        };
+        UtcDateTime::from_unix(secs, nanos)
+    }
 }
 
 #[stable(feature = "time2", since = "1.8.0")]
@@ -686,3 +715,435 @@ fn from_inner(time: time::SystemTime) -> SystemTime {
         SystemTime(time)
     }
 }
+
+/// A calendar date and time of day in Coordinated Universal Time (UTC).
+///
+/// `UtcDateTime` breaks a [`SystemTime`] down into the fields of the
+/// proleptic Gregorian calendar, and converts back. It knows nothing about
+/// time zones or daylight saving time, and, like [`SystemTime`], it ignores
+/// leap seconds: every day is exactly 86 400 seconds long.
+///
+/// Its [`Display`] implementation writes the date and time in the format of
+/// [RFC 3339] (a profile of ISO 8601), and its [`FromStr`] implementation
+/// parses that format back.
+///
+/// [`Display`]: fmt::Display
+/// [`FromStr`]: crate::str::FromStr
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+///
+/// # Examples
+///
+/// ```
+/// #![feature(system_time_civil)]
+/// use std::time::{Duration, SystemTime, UtcDateTime};
+///
+/// let t = SystemTime::UNIX_EPOCH + Duration::from_secs(1_792_324_800);
+/// let utc = t.to_utc();
+/// assert_eq!((utc.year(), utc.month(), utc.day()), (2026, 10, 18));
+/// assert_eq!(utc.to_string(), "2026-10-18T12:00:00Z");
+///
+/// let parsed: UtcDateTime = "2026-10-18T14:00:00+02:00".parse().unwrap();
+/// assert_eq!(parsed, utc);
+/// assert_eq!(parsed.to_system_time(), Some(t));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[unstable(feature = "system_time_civil", issue = "none")]
+pub struct UtcDateTime {
+    // The field order makes the derived `Ord` chronological.
+    year: i64,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+const SECS_PER_DAY: i128 = 86_400;
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+// The conversions between days since the Unix epoch and civil dates follow
+// Howard Hinnant's `days_from_civil` and `civil_from_days` algorithms, which
+// work in 400-year eras starting on March 1st so that leap days fall at the
+// end of each year.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i128 {
+    let y = year as i128 - (month <= 2) as i128;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i128 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i128 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(days: i128) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + (month <= 2) as i128;
+    (year as i64, month, day)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl UtcDateTime {
+    /// Creates a `UtcDateTime` from its calendar fields.
+    ///
+    /// `month` counts from 1 to 12 and `day` from 1 to the length of the
+    /// month. Returns `None` if any field is out of range, including a
+    /// `second` of 60, since leap seconds can't be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(system_time_civil)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// assert!(UtcDateTime::new(2024, 2, 29, 23, 59, 59, 0).is_some());
+    /// assert!(UtcDateTime::new(2023, 2, 29, 0, 0, 0, 0).is_none());
+    /// ```
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn new(
+        year: i64,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<UtcDateTime> {
+        let valid = (1..=12).contains(&month)
+            && (1..=days_in_month(year, month)).contains(&day)
+            && hour < 24
+            && minute < 60
+            && second < 60
+            && nanosecond < NANOS_PER_SEC;
+        valid.then(|| UtcDateTime { year, month, day, hour, minute, second, nanosecond })
+    }
+
+    fn from_unix(secs: i128, nanosecond: u32) -> UtcDateTime {
+        let (year, month, day) = civil_from_days(secs.div_euclid(SECS_PER_DAY));
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY) as u32;
+        UtcDateTime {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u8,
+            minute: (secs_of_day / 60 % 60) as u8,
+            second: (secs_of_day % 60) as u8,
+            nanosecond,
+        }
+    }
+
+    fn unix_secs(&self) -> i128 {
+        days_from_civil(self.year, self.month, self.day) * SECS_PER_DAY
+            + self.hour as i128 * 3600
+            + self.minute as i128 * 60
+            + self.second as i128
+    }
+
+    /// Returns the [`SystemTime`] at this date and time, or `None` if it is
+    /// outside of the range `SystemTime` can represent on this platform.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let secs = self.unix_secs();
+        if secs >= 0 {
+            let secs = u64::try_from(secs).ok()?;
+            UNIX_EPOCH.checked_add(Duration::new(secs, self.nanosecond))
+        } else if self.nanosecond == 0 {
+            UNIX_EPOCH.checked_sub(Duration::new(u64::try_from(-secs).ok()?, 0))
+        } else {
+            let secs = u64::try_from(-secs - 1).ok()?;
+            UNIX_EPOCH.checked_sub(Duration::new(secs, NANOS_PER_SEC - self.nanosecond))
+        }
+    }
+
+    /// Returns the year. Years before 1 AD are numbered astronomically, so
+    /// the year 1 BC is `0` and 2 BC is `-1`.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Returns the month, from 1 (January) to 12 (December).
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting from 1.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour of the day, from 0 to 23.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute of the hour, from 0 to 59.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second of the minute, from 0 to 59.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fraction of the second, in nanoseconds.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Returns the day of the year, from 1 (January 1st) to 366.
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn ordinal(&self) -> u16 {
+        (days_from_civil(self.year, self.month, self.day) - days_from_civil(self.year, 1, 1) + 1)
+            as u16
+    }
+
+    /// Returns the ISO 8601 day of the week, from 1 (Monday) to 7 (Sunday).
+    #[must_use]
+    #[unstable(feature = "system_time_civil", issue = "none")]
+    pub fn weekday(&self) -> u8 {
+        // The Unix epoch was a Thursday.
+        ((days_from_civil(self.year, self.month, self.day) + 3).rem_euclid(7) + 1) as u8
+    }
+}
+
+#[unstable(feature = "system_time_civil", issue = "none")]
+impl From<SystemTime> for UtcDateTime {
+    fn from(t: SystemTime) -> UtcDateTime {
+        t.to_utc()
+    }
+}
+
+/// Writes the date and time as an RFC 3339 timestamp, such as
+/// `2026-10-18T12:00:00Z` or `2026-10-18T12:00:00.250Z`.
+///
+/// The fraction of the second is written with as many groups of three digits
+/// as are needed to represent it exactly, and is omitted when it is zero. A
+/// precision, as in `{:.3}`, writes exactly that many digits instead
+/// (truncating, up to 9), with `{:.0}` omitting the fraction.
+///
+/// RFC 3339 only covers the years 0 to 9999. Other years are written in the
+/// expanded form of ISO 8601, with an explicit sign, such as `+10000` or `-0001`.
+#[unstable(feature = "system_time_civil", issue = "none")]
+impl fmt::Display for UtcDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            write!(f, "{:+05}", self.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.month, self.day, self.hour, self.minute, self.second
+        )?;
+
+        let digits = match f.precision() {
+            Some(p) => p.min(9),
+            None if self.nanosecond == 0 => 0,
+            None if self.nanosecond % 1_000_000 == 0 => 3,
+            None if self.nanosecond % 1_000 == 0 => 6,
+            None => 9,
+        };
+        if digits > 0 {
+            let frac = self.nanosecond / 10u32.pow(9 - digits as u32);
+            write!(f, ".{frac:0digits$}")?;
+        }
+        f.write_str("Z")
+    }
+}
+
+/// An error which can be returned when parsing a [`UtcDateTime`].
+///
+/// This error is used as the error type for the [`FromStr`] implementation
+/// for [`UtcDateTime`].
+///
+/// [`FromStr`]: crate::str::FromStr
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "system_time_civil", issue = "none")]
+pub struct ParseDateTimeError(ParseDateTimeErrorKind);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParseDateTimeErrorKind {
+    Syntax,
+    OutOfRange,
+}
+
+#[unstable(feature = "system_time_civil", issue = "none")]
+impl fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.0 {
+            ParseDateTimeErrorKind::Syntax => "invalid RFC 3339 timestamp syntax",
+            ParseDateTimeErrorKind::OutOfRange => "date or time component out of range",
+        })
+    }
+}
+
+#[unstable(feature = "system_time_civil", issue = "none")]
+impl Error for ParseDateTimeError {}
+
+struct DateTimeParser<'a> {
+    state: &'a [u8],
+}
+
+impl<'a> DateTimeParser<'a> {
+    fn read_byte(&mut self) -> Option<u8> {
+        let (&b, rest) = self.state.split_first()?;
+        self.state = rest;
+        Some(b)
+    }
+
+    fn read_given_byte(&mut self, expected: u8) -> Option<()> {
+        self.read_byte().filter(|&b| b == expected).map(drop)
+    }
+
+    fn read_digits(&mut self, count: usize) -> Option<u32> {
+        (0..count).try_fold(0, |acc, _| {
+            let d = (self.read_byte()? as char).to_digit(10)?;
+            Some(acc * 10 + d)
+        })
+    }
+
+    // date-fullyear, or the expanded form of ISO 8601 written by `Display`
+    // for other years: a sign followed by at least four digits.
+    fn read_year(&mut self) -> Option<i64> {
+        let negative = match self.state.first()? {
+            b'+' => false,
+            b'-' => true,
+            _ => return self.read_digits(4).map(i64::from),
+        };
+        self.state = &self.state[1..];
+        let digits = self.state.iter().take_while(|b| b.is_ascii_digit()).count();
+        // More than 18 digits could overflow, and are far outside of the
+        // range of `SystemTime` anyway.
+        if !(4..=18).contains(&digits) {
+            return None;
+        }
+        let (year, rest) = self.state.split_at(digits);
+        self.state = rest;
+        let year = year.iter().fold(0, |acc, &b| acc * 10 + i64::from(b - b'0'));
+        Some(if negative { -year } else { year })
+    }
+
+    // date-time = full-date "T" full-time, with the fraction and offset parsed
+    // into nanoseconds and seconds east of UTC.
+    fn read_date_time(&mut self) -> Option<(i64, [u32; 5], u32, i32)> {
+        let year = self.read_year()?;
+        self.read_given_byte(b'-')?;
+        let month = self.read_digits(2)?;
+        self.read_given_byte(b'-')?;
+        let day = self.read_digits(2)?;
+        if !matches!(self.read_byte()?, b'T' | b't' | b' ') {
+            return None;
+        }
+        let hour = self.read_digits(2)?;
+        self.read_given_byte(b':')?;
+        let minute = self.read_digits(2)?;
+        self.read_given_byte(b':')?;
+        let second = self.read_digits(2)?;
+
+        let mut nanosecond = 0;
+        let mut separator = self.read_byte()?;
+        if separator == b'.' {
+            let mut digits = 0;
+            loop {
+                separator = self.read_byte()?;
+                match (separator as char).to_digit(10) {
+                    // Digits beyond nanosecond precision are truncated.
+                    Some(d) if digits < 9 => nanosecond = nanosecond * 10 + d,
+                    Some(_) => {}
+                    None if digits == 0 => return None,
+                    None => break,
+                }
+                digits += 1;
+            }
+            nanosecond *= 10u32.pow(9u32.saturating_sub(digits));
+        }
+
+        let offset = match separator {
+            b'Z' | b'z' => 0,
+            sign @ (b'+' | b'-') => {
+                let hours = self.read_digits(2)?;
+                self.read_given_byte(b':')?;
+                let minutes = self.read_digits(2)?;
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                let offset = (hours * 3600 + minutes * 60) as i32;
+                if sign == b'-' { -offset } else { offset }
+            }
+            _ => return None,
+        };
+        Some((year, [month, day, hour, minute, second], nanosecond, offset))
+    }
+}
+
+/// Parses an RFC 3339 timestamp, such as `2026-10-18T12:00:00Z` or
+/// `2026-10-18 14:00:00.5+02:00`.
+///
+/// The date and time may be separated by `T`, `t` or a space, and the offset
+/// may be `Z`, `z` or a numeric offset, which is applied to convert the time
+/// to UTC. Years outside of 0 to 9999 are accepted in the expanded form
+/// written by the [`Display`](fmt::Display) implementation, such as
+/// `+10000-01-01T00:00:00Z`. Fractions of a second finer than a nanosecond are truncated. Leap
+/// seconds (a second of 60) are rejected.
+#[unstable(feature = "system_time_civil", issue = "none")]
+impl crate::str::FromStr for UtcDateTime {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<UtcDateTime, ParseDateTimeError> {
+        let mut p = DateTimeParser { state: s.as_bytes() };
+        let (year, [month, day, hour, minute, second], nanosecond, offset) = p
+            .read_date_time()
+            .filter(|_| p.state.is_empty())
+            .ok_or(ParseDateTimeError(ParseDateTimeErrorKind::Syntax))?;
+
+        let local = UtcDateTime::new(
+            year,
+            month as u8,
+            day as u8,
+            hour as u8,
+            minute as u8,
+            second as u8,
+            nanosecond,
+        )
+        .ok_or(ParseDateTimeError(ParseDateTimeErrorKind::OutOfRange))?;
+        Ok(UtcDateTime::from_unix(local.unix_secs() - offset as i128, nanosecond))
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use super::{Duration, Instant, SystemTime, UtcDateTime, UNIX_EPOCH};
 #[cfg(not(target_arch = "wasm32"))]
 use test::{black_box, Bencher};
 
@@ -192,6 +192,78 @@ fn since_epoch() {
     assert!(a < hundred_twenty_years);
 }
 
+#[test]
+fn utc_date_time_roundtrip() {
+    let t = UNIX_EPOCH + Duration::from_secs(1_792_324_800);
+    let utc = t.to_utc();
+    assert_eq!(utc, UtcDateTime::new(2026, 10, 18, 12, 0, 0, 0).unwrap());
+    assert_eq!((utc.weekday(), utc.ordinal()), (7, 291));
+    assert_eq!(utc.to_system_time(), Some(t));
+
+    let before_epoch = UNIX_EPOCH - Duration::new(86_400 * 365, 1);
+    let utc = before_epoch.to_utc();
+    assert_eq!(utc, UtcDateTime::new(1968, 12, 31, 23, 59, 59, 999_999_999).unwrap());
+    assert_eq!(utc.to_system_time(), Some(before_epoch));
+
+    // Leap days, including the century rules.
+    assert!(UtcDateTime::new(2000, 2, 29, 0, 0, 0, 0).is_some());
+    assert!(UtcDateTime::new(2100, 2, 29, 0, 0, 0, 0).is_none());
+    let leap = UtcDateTime::new(2024, 2, 29, 12, 0, 0, 0).unwrap();
+    assert_eq!(leap.to_system_time().unwrap().to_utc(), leap);
+    assert_eq!(leap.ordinal(), 60);
+}
+
+#[test]
+fn utc_date_time_display() {
+    let t = UtcDateTime::new(2000, 2, 29, 1, 2, 3, 0).unwrap();
+    assert_eq!(t.to_string(), "2000-02-29T01:02:03Z");
+    let t = UtcDateTime::new(2000, 2, 29, 1, 2, 3, 250_000_000).unwrap();
+    assert_eq!(t.to_string(), "2000-02-29T01:02:03.250Z");
+    let t = UtcDateTime::new(2000, 2, 29, 1, 2, 3, 123_456_789).unwrap();
+    assert_eq!(t.to_string(), "2000-02-29T01:02:03.123456789Z");
+    assert_eq!(format!("{t:.3}"), "2000-02-29T01:02:03.123Z");
+    assert_eq!(format!("{t:.0}"), "2000-02-29T01:02:03Z");
+
+    let t = UtcDateTime::new(-1, 12, 31, 0, 0, 0, 0).unwrap();
+    assert_eq!(t.to_string(), "-0001-12-31T00:00:00Z");
+    let t = UtcDateTime::new(10_000, 1, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(t.to_string(), "+10000-01-01T00:00:00Z");
+}
+
+#[test]
+fn utc_date_time_parse() {
+    let t = UtcDateTime::new(2000, 2, 29, 1, 2, 3, 120_000_000).unwrap();
+    assert_eq!("2000-02-29T01:02:03.12Z".parse(), Ok(t));
+    assert_eq!("2000-02-29t01:02:03.120z".parse(), Ok(t));
+    assert_eq!("2000-02-29 01:02:03.1200000009Z".parse(), Ok(t));
+    assert_eq!("2000-02-29T03:32:03.12+02:30".parse(), Ok(t));
+    assert_eq!("2000-02-28T23:02:03.12-02:00".parse(), Ok(t));
+    assert_eq!(t.to_string().parse(), Ok(t));
+
+    let bc = UtcDateTime::new(-1, 12, 31, 0, 0, 0, 0).unwrap();
+    assert_eq!("-0001-12-31T00:00:00Z".parse(), Ok(bc));
+    assert_eq!("+2000-02-29T01:02:03.12Z".parse(), Ok(t));
+
+    // Years written in the expanded form round-trip.
+    for year in [-10_000, -1, 0, 9999, 10_000] {
+        let t = UtcDateTime::new(year, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(t.to_string().parse(), Ok(t), "{t}");
+    }
+
+    for s in ["2000-02-30T00:00:00Z", "2000-01-01T00:00:60Z", "2000-01-01T24:00:00Z"] {
+        assert!(s.parse::<UtcDateTime>().is_err(), "{s}");
+    }
+    for s in ["", "2000-01-01", "2000-01-01T00:00:00", "2000-1-01T00:00:00Z", "2000-01-01T00:00Z"] {
+        assert!(s.parse::<UtcDateTime>().is_err(), "{s}");
+    }
+    for s in ["+200-01-01T00:00:00Z", "+-2000-01-01T00:00:00Z", "-1-01-01T00:00:00Z"] {
+        assert!(s.parse::<UtcDateTime>().is_err(), "{s}");
+    }
+    for s in ["2000-01-01T00:00:00+0100", "2000-01-01T00:00:00+24:00", "2000-01-01T00:00:00Z "] {
+        assert!(s.parse::<UtcDateTime>().is_err(), "{s}");
+    }
+}
+
 macro_rules! bench_instant_threaded {
     ($bench_name:ident, $thread_count:expr) => {
         #[bench]
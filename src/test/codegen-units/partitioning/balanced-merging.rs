@@ -0,0 +1,44 @@
+// We specify incremental here because we want to test the partitioning for
+// incremental compilation
+// incremental
+// compile-flags:-Zprint-mono-items=lazy
+// compile-flags:-Ccodegen-units=2
+// compile-flags:-Zcgu-partitioning-strategy=balanced
+
+#![crate_type = "rlib"]
+
+// This test makes sure that the balanced partitioning strategy merges the CGUs
+// that reference each other, as long as this does not make them too big.
+//
+// All four CGUs have the same size, so the default strategy would merge `aaa`
+// with `bbb` and `ccc` with `ddd`. The balanced strategy instead merges `aaa`
+// with `ccc` and `bbb` with `ddd`, because `aaa` calls into `ccc` and `bbb`
+// calls into `ddd`.
+
+pub mod aaa {
+    //~ MONO_ITEM fn aaa::foo @@ balanced_merging-aaa--balanced_merging-ccc[External]
+    pub fn foo(a: u64) -> u64 {
+        crate::ccc::foo(a, a, a) + 1
+    }
+}
+
+pub mod bbb {
+    //~ MONO_ITEM fn bbb::foo @@ balanced_merging-bbb--balanced_merging-ddd[External]
+    pub fn foo(a: u64) -> u64 {
+        crate::ddd::foo(a, a, a) + 1
+    }
+}
+
+pub mod ccc {
+    //~ MONO_ITEM fn ccc::foo @@ balanced_merging-aaa--balanced_merging-ccc[External]
+    pub fn foo(a: u64, b: u64, c: u64) -> u64 {
+        a + b + c
+    }
+}
+
+pub mod ddd {
+    //~ MONO_ITEM fn ddd::foo @@ balanced_merging-bbb--balanced_merging-ddd[External]
+    pub fn foo(a: u64, b: u64, c: u64) -> u64 {
+        a * b * c
+    }
+}
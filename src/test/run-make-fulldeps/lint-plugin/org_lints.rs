@@ -0,0 +1,34 @@
+#![feature(rustc_private)]
+
+extern crate rustc_ast;
+
+// Load rustc as a plugin to get macros
+extern crate rustc_driver;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_ast as ast;
+use rustc_lint::{EarlyContext, EarlyLintPass, LintContext, LintStore};
+
+declare_lint!(TEST_LINT, Warn, "Warn about items named 'lintme'");
+
+declare_lint_pass!(Pass => [TEST_LINT]);
+
+impl EarlyLintPass for Pass {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, it: &ast::Item) {
+        if it.ident.name.as_str() == "lintme" {
+            cx.lint(TEST_LINT, |lint| {
+                lint.build("item is named 'lintme'").set_span(it.span).emit();
+            });
+        }
+    }
+}
+
+fn register(lint_store: &mut LintStore) {
+    lint_store.register_lints(&[&TEST_LINT]);
+    lint_store.register_early_pass(|| Box::new(Pass));
+}
+
+declare_lint_plugin!(register);
@@ -0,0 +1,7 @@
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+pub fn generic<T: Clone>(t: &T) -> (T, T) {
+    (t.clone(), t.clone())
+}
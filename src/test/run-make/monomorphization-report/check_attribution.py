@@ -0,0 +1,15 @@
+# Checks that the instantiations of upstream generics that are only caused by
+# other upstream generics, such as `RawVec::<MyType>` through `Vec::<MyType>`,
+# are attributed to the local crate rather than to the upstream crate.
+
+import json
+import sys
+
+report = json.load(sys.stdin)
+items = [item for item in report["items"] if "MyType" in item["item"]]
+
+indirect = [item for item in items if "RawVec::<MyType" in item["item"]]
+assert indirect, "no `RawVec::<MyType>` item in the report"
+
+for item in items:
+    assert item["attributed_to"] == ["through_std"], item
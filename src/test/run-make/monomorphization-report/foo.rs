@@ -0,0 +1,12 @@
+fn generic<T: Default>() -> T {
+    T::default()
+}
+
+fn uses_generic() -> (u8, u16) {
+    (generic(), generic())
+}
+
+fn main() {
+    let _ = uses_generic();
+    let _: u32 = generic();
+}
@@ -0,0 +1,7 @@
+struct MyType(u8);
+
+fn main() {
+    let mut v = Vec::new();
+    v.push(MyType(1));
+    let _ = v.pop().map(|x| x.0);
+}
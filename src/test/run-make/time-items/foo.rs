@@ -0,0 +1,7 @@
+fn generic<T: std::fmt::Debug>(t: T) -> String {
+    format!("{:?}", t)
+}
+
+fn main() {
+    println!("{} {} {}", generic(1u8), generic("a"), generic(1.0f64));
+}
@@ -0,0 +1,29 @@
+// check-fail
+// A spec rejected by `CustomFormat::CHECK_SPEC` is reported at the placeholder
+// before monomorphization, including in generic functions that are never used.
+
+#![feature(fmt_custom_spec)]
+
+use std::fmt::{self, CustomFormat, CustomSpec};
+
+struct Flag;
+
+impl<S: CustomSpec> CustomFormat<S> for Flag {
+    const CHECK_SPEC: Result<(), &'static str> = match S::SPEC.as_bytes() {
+        b"%on" | b"%off" => Ok(()),
+        _ => Err("expected `%on` or `%off`"),
+    };
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&S::SPEC[1..])
+    }
+}
+
+fn unused<T>(_: T) -> String {
+    format!("{:%yes}", Flag) //~ ERROR invalid format spec for `Flag`
+}
+
+fn main() {
+    println!("{:%on}", Flag);
+    println!("{:%maybe}", Flag); //~ ERROR invalid format spec for `Flag`
+}
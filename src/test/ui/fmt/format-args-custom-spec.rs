@@ -0,0 +1,63 @@
+// run-pass
+// Custom `{:%...}` format specs are passed verbatim to `CustomFormat`.
+
+#![feature(fmt_custom_spec)]
+
+use std::fmt::{self, CustomFormat, CustomSpec};
+
+struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+const fn check(spec: &str) -> Result<(), &'static str> {
+    let spec = spec.as_bytes();
+    let mut i = 0;
+    while i < spec.len() {
+        if spec[i] == b'%' {
+            if i + 1 == spec.len() {
+                return Err("incomplete conversion");
+            }
+            match spec[i + 1] {
+                b'Y' | b'm' | b'd' | b'%' => {}
+                _ => return Err("unknown conversion"),
+            }
+            i += 1;
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+impl<S: CustomSpec> CustomFormat<S> for Date {
+    const CHECK_SPEC: Result<(), &'static str> = check(S::SPEC);
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        let mut chars = S::SPEC.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => match chars.next() {
+                    Some('Y') => out += &format!("{:04}", self.year),
+                    Some('m') => out += &format!("{:02}", self.month),
+                    Some('d') => out += &format!("{:02}", self.day),
+                    _ => out.push('%'),
+                },
+                c => out.push(c),
+            }
+        }
+        f.pad(&out)
+    }
+}
+
+fn main() {
+    let date = Date { year: 2022, month: 3, day: 9 };
+    assert_eq!(format!("{date:%Y-%m-%d}"), "2022-03-09");
+    assert_eq!(format!("{:%d/%m/%Y}", date), "09/03/2022");
+    assert_eq!(format!("{0:%Y} {0:%m} {0:%Y}", date), "2022 03 2022");
+    assert_eq!(format!("{date:>12%Y-%m-%d}|"), "  2022-03-09|");
+    assert_eq!(format!("{date:*<8%m %d}|"), "03 09***|");
+    assert_eq!(format!("{date:%% %Y {}"), "% 2022 {");
+    assert_eq!(format!("[{} {date:%Y}]", 1), "[1 2022]");
+}
@@ -0,0 +1,63 @@
+// edition:2018
+#![feature(async_closure, blocking, lock_timeout)]
+#![deny(blocking_in_async)]
+
+use std::fs::File;
+use std::future::Future;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+#[blocking = "flushing waits for the disk"]
+fn flush() {}
+
+struct Db;
+
+impl Db {
+    #[blocking]
+    fn query(&self) {}
+}
+
+fn not_async(m: &Mutex<u32>) {
+    thread::sleep(Duration::from_millis(1));
+    *m.lock().unwrap() += 1;
+}
+
+async fn other() {}
+
+async fn service(m: &Mutex<u32>, db: &Db) {
+    thread::sleep(Duration::from_millis(1)); //~ ERROR call to blocking function
+    *m.lock().unwrap() += 1; //~ ERROR call to blocking function
+    db.query(); //~ ERROR call to blocking function
+    let _ = m.try_lock_for(Duration::from_millis(1)); //~ ERROR call to blocking function
+    other().await;
+    // Closures are often run on a thread that is allowed to block.
+    let offloaded = || flush();
+    offloaded();
+}
+
+async fn io(stream: &mut TcpStream, file: &mut File, mut bytes: &[u8], buf: &mut [u8]) {
+    let _ = stream.read(buf); //~ ERROR call to blocking function
+    let _ = Write::write(file, buf); //~ ERROR call to blocking function
+    // Reading from a slice doesn't block.
+    let _ = bytes.read(buf);
+}
+
+async fn generic<R: Read>(mut reader: R, buf: &mut [u8]) {
+    // The impl of `Read` is unknown, so only the trait method is checked.
+    let _ = reader.read(buf);
+}
+
+fn spawn() -> impl Future<Output = ()> {
+    async {
+        flush(); //~ ERROR call to blocking function
+        let _ = std::fs::read("data.bin"); //~ ERROR call to blocking function
+    }
+}
+
+fn main() {
+    let closure = async || Db.query(); //~ ERROR call to blocking function
+    drop(closure);
+}
@@ -0,0 +1,4 @@
+#[blocking] //~ ERROR the `#[blocking]` attribute is an experimental feature
+fn wait() {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#![feature(blocking)]
+
+#[blocking] //~ ERROR `blocking` attribute should be applied to a function
+struct Connection;
+
+fn main() {}
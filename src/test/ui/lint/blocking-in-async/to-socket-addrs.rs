@@ -0,0 +1,19 @@
+// edition:2018
+// Checks that resolving a host name within an `async` body is reported when
+// the receiver is a concrete type, and that the lint is not feature gated.
+#![deny(blocking_in_async)]
+
+use std::net::{SocketAddr, ToSocketAddrs};
+
+async fn resolve(host: &str, owned: String) {
+    let _ = "localhost:80".to_socket_addrs(); //~ ERROR call to blocking function
+    let _ = host.to_socket_addrs(); //~ ERROR call to blocking function
+    let _ = owned.to_socket_addrs(); //~ ERROR call to blocking function
+    let _ = (host, 80).to_socket_addrs(); //~ ERROR call to blocking function
+    let _ = (owned.clone(), 80).to_socket_addrs(); //~ ERROR call to blocking function
+    // Socket addresses are used as they are.
+    let addr = SocketAddr::from(([127, 0, 0, 1], 80));
+    let _ = addr.to_socket_addrs();
+}
+
+fn main() {}
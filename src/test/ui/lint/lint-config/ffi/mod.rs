@@ -0,0 +1,14 @@
+// Loaded by `lint-config.rs`, the override for `ffi/**` applies here.
+
+pub fn raw() {
+    unsafe {}
+}
+
+pub fn rawCall() {}
+
+#[deny(unsafe_code)]
+pub mod strict {
+    pub fn raw() {
+        unsafe {}
+    }
+}
@@ -0,0 +1,4 @@
+// compile-flags: -Z lint-config={{src-base}}/lint/lint-config/lint-config-invalid.toml
+// Checks that errors in a lint config are reported with their line.
+
+fn main() {}
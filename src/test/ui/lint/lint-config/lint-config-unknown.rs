@@ -0,0 +1,7 @@
+// compile-flags: -Z lint-config={{src-base}}/lint/lint-config/lint-config-unknown.toml
+// Checks that unknown lints in a lint config are reported once each, including
+// those of overrides that apply to no module.
+
+#![crate_type = "lib"]
+
+pub fn f() {}
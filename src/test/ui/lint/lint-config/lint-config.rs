@@ -0,0 +1,22 @@
+// compile-flags: -Z lint-config={{src-base}}/lint/lint-config/lint-config.toml
+// Checks that the levels of the `[lints]` table of a lint config apply to the
+// whole crate, that the levels of an override apply to the modules loaded from
+// matching files, and that lint attributes take precedence over both.
+
+#![crate_type = "lib"]
+#![allow(unused_unsafe)]
+
+pub mod ffi;
+
+pub fn wrapper() {
+    unsafe {} //~ ERROR usage of an `unsafe` block
+}
+
+#[allow(unsafe_code)]
+pub fn allowed() {
+    unsafe {}
+}
+
+pub mod inline {
+    pub fn camelCase() {} //~ ERROR function `camelCase` should have a snake case name
+}
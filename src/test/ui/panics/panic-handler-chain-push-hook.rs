@@ -0,0 +1,54 @@
+// run-pass
+// needs-unwind
+// ignore-emscripten no threads support
+
+#![feature(panic_hook_chain)]
+
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+
+static BASE: AtomicUsize = AtomicUsize::new(0);
+static OUTER: AtomicUsize = AtomicUsize::new(0);
+static INNER: AtomicUsize = AtomicUsize::new(0);
+static LAST_COUNT: AtomicUsize = AtomicUsize::new(0);
+static NAMED: AtomicBool = AtomicBool::new(false);
+
+fn main() {
+    panic::set_hook(Box::new(|_| {
+        BASE.fetch_add(1, Ordering::SeqCst);
+    }));
+    panic::push_hook(|prev, info| {
+        assert_eq!(info.payload().downcast_ref::<&str>(), Some(&"boom"));
+        assert!(info.location().is_some());
+        INNER.fetch_add(1, Ordering::SeqCst);
+        LAST_COUNT.store(info.panic_count(), Ordering::SeqCst);
+        NAMED.store(info.thread_name() == Some("worker"), Ordering::SeqCst);
+        prev(info);
+    });
+    panic::push_hook(|_prev, _info| {
+        // Swallow the panic without running the rest of the chain.
+        OUTER.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let _ = thread::spawn(|| panic!("boom")).join();
+    assert_eq!(OUTER.load(Ordering::SeqCst), 1);
+    assert_eq!(BASE.load(Ordering::SeqCst), 0);
+    assert_eq!(INNER.load(Ordering::SeqCst), 0);
+
+    assert!(panic::pop_hook().is_some());
+
+    let _ = thread::Builder::new().name("worker".into()).spawn(|| panic!("boom")).unwrap().join();
+    assert_eq!(OUTER.load(Ordering::SeqCst), 1);
+    assert_eq!(BASE.load(Ordering::SeqCst), 1);
+    assert_eq!(INNER.load(Ordering::SeqCst), 1);
+    assert_eq!(LAST_COUNT.load(Ordering::SeqCst), 2);
+    assert!(NAMED.load(Ordering::SeqCst));
+
+    assert!(panic::pop_hook().is_some());
+    assert!(panic::pop_hook().is_none());
+
+    let _ = thread::spawn(|| panic!("boom")).join();
+    assert_eq!(BASE.load(Ordering::SeqCst), 2);
+    assert_eq!(INNER.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,36 @@
+// compile-flags: -Z unstable-options --print=type-sizes
+// build-pass
+
+// This file checks the JSON output of `--print=type-sizes`, which extends
+// the information printed by `-Z print-type-sizes` with the padding between
+// fields, the largest niche of each type, and how enums store their tag.
+//
+// It avoids using u64/i64 because on some targets that is only 4-byte
+// aligned (while on most it is 8-byte aligned) and so the resulting
+// padding and overall computed sizes can be quite different.
+
+#![feature(start)]
+#![allow(dead_code)]
+
+struct S {
+    a: u8,
+    g: i32,
+}
+
+enum E {
+    A(u8, u16),
+    B,
+}
+
+enum MyOption<T> {
+    None,
+    Some(T),
+}
+
+#[start]
+fn start(_: isize, _: *const *const u8) -> isize {
+    let _s = S { a: 0, g: 0 };
+    let _e = E::B;
+    let _o: MyOption<bool> = MyOption::None;
+    0
+}